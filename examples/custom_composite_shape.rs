@@ -6,7 +6,7 @@ extern crate ncollide;
 use na::{Isometry2, Point2, Translation2, Vector2};
 use ncollide::query::{self, Proximity};
 use ncollide::shape::{CompositeShape, CompositeShape2, Cuboid2, Shape, Shape2};
-use ncollide::partitioning::BVT;
+use ncollide::partitioning::{BVTCostFn, BVTVisitor, BVT};
 use ncollide::bounding_volume::AABB2;
 
 struct CrossedCuboids {
@@ -85,9 +85,17 @@ impl CompositeShape<Point2<f32>, Isometry2<f32>> for CrossedCuboids {
         CrossedCuboids::generate_aabb(i)
     }
 
-    fn bvt(&self) -> &BVT<usize, AABB2<f32>> {
-        // Reference to the acceleration structure.
-        &self.bvt
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB2<f32>>) {
+        // Traverse the acceleration structure.
+        self.bvt.visit(visitor)
+    }
+
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<f32, usize, AABB2<f32>, UserData = ()>,
+    ) -> Option<usize> {
+        // Find the part with the smallest cost using the acceleration structure.
+        self.bvt.best_first_search(cost_fn).map(|(part, _)| *part)
     }
 }
 
@@ -19,6 +19,7 @@ where
 
 /// An Axis Aligned Bounding Box.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct AABB<P> {
     mins: P,
     maxs: P,
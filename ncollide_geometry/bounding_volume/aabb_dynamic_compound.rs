@@ -0,0 +1,29 @@
+use na;
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::DynamicCompound;
+use math::{Isometry, Point};
+
+impl<P, M, M2> HasBoundingVolume<M2, AABB<P>> for DynamicCompound<P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+    M2: Isometry<P>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M2) -> AABB<P> {
+        match self.dbvt().root_bounding_volume() {
+            Some(bv) => {
+                let ls_center = bv.center();
+                let center = m.transform_point(&ls_center);
+                let half_extents = (*bv.maxs() - *bv.mins()) / na::convert::<f64, P::Real>(2.0);
+                let ws_half_extents = m.absolute_rotate_vector(&half_extents);
+
+                AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+            }
+            None => {
+                let center = m.transform_point(&P::origin());
+                AABB::new(center, center)
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use alga::general::Real;
+use alga::linear::Translation;
+use na;
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::Ellipsoid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        let center = P::from_coordinates(m.translation().to_vector());
+        let dim = na::dimension::<P::Vector>();
+
+        // The extent along a world axis `e` is the norm of `R * rot^T * e` where `R` is the
+        // diagonal matrix of radii.
+        let mut half_extents = na::zero::<P::Vector>();
+
+        for i in 0..dim {
+            let mut local_axis = na::zero::<P::Vector>();
+            local_axis[i] = self.radii()[i];
+            let axis = m.rotate_vector(&local_axis);
+
+            for j in 0..dim {
+                half_extents[j] += axis[j] * axis[j];
+            }
+        }
+
+        for j in 0..dim {
+            half_extents[j] = half_extents[j].sqrt();
+        }
+
+        AABB::new(center + -half_extents, center + half_extents)
+    }
+}
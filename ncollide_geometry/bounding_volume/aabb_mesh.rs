@@ -1,6 +1,6 @@
 use na;
 use bounding_volume::{self, HasBoundingVolume, AABB};
-use shape::{BaseMesh, BaseMeshElement, Polyline, TriMesh};
+use shape::{BaseMesh, BaseMeshElement, Polyline, TetMesh, TriMesh};
 use math::{Isometry, Point};
 
 impl<P, M, I, E> HasBoundingVolume<M, AABB<P>> for BaseMesh<P, I, E>
@@ -34,3 +34,10 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Polyline<P> {
         bounding_volume::aabb(self.base_mesh(), m)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for TetMesh<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        bounding_volume::aabb(self.base_mesh(), m)
+    }
+}
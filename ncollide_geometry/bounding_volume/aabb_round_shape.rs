@@ -0,0 +1,14 @@
+use bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use shape::RoundShape;
+use math::Point;
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for RoundShape<P::Real, S>
+where
+    P: Point,
+    S: HasBoundingVolume<M, AABB<P>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        self.inner().bounding_volume(m).loosened(self.radius())
+    }
+}
@@ -0,0 +1,34 @@
+use na;
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::{Scaled, ScaledCompoundPart, Shape};
+use math::{Isometry, Point};
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        let one: M = na::one();
+
+        transformed_aabb(&self.scale_aabb(&self.inner().aabb(&one)), m)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for ScaledCompoundPart<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        transformed_aabb(&self.local_aabb(), m)
+    }
+}
+
+#[inline]
+fn transformed_aabb<P: Point, M: Isometry<P>>(bv: &AABB<P>, m: &M) -> AABB<P> {
+    let center = m.transform_point(&bv.center());
+    let half_extents = (*bv.maxs() - *bv.mins()) * na::convert::<f64, P::Real>(0.5);
+    let ws_half_extents = m.absolute_rotate_vector(&half_extents);
+
+    AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+}
@@ -4,7 +4,7 @@ use bounding_volume::AABB;
 use math::{Isometry, Point};
 
 /// Computes the AABB of an support mapped shape.
-pub fn support_map_aabb<P, M, G>(m: &M, i: &G) -> AABB<P>
+pub fn support_map_aabb<P, M, G: ?Sized>(m: &M, i: &G) -> AABB<P>
 where
     P: Point,
     M: Isometry<P>,
@@ -21,6 +21,7 @@ where
 
 /// A Bounding Sphere.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct BoundingSphere<P: Point> {
     center: P,
     radius: P::Real,
@@ -1,6 +1,9 @@
 use bounding_volume::{BoundingSphere, HasBoundingVolume};
 use bounding_volume;
-use shape::ConvexHull;
+use alga::general::Real;
+use na::{Point2, Point3};
+
+use shape::{ConvexHull, ConvexPolygon, ConvexPolyhedron};
 use math::{Isometry, Point};
 
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for ConvexHull<P> {
@@ -11,3 +14,29 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Conve
         BoundingSphere::new(m.transform_point(&center), radius)
     }
 }
+
+impl<N, M> HasBoundingVolume<M, BoundingSphere<Point3<N>>> for ConvexPolyhedron<N>
+where
+    N: Real,
+    M: Isometry<Point3<N>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<Point3<N>> {
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(self.points());
+
+        BoundingSphere::new(m.transform_point(&center), radius)
+    }
+}
+
+impl<N, M> HasBoundingVolume<M, BoundingSphere<Point2<N>>> for ConvexPolygon<N>
+where
+    N: Real,
+    M: Isometry<Point2<N>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<Point2<N>> {
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(self.points());
+
+        BoundingSphere::new(m.transform_point(&center), radius)
+    }
+}
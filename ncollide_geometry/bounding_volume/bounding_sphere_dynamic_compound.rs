@@ -0,0 +1,24 @@
+use na;
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use shape::DynamicCompound;
+use math::{Isometry, Point};
+
+impl<P, M, M2> HasBoundingVolume<M2, BoundingSphere<P>> for DynamicCompound<P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+    M2: Isometry<P>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M2) -> BoundingSphere<P> {
+        match self.dbvt().root_bounding_volume() {
+            Some(aabb) => {
+                let center = m.transform_point(&aabb.center());
+                let radius = na::norm(&aabb.half_extents());
+
+                BoundingSphere::new(center, radius)
+            }
+            None => BoundingSphere::new(m.transform_point(&P::origin()), na::zero()),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use na;
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use shape::Ellipsoid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let center = m.translate_point(&P::origin());
+        let mut radius = self.radii()[0];
+
+        for i in 1..na::dimension::<P::Vector>() {
+            if self.radii()[i] > radius {
+                radius = self.radii()[i];
+            }
+        }
+
+        BoundingSphere::new(center, radius)
+    }
+}
@@ -0,0 +1,15 @@
+use na;
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use shape::HeightField;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for HeightField<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let aabb = self.local_aabb();
+        let center = m.transform_point(&aabb.center());
+        let radius = na::norm(&aabb.half_extents());
+
+        BoundingSphere::new(center, radius)
+    }
+}
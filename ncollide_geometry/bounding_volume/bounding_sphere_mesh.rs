@@ -1,6 +1,6 @@
 use bounding_volume::{BoundingSphere, HasBoundingVolume};
 use bounding_volume;
-use shape::{BaseMesh, BaseMeshElement, Polyline, TriMesh};
+use shape::{BaseMesh, BaseMeshElement, Polyline, TetMesh, TriMesh};
 use math::{Isometry, Point};
 
 impl<P, M, I, E> HasBoundingVolume<M, BoundingSphere<P>> for BaseMesh<P, I, E>
@@ -30,3 +30,10 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Polyl
         self.base_mesh().bounding_volume(m)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for TetMesh<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.base_mesh().bounding_volume(m)
+    }
+}
@@ -0,0 +1,14 @@
+use bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use shape::RoundShape;
+use math::Point;
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for RoundShape<P::Real, S>
+where
+    P: Point,
+    S: HasBoundingVolume<M, BoundingSphere<P>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.inner().bounding_volume(m).loosened(self.radius())
+    }
+}
@@ -0,0 +1,47 @@
+use na;
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use shape::{Scaled, ScaledCompoundPart, Shape};
+use math::{Isometry, Point};
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let one: M = na::one();
+
+        scaled_bounding_sphere(self, &self.inner().bounding_sphere(&one), m)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for ScaledCompoundPart<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        scaled_bounding_sphere(self.scaled(), &self.shape().bounding_sphere(self.m()), m)
+    }
+}
+
+// The sphere is enlarged by the greatest scaling factor.
+fn scaled_bounding_sphere<P, M, S>(
+    scaled: &Scaled<P::Vector, S>,
+    bs: &BoundingSphere<P>,
+    m: &M,
+) -> BoundingSphere<P>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let center = m.transform_point(&scaled.scale_point(bs.center()));
+    let mut max_scale = scaled.scale()[0];
+
+    for i in 1..na::dimension::<P::Vector>() {
+        if scaled.scale()[i] > max_scale {
+            max_scale = scaled.scale()[i];
+        }
+    }
+
+    BoundingSphere::new(center, bs.radius() * max_scale)
+}
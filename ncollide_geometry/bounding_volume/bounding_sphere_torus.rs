@@ -0,0 +1,13 @@
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use shape::Torus;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Torus<P::Real> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let center = m.translate_point(&P::origin());
+        let radius = self.major_radius() + self.minor_radius();
+
+        BoundingSphere::new(center, radius)
+    }
+}
@@ -27,7 +27,14 @@ mod aabb_ball;
 mod aabb_plane;
 mod aabb_convex;
 mod aabb_compound;
+mod aabb_dynamic_compound;
 mod aabb_mesh;
+mod aabb_heightfield;
+mod aabb_sdf_grid;
+mod aabb_ellipsoid;
+mod aabb_torus;
+mod aabb_round_shape;
+mod aabb_scaled;
 mod aabb_utils;
 mod aabb_shape;
 
@@ -41,9 +48,17 @@ mod bounding_sphere_capsule;
 mod bounding_sphere_plane;
 mod bounding_sphere_convex;
 mod bounding_sphere_compound;
+mod bounding_sphere_dynamic_compound;
 mod bounding_sphere_triangle;
+mod bounding_sphere_tetrahedron;
 mod bounding_sphere_segment;
 mod bounding_sphere_mesh;
+mod bounding_sphere_heightfield;
+mod bounding_sphere_sdf_grid;
+mod bounding_sphere_ellipsoid;
+mod bounding_sphere_torus;
+mod bounding_sphere_round_shape;
+mod bounding_sphere_scaled;
 mod bounding_sphere_utils;
 mod bounding_sphere_shape;
 
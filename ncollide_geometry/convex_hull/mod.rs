@@ -0,0 +1,10 @@
+//! Convex hull computation shared by the mass properties and `ncollide_transformation`.
+
+pub use self::convex_hull_utils::{denormalize, indexed_support_point_id, normalize,
+                                  support_point_id};
+pub use self::convex_hull2::convex_hull2_idx;
+pub use self::convex_hull3::convex_hull3_idx;
+
+mod convex_hull_utils;
+mod convex_hull2;
+mod convex_hull3;
@@ -16,8 +16,21 @@ extern crate nalgebra as na;
 extern crate ncollide_math as math;
 extern crate ncollide_utils as utils;
 extern crate num_traits as num;
+#[cfg(feature = "serde-serialize")]
+extern crate erased_serde;
+#[cfg(feature = "serde-serialize")]
+#[macro_use]
+extern crate lazy_static;
+#[cfg(feature = "serde-serialize")]
+extern crate serde;
+#[cfg(feature = "serde-serialize")]
+#[macro_use]
+extern crate serde_derive;
 
 pub mod shape;
 pub mod bounding_volume;
 pub mod partitioning;
 pub mod query;
+pub mod mass_properties;
+#[doc(hidden)]
+pub mod convex_hull; // Internal implementation details.
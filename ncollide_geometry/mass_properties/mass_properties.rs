@@ -0,0 +1,144 @@
+use std::ops::{Add, Mul};
+
+use alga::general::Real;
+use na::{self, Matrix3, Vector3};
+use math::{Isometry, Point};
+
+/// Traits of solids having a volume, a center of mass, and an angular inertia.
+///
+/// All the quantities are expressed in the local space of the solid and assume a uniform density.
+/// In 2D, the volume is the area of the solid.
+pub trait MassProperties<P: Point> {
+    /// The volume of this solid (its area in 2D).
+    fn volume(&self) -> P::Real;
+
+    /// The center of mass of this solid.
+    fn center_of_mass(&self) -> P;
+
+    /// The angular inertia of this solid wrt. its center of mass, assuming it has a unit mass.
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real>;
+
+    /// The mass of this solid given its density.
+    #[inline]
+    fn mass(&self, density: P::Real) -> P::Real {
+        self.volume() * density
+    }
+
+    /// The angular inertia of this solid wrt. its center of mass given its mass.
+    #[inline]
+    fn angular_inertia(&self, mass: P::Real) -> AngularInertia<P::Real> {
+        self.unit_angular_inertia() * mass
+    }
+
+    /// The mass, center of mass, and angular inertia of this solid given its density.
+    #[inline]
+    fn mass_properties(&self, density: P::Real) -> (P::Real, P, AngularInertia<P::Real>) {
+        let mass = self.mass(density);
+
+        (mass, self.center_of_mass(), self.angular_inertia(mass))
+    }
+}
+
+/// The angular inertia of a solid.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AngularInertia<N: Real> {
+    /// The angular inertia of a 2D solid wrt. the axis orthogonal to its plane.
+    Scalar(N),
+    /// The inertia tensor of a 3D solid.
+    Tensor(Matrix3<N>),
+}
+
+impl<N: Real> Mul<N> for AngularInertia<N> {
+    type Output = AngularInertia<N>;
+
+    #[inline]
+    fn mul(self, rhs: N) -> AngularInertia<N> {
+        match self {
+            AngularInertia::Scalar(i) => AngularInertia::Scalar(i * rhs),
+            AngularInertia::Tensor(i) => AngularInertia::Tensor(i * rhs),
+        }
+    }
+}
+
+impl<N: Real> Add<AngularInertia<N>> for AngularInertia<N> {
+    type Output = AngularInertia<N>;
+
+    #[inline]
+    fn add(self, rhs: AngularInertia<N>) -> AngularInertia<N> {
+        match (self, rhs) {
+            (AngularInertia::Scalar(i1), AngularInertia::Scalar(i2)) => {
+                AngularInertia::Scalar(i1 + i2)
+            }
+            (AngularInertia::Tensor(i1), AngularInertia::Tensor(i2)) => {
+                AngularInertia::Tensor(i1 + i2)
+            }
+            _ => panic!("Cannot add angular inertias of solids with different dimensions."),
+        }
+    }
+}
+
+// The zero angular inertia in the dimension of `P`.
+pub(crate) fn zero_angular_inertia<P: Point>() -> AngularInertia<P::Real> {
+    if na::dimension::<P::Vector>() == 2 {
+        AngularInertia::Scalar(na::zero())
+    } else {
+        AngularInertia::Tensor(na::zero())
+    }
+}
+
+// The angular inertia of a solid with the given principal angular inertia along each local axis.
+//
+// In 2D, only the angular inertia along the `z` axis, i.e., `principal[2]`, is used.
+pub(crate) fn principal_angular_inertia<P: Point>(
+    principal: [P::Real; 3],
+) -> AngularInertia<P::Real> {
+    match na::dimension::<P::Vector>() {
+        2 => AngularInertia::Scalar(principal[2]),
+        3 => AngularInertia::Tensor(Matrix3::from_diagonal(&Vector3::new(
+            principal[0],
+            principal[1],
+            principal[2],
+        ))),
+        _ => panic!("Mass properties are only supported in 2D and 3D."),
+    }
+}
+
+// The angular inertia wrt. the origin of a unit point mass located at `pt`.
+//
+// This is the term added by the parallel axis theorem.
+pub(crate) fn point_mass_angular_inertia<P: Point>(pt: &P::Vector) -> AngularInertia<P::Real> {
+    let sq_norm = na::norm_squared(pt);
+
+    if na::dimension::<P::Vector>() == 2 {
+        AngularInertia::Scalar(sq_norm)
+    } else {
+        let pt = Vector3::new(pt[0], pt[1], pt[2]);
+
+        AngularInertia::Tensor(Matrix3::from_diagonal_element(sq_norm) - pt * pt.transpose())
+    }
+}
+
+// Expresses an angular inertia in the space obtained by rotating its local space by `m`.
+pub(crate) fn rotate_angular_inertia<P: Point, M: Isometry<P>>(
+    inertia: &AngularInertia<P::Real>,
+    m: &M,
+) -> AngularInertia<P::Real> {
+    match *inertia {
+        AngularInertia::Scalar(_) => *inertia,
+        AngularInertia::Tensor(ref tensor) => {
+            let mut rot = Matrix3::zeros();
+
+            for j in 0..3 {
+                let mut axis = na::zero::<P::Vector>();
+                axis[j] = na::one();
+                let axis = m.rotate_vector(&axis);
+
+                for i in 0..3 {
+                    rot[(i, j)] = axis[i];
+                }
+            }
+
+            AngularInertia::Tensor(rot * *tensor * rot.transpose())
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use alga::general::Real;
+use na;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Ball;
+use math::Point;
+
+impl<P: Point> MassProperties<P> for Ball<P::Real> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        let r = self.radius();
+
+        if na::dimension::<P::Vector>() == 2 {
+            P::Real::pi() * r * r
+        } else {
+            let _4_3: P::Real = na::convert(4.0f64 / 3.0);
+            _4_3 * P::Real::pi() * r * r * r
+        }
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        P::origin()
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        let r2 = self.radius() * self.radius();
+
+        if na::dimension::<P::Vector>() == 2 {
+            AngularInertia::Scalar(r2 * na::convert(0.5f64))
+        } else {
+            let i = r2 * na::convert(2.0f64 / 5.0);
+            mass_properties::principal_angular_inertia::<P>([i, i, i])
+        }
+    }
+}
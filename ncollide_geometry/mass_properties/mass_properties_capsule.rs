@@ -0,0 +1,64 @@
+use alga::general::Real;
+use na;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Capsule;
+use math::Point;
+
+// The angular inertia of a capsule is the sum of the angular inertia of its cylindrical part and
+// of its two hemispherical caps, each being translated along the axis of the capsule.
+impl<P: Point> MassProperties<P> for Capsule<P::Real> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        let _2: P::Real = na::convert(2.0f64);
+        let _4_3: P::Real = na::convert(4.0f64 / 3.0);
+        let (r, h) = (self.radius(), self.half_height());
+
+        if na::dimension::<P::Vector>() == 2 {
+            _2 * r * _2 * h + P::Real::pi() * r * r
+        } else {
+            P::Real::pi() * r * r * (_2 * h + _4_3 * r)
+        }
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        P::origin()
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        let _2: P::Real = na::convert(2.0f64);
+        let _3: P::Real = na::convert(3.0f64);
+        let _4: P::Real = na::convert(4.0f64);
+        let (r, h) = (self.radius(), self.half_height());
+        let (r2, h2) = (r * r, h * h);
+        let pi = P::Real::pi();
+
+        if na::dimension::<P::Vector>() == 2 {
+            let rect_area = _2 * r * _2 * h;
+            let disk_area = pi * r2;
+            // The centroid of a half-disk is at `4r / 3π` from its center.
+            let rect_inertia = rect_area * (r2 + h2) / _3;
+            let disk_inertia = disk_area * (r2 / _2 + h2 + _2 * h * _4 * r / (_3 * pi));
+
+            AngularInertia::Scalar((rect_inertia + disk_inertia) / (rect_area + disk_area))
+        } else {
+            let _2_5: P::Real = na::convert(2.0f64 / 5.0);
+            let _3_4: P::Real = na::convert(3.0f64 / 4.0);
+            let cylinder_volume = pi * r2 * _2 * h;
+            let ball_volume = pi * r2 * r * _4 / _3;
+            let volume = cylinder_volume + ball_volume;
+            // The centroid of a hemisphere is at `3r / 8` from its center.
+            let axis_inertia = cylinder_volume * r2 / _2 + ball_volume * r2 * _2_5;
+            let off_axis_inertia = cylinder_volume * (r2 / _4 + h2 / _3)
+                + ball_volume * (r2 * _2_5 + h2 + h * r * _3_4);
+            let off_principal = off_axis_inertia / volume;
+
+            mass_properties::principal_angular_inertia::<P>([
+                off_principal,
+                axis_inertia / volume,
+                off_principal,
+            ])
+        }
+    }
+}
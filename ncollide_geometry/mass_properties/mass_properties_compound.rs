@@ -0,0 +1,81 @@
+use num::Zero;
+
+use na;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Compound;
+use math::{Isometry, Point};
+
+// The mass properties of a compound shape, assuming all its parts have the same density.
+//
+// The angular inertia of each part is moved to the center of mass of the compound shape using
+// the parallel axis theorem.
+//
+// Panics if one of the parts of the compound shape does not have mass properties, i.e., if
+// `Shape::as_mass_properties` returns `None` for one of its parts.
+impl<P: Point, M: Isometry<P>> MassProperties<P> for Compound<P, M> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        self.compound_mass_properties().0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        self.compound_mass_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        self.compound_mass_properties().2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: P::Real) -> (P::Real, P, AngularInertia<P::Real>) {
+        let (volume, center, inertia) = self.compound_mass_properties();
+        let mass = volume * density;
+
+        (mass, center, inertia * mass)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> Compound<P, M> {
+    /// Whether all the parts of this compound shape have mass properties.
+    pub fn has_mass_properties(&self) -> bool {
+        self.shapes()
+            .iter()
+            .all(|&(_, ref shape)| shape.as_mass_properties().is_some())
+    }
+
+    fn compound_mass_properties(&self) -> (P::Real, P, AngularInertia<P::Real>) {
+        let mut parts = Vec::with_capacity(self.shapes().len());
+        let mut volume = na::zero::<P::Real>();
+        let mut moment = na::zero::<P::Vector>();
+
+        for &(ref delta, ref shape) in self.shapes().iter() {
+            let props = shape
+                .as_mass_properties()
+                .expect("Every part of the compound shape must have mass properties.");
+            let part_volume = props.volume();
+            let part_center = delta.transform_point(&props.center_of_mass());
+
+            volume += part_volume;
+            moment += part_center.coordinates() * part_volume;
+            parts.push((part_volume, part_center, props.unit_angular_inertia(), delta));
+        }
+
+        if volume.is_zero() {
+            return (volume, P::origin(), mass_properties::zero_angular_inertia::<P>());
+        }
+
+        let center = P::from_coordinates(moment / volume);
+        let mut inertia = mass_properties::zero_angular_inertia::<P>();
+
+        for (part_volume, part_center, part_inertia, delta) in parts {
+            let part_inertia = mass_properties::rotate_angular_inertia(&part_inertia, delta)
+                + mass_properties::point_mass_angular_inertia::<P>(&(part_center - center));
+
+            inertia = inertia + part_inertia * part_volume;
+        }
+
+        (volume, center, inertia * (na::one::<P::Real>() / volume))
+    }
+}
@@ -0,0 +1,57 @@
+use alga::general::Real;
+use na;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Cone;
+use math::Point;
+
+// In 2D, a cone is an isosceles triangle.
+impl<P: Point> MassProperties<P> for Cone<P::Real> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        let _2: P::Real = na::convert(2.0f64);
+        let _3: P::Real = na::convert(3.0f64);
+        let (r, h) = (self.radius(), self.half_height());
+
+        if na::dimension::<P::Vector>() == 2 {
+            _2 * r * h
+        } else {
+            P::Real::pi() * r * r * _2 * h / _3
+        }
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        let mut center = P::origin();
+
+        if na::dimension::<P::Vector>() == 2 {
+            center[1] = -self.half_height() / na::convert(3.0f64);
+        } else {
+            center[1] = -self.half_height() / na::convert(2.0f64);
+        }
+
+        center
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        let r2 = self.radius() * self.radius();
+        let h2 = self.half_height() * self.half_height();
+
+        if na::dimension::<P::Vector>() == 2 {
+            let _2_9: P::Real = na::convert(2.0f64 / 9.0);
+            let _1_6: P::Real = na::convert(1.0f64 / 6.0);
+
+            AngularInertia::Scalar(h2 * _2_9 + r2 * _1_6)
+        } else {
+            let _3_10: P::Real = na::convert(3.0f64 / 10.0);
+            let _3_20: P::Real = na::convert(3.0f64 / 20.0);
+            let off_principal = (r2 + h2) * _3_20;
+
+            mass_properties::principal_angular_inertia::<P>([
+                off_principal,
+                r2 * _3_10,
+                off_principal,
+            ])
+        }
+    }
+}
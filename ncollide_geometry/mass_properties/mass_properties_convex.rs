@@ -0,0 +1,68 @@
+use na::{self, Point2, Point3};
+use convex_hull;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::ConvexHull;
+use math::Point;
+
+impl<P: Point> MassProperties<P> for ConvexHull<P> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        convex_hull_mass_properties(self.points()).0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        convex_hull_mass_properties(self.points()).1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        convex_hull_mass_properties(self.points()).2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: P::Real) -> (P::Real, P, AngularInertia<P::Real>) {
+        let (volume, center, inertia) = convex_hull_mass_properties(self.points());
+        let mass = volume * density;
+
+        (mass, center, inertia * mass)
+    }
+}
+
+fn convex_hull_mass_properties<P: Point>(points: &[P]) -> (P::Real, P, AngularInertia<P::Real>) {
+    match na::dimension::<P::Vector>() {
+        2 => mass_properties::polygon_mass_properties(points, &convex_hull2_boundary(points)[..]),
+        3 => mass_properties::trimesh_mass_properties(points, &convex_hull3_boundary(points)[..]),
+        _ => panic!("Mass properties are only supported in 2D and 3D."),
+    }
+}
+
+// The boundary segments of the 2D convex hull of a set of points. Returns an empty boundary if all
+// the points are equal.
+fn convex_hull2_boundary<P: Point>(points: &[P]) -> Vec<Point2<usize>> {
+    if points.iter().all(|pt| *pt == points[0]) {
+        return Vec::new();
+    }
+
+    let points: Vec<_> = points.iter().map(|pt| Point2::new(pt[0], pt[1])).collect();
+    let idx = convex_hull::convex_hull2_idx(&points[..]);
+
+    (0..idx.len())
+        .map(|i| Point2::new(idx[i], idx[(i + 1) % idx.len()]))
+        .collect()
+}
+
+// The boundary triangles of the 3D convex hull of a set of points. Returns an empty boundary if all
+// the points are equal.
+fn convex_hull3_boundary<P: Point>(points: &[P]) -> Vec<Point3<usize>> {
+    if points.iter().all(|pt| *pt == points[0]) {
+        return Vec::new();
+    }
+
+    let points: Vec<_> = points
+        .iter()
+        .map(|pt| Point3::new(pt[0], pt[1], pt[2]))
+        .collect();
+
+    convex_hull::convex_hull3_idx(&points[..])
+}
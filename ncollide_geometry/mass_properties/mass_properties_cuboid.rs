@@ -0,0 +1,41 @@
+use na;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Cuboid;
+use math::Point;
+
+impl<P: Point> MassProperties<P> for Cuboid<P::Vector> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        let _2: P::Real = na::convert(2.0f64);
+        let mut volume = na::one::<P::Real>();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            volume *= self.half_extents()[i] * _2;
+        }
+
+        volume
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        P::origin()
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        let _3: P::Real = na::convert(3.0f64);
+        let mut sq_extents = [na::zero::<P::Real>(); 3];
+
+        for i in 0..na::dimension::<P::Vector>() {
+            sq_extents[i] = self.half_extents()[i] * self.half_extents()[i];
+        }
+
+        let (x2, y2, z2) = (sq_extents[0], sq_extents[1], sq_extents[2]);
+
+        mass_properties::principal_angular_inertia::<P>([
+            (y2 + z2) / _3,
+            (x2 + z2) / _3,
+            (x2 + y2) / _3,
+        ])
+    }
+}
@@ -0,0 +1,42 @@
+use alga::general::Real;
+use na;
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Cylinder;
+use math::Point;
+
+// In 2D, a cylinder is a rectangle.
+impl<P: Point> MassProperties<P> for Cylinder<P::Real> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        let _2: P::Real = na::convert(2.0f64);
+        let (r, h) = (self.radius(), self.half_height());
+
+        if na::dimension::<P::Vector>() == 2 {
+            _2 * r * _2 * h
+        } else {
+            P::Real::pi() * r * r * _2 * h
+        }
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        P::origin()
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        let _3: P::Real = na::convert(3.0f64);
+        let r2 = self.radius() * self.radius();
+        let h2 = self.half_height() * self.half_height();
+
+        if na::dimension::<P::Vector>() == 2 {
+            AngularInertia::Scalar((r2 + h2) / _3)
+        } else {
+            let _2: P::Real = na::convert(2.0f64);
+            let _4: P::Real = na::convert(4.0f64);
+            let off_principal = r2 / _4 + h2 / _3;
+
+            mass_properties::principal_angular_inertia::<P>([off_principal, r2 / _2, off_principal])
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::{Polyline, TriMesh};
+use math::Point;
+
+// The mass properties of the solid bounded by a triangle mesh.
+//
+// The results are meaningless if the mesh is not closed.
+impl<P: Point> MassProperties<P> for TriMesh<P> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        self.trimesh_mass_properties().0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        self.trimesh_mass_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        self.trimesh_mass_properties().2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: P::Real) -> (P::Real, P, AngularInertia<P::Real>) {
+        let (volume, center, inertia) = self.trimesh_mass_properties();
+        let mass = volume * density;
+
+        (mass, center, inertia * mass)
+    }
+}
+
+impl<P: Point> TriMesh<P> {
+    fn trimesh_mass_properties(&self) -> (P::Real, P, AngularInertia<P::Real>) {
+        mass_properties::trimesh_mass_properties(&self.vertices()[..], &self.indices()[..])
+    }
+}
+
+// The mass properties of the 2D polygon bounded by a polyline.
+//
+// The results are meaningless if the polyline is not closed.
+impl<P: Point> MassProperties<P> for Polyline<P> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        self.polygon_mass_properties().0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        self.polygon_mass_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        self.polygon_mass_properties().2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: P::Real) -> (P::Real, P, AngularInertia<P::Real>) {
+        let (area, center, inertia) = self.polygon_mass_properties();
+        let mass = area * density;
+
+        (mass, center, inertia * mass)
+    }
+}
+
+impl<P: Point> Polyline<P> {
+    fn polygon_mass_properties(&self) -> (P::Real, P, AngularInertia<P::Real>) {
+        mass_properties::polygon_mass_properties(&self.vertices()[..], &self.indices()[..])
+    }
+}
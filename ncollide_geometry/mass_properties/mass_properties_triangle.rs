@@ -0,0 +1,40 @@
+use na::{self, Point2};
+use mass_properties::{self, AngularInertia, MassProperties};
+use shape::Triangle;
+use math::Point;
+
+// In 3D, a triangle is a flat solid with a zero volume.
+impl<P: Point> MassProperties<P> for Triangle<P> {
+    #[inline]
+    fn volume(&self) -> P::Real {
+        self.triangle_mass_properties().0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> P {
+        self.triangle_mass_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<P::Real> {
+        self.triangle_mass_properties().2
+    }
+}
+
+impl<P: Point> Triangle<P> {
+    fn triangle_mass_properties(&self) -> (P::Real, P, AngularInertia<P::Real>) {
+        if na::dimension::<P::Vector>() == 2 {
+            let vertices = [*self.a(), *self.b(), *self.c()];
+            let segments = [Point2::new(0, 1), Point2::new(1, 2), Point2::new(2, 0)];
+
+            mass_properties::polygon_mass_properties(&vertices[..], &segments[..])
+        } else {
+            let center = na::center(&na::center(self.a(), self.b()), self.c());
+            (
+                na::zero(),
+                center,
+                mass_properties::zero_angular_inertia::<P>(),
+            )
+        }
+    }
+}
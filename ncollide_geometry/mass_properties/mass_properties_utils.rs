@@ -0,0 +1,132 @@
+use num::Zero;
+
+use na::{self, Matrix3, Point2, Point3, Vector3};
+use mass_properties::AngularInertia;
+use math::Point;
+
+/// Computes the area, center of mass, and unit angular inertia of a 2D polygon.
+///
+/// The polygon is given by the segments of its closed boundary, each being a pair of indices into
+/// `vertices`. The segments may be oriented either clockwise or counterclockwise, but
+/// consistently. Returns a zero area and angular inertia if the polygon is empty or degenerate.
+pub fn polygon_mass_properties<P: Point>(
+    vertices: &[P],
+    segments: &[Point2<usize>],
+) -> (P::Real, P, AngularInertia<P::Real>) {
+    assert!(
+        na::dimension::<P::Vector>() == 2,
+        "Polygon mass properties are only supported in 2D."
+    );
+
+    if segments.is_empty() {
+        return (na::zero(), P::origin(), AngularInertia::Scalar(na::zero()));
+    }
+
+    // The integrals are computed wrt. the first vertex to limit roundoff errors.
+    let origin = vertices[segments[0].x];
+    let _2: P::Real = na::convert(2.0f64);
+    let _3: P::Real = na::convert(3.0f64);
+    let _6: P::Real = na::convert(6.0f64);
+    let mut area = na::zero::<P::Real>();
+    let mut moment = na::zero::<P::Vector>();
+    let mut inertia = na::zero::<P::Real>();
+
+    for seg in segments.iter() {
+        let a = vertices[seg.x] - origin;
+        let b = vertices[seg.y] - origin;
+        let tri_area = (a[0] * b[1] - a[1] * b[0]) / _2;
+
+        area += tri_area;
+        moment += (a + b) * (tri_area / _3);
+        inertia += tri_area / _6 * (na::dot(&a, &a) + na::dot(&a, &b) + na::dot(&b, &b));
+    }
+
+    // Clockwise polygons have a negative signed area.
+    if area < na::zero() {
+        area = -area;
+        moment = -moment;
+        inertia = -inertia;
+    }
+
+    if area.is_zero() {
+        return (area, origin, AngularInertia::Scalar(na::zero()));
+    }
+
+    let center = moment / area;
+    let inertia = inertia - area * na::norm_squared(&center);
+
+    (area, origin + center, AngularInertia::Scalar(inertia / area))
+}
+
+/// Computes the volume, center of mass, and unit angular inertia of the solid bounded by a closed
+/// triangle mesh.
+///
+/// The triangles may be oriented either clockwise or counterclockwise when seen from outside of
+/// the solid, but consistently. Returns a zero volume and angular inertia if the mesh is empty or
+/// degenerate.
+pub fn trimesh_mass_properties<P: Point>(
+    vertices: &[P],
+    triangles: &[Point3<usize>],
+) -> (P::Real, P, AngularInertia<P::Real>) {
+    assert!(
+        na::dimension::<P::Vector>() == 3,
+        "Triangle mesh mass properties are only supported in 3D."
+    );
+
+    if triangles.is_empty() {
+        return (na::zero(), P::origin(), AngularInertia::Tensor(na::zero()));
+    }
+
+    // The integrals are computed wrt. the first vertex to limit roundoff errors.
+    let origin = vertices[triangles[0].x];
+    let _4: P::Real = na::convert(4.0f64);
+    let _6: P::Real = na::convert(6.0f64);
+    let _120: P::Real = na::convert(120.0f64);
+    let _1: P::Real = na::one();
+    let _2: P::Real = na::convert(2.0f64);
+
+    // The covariance of the canonical tetrahedron `(0, x, y, z)`.
+    let canonical_covariance = Matrix3::new(_2, _1, _1, _1, _2, _1, _1, _1, _2) / _120;
+    let mut volume = na::zero::<P::Real>();
+    let mut moment = Vector3::zeros();
+    let mut covariance = Matrix3::zeros();
+
+    for tri in triangles.iter() {
+        let a = vertices[tri.x] - origin;
+        let b = vertices[tri.y] - origin;
+        let c = vertices[tri.z] - origin;
+        let a = Vector3::new(a[0], a[1], a[2]);
+        let b = Vector3::new(b[0], b[1], b[2]);
+        let c = Vector3::new(c[0], c[1], c[2]);
+
+        // The tetrahedron formed by the triangle and the origin.
+        let transform = Matrix3::from_columns(&[a, b, c]);
+        let det = transform.determinant();
+
+        volume += det / _6;
+        moment += (a + b + c) * (det / _6 / _4);
+        covariance += transform * canonical_covariance * transform.transpose() * det;
+    }
+
+    // Triangles oriented clockwise yield a negative signed volume.
+    if volume < na::zero() {
+        volume = -volume;
+        moment = -moment;
+        covariance = -covariance;
+    }
+
+    if volume.is_zero() {
+        return (volume, origin, AngularInertia::Tensor(na::zero()));
+    }
+
+    let center = moment / volume;
+    let covariance = covariance - center * center.transpose() * volume;
+    let inertia = Matrix3::from_diagonal_element(covariance.trace()) - covariance;
+    let mut shift = na::zero::<P::Vector>();
+
+    for i in 0..3 {
+        shift[i] = center[i];
+    }
+
+    (volume, origin + shift, AngularInertia::Tensor(inertia / volume))
+}
@@ -0,0 +1,21 @@
+//! Mass properties (volume, center of mass, angular inertia) of solids.
+
+#[doc(inline)]
+pub use mass_properties::mass_properties::{AngularInertia, MassProperties};
+pub use mass_properties::mass_properties_utils::{polygon_mass_properties, trimesh_mass_properties};
+pub(crate) use mass_properties::mass_properties::{point_mass_angular_inertia,
+                                                   principal_angular_inertia,
+                                                   rotate_angular_inertia, zero_angular_inertia};
+
+#[doc(hidden)]
+pub mod mass_properties;
+mod mass_properties_utils;
+mod mass_properties_ball;
+mod mass_properties_cuboid;
+mod mass_properties_cylinder;
+mod mass_properties_cone;
+mod mass_properties_capsule;
+mod mass_properties_convex;
+mod mass_properties_triangle;
+mod mass_properties_mesh;
+mod mass_properties_compound;
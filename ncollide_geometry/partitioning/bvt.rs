@@ -1,4 +1,4 @@
-//! A read-only Bounding Volume Tree.
+//! A Bounding Volume Tree with a static topology.
 
 use std::collections::BinaryHeap;
 
@@ -12,12 +12,14 @@ use math::Point;
 
 /// A Bounding Volume Tree.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct BVT<B, BV> {
     tree: Option<BVTNode<B, BV>>,
 }
 
 /// A node of the bounding volume tree.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub enum BVTNode<B, BV> {
     // XXX: give a faster access to the BV
     /// An internal node.
@@ -54,7 +56,7 @@ impl<B, BV> BVT<B, BV> {
     ///
     /// This will traverse the whole tree and call the visitor `.visit_internal(...)` (resp.
     /// `.visit_leaf(...)`) method on each internal (resp. leaf) node.
-    pub fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
+    pub fn visit<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
         match self.tree {
             Some(ref t) => t.visit(visitor),
             None => {}
@@ -74,7 +76,7 @@ impl<B, BV> BVT<B, BV> {
     ///
     /// Returns the content of the leaf with the smallest associated cost, and a result of
     /// user-defined type.
-    pub fn best_first_search<'a, N, BFS>(
+    pub fn best_first_search<'a, N, BFS: ?Sized>(
         &'a self,
         algorithm: &mut BFS,
     ) -> Option<(&'a B, BFS::UserData)>
@@ -118,6 +120,22 @@ impl<B, BV> BVT<B, BV> {
         BVT::new_with_partitioner(leaves, &mut Self::median_partitioner)
     }
 
+    /// Recomputes the bounding volumes of this tree bottom-up without modifying its topology.
+    ///
+    /// The bounding volume of each leaf is replaced by `leaf_bv` applied to its content, and the
+    /// bounding volume of each internal node by the merge of the bounding volumes of its children.
+    /// The tree may become less efficient if the leaves move a lot relative to each other.
+    pub fn refit<P, F>(&mut self, leaf_bv: &mut F)
+    where
+        P: Point,
+        BV: BoundingVolume<P>,
+        F: FnMut(&B) -> BV,
+    {
+        if let Some(ref mut t) = self.tree {
+            t.refit(leaf_bv)
+        }
+    }
+
     /// Construction function for a kdree to be used with `BVT::new_with_partitioner`.
     pub fn median_partitioner_with_centers<P, F: FnMut(&B, &BV) -> P>(
         depth: usize,
@@ -218,7 +236,7 @@ impl<B, BV> BVTNode<B, BV> {
         }
     }
 
-    fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
+    fn visit<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
         match *self {
             BVTNode::Internal(ref bv, ref left, ref right) => {
                 if visitor.visit_internal(bv) {
@@ -263,7 +281,7 @@ impl<B, BV> BVTNode<B, BV> {
         }
     }
 
-    fn best_first_search<'a, N, BFS>(
+    fn best_first_search<'a, N, BFS: ?Sized>(
         &'a self,
         algorithm: &mut BFS,
     ) -> Option<(&'a B, BFS::UserData)>
@@ -325,6 +343,22 @@ impl<B, BV> BVTNode<B, BV> {
         result
     }
 
+    fn refit<P, F>(&mut self, leaf_bv: &mut F)
+    where
+        P: Point,
+        BV: BoundingVolume<P>,
+        F: FnMut(&B) -> BV,
+    {
+        match *self {
+            BVTNode::Internal(ref mut bv, ref mut left, ref mut right) => {
+                left.refit(leaf_bv);
+                right.refit(leaf_bv);
+                *bv = left.bounding_volume().merged(right.bounding_volume());
+            }
+            BVTNode::Leaf(ref mut bv, ref b) => *bv = leaf_bv(b),
+        }
+    }
+
     fn depth(&self) -> usize {
         match *self {
             BVTNode::Internal(_, ref left, ref right) => 1 + na::max(left.depth(), right.depth()),
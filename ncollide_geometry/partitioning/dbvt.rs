@@ -1,10 +1,13 @@
 use std::ops::Index;
+use std::collections::BinaryHeap;
 
+use alga::general::Real;
 use na;
 
 use utils::data::SparseVec;
+use utils::data::ref_with_cost::RefWithCost;
 use math::Point;
-use partitioning::BVTVisitor;
+use partitioning::{BVTCostFn, BVTVisitor};
 use bounding_volume::BoundingVolume;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -46,6 +49,7 @@ enum DBVTNodeId {
 }
 
 /// A boundin volume hierarchy on which objects can be added or removed after construction.
+#[derive(Clone)]
 pub struct DBVT<P, B, BV> {
     root: DBVTNodeId,
     leaves: SparseVec<DBVTLeaf<P, B, BV>>,
@@ -66,6 +70,7 @@ pub struct DBVTLeaf<P, B, BV> {
 }
 
 /// Internal node of a DBVT. An internal node always has two children.
+#[derive(Clone)]
 struct DBVTInternal<P, BV> {
     /// The bounding volume of this node. It always encloses both its children bounding volumes.
     bounding_volume: BV,
@@ -309,17 +314,98 @@ impl<P: Point, B, BV: BoundingVolume<P>> DBVT<P, B, BV> {
         leaf
     }
 
+    /// Reference to the bounding volume of the tree root, or `None` if this DBVT is empty.
+    ///
+    /// The bounding volumes of the internal nodes are not shrunk when leaves are removed so
+    /// this may be larger than necessary.
+    #[inline]
+    pub fn root_bounding_volume(&self) -> Option<&BV> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.node_bounding_volume(self.root))
+        }
+    }
+
+    /// Performs a best-fist-search on the tree.
+    ///
+    /// Returns the content of the leaf with the smallest associated cost, and a result of
+    /// user-defined type.
+    pub fn best_first_search<'a, N, BFS: ?Sized>(
+        &'a self,
+        algorithm: &mut BFS,
+    ) -> Option<(&'a B, BFS::UserData)>
+    where
+        N: Real,
+        BFS: BVTCostFn<N, B, BV>,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut queue: BinaryHeap<RefWithCost<'a, N, DBVTNodeId>> = BinaryHeap::new();
+        let mut best_cost = N::max_value();
+        let mut result = None;
+
+        match algorithm.compute_bv_cost(self.node_bounding_volume(self.root)) {
+            Some(cost) => queue.push(RefWithCost::new(&self.root, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            match *node.object {
+                DBVTNodeId::Internal(i) => {
+                    let internal = &self.internals[i];
+
+                    for child in [&internal.left, &internal.right].iter() {
+                        let bv = self.node_bounding_volume(**child);
+
+                        if let Some(cost) = algorithm.compute_bv_cost(bv) {
+                            if cost < best_cost {
+                                queue.push(RefWithCost::new(*child, -cost))
+                            }
+                        }
+                    }
+                }
+                DBVTNodeId::Leaf(l) => {
+                    let leaf = &self.leaves[l];
+
+                    if let Some((cost, res)) = algorithm.compute_b_cost(&leaf.data) {
+                        if cost < best_cost {
+                            best_cost = cost;
+                            result = Some((&leaf.data, res));
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    #[inline]
+    fn node_bounding_volume(&self, node: DBVTNodeId) -> &BV {
+        match node {
+            DBVTNodeId::Leaf(i) => &self.leaves[i].bounding_volume,
+            DBVTNodeId::Internal(i) => &self.internals[i].bounding_volume,
+        }
+    }
+
     /// Traverses this tree using an object implementing the `BVTVisitor`trait.
     ///
     /// This will traverse the whole tree and call the visitor `.visit_internal(...)` (resp.
     /// `.visit_leaf(...)`) method on each internal (resp. leaf) node.
-    pub fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
+    pub fn visit<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
         if !self.is_empty() {
             self.visit_node(visitor, self.root);
         }
     }
 
-    fn visit_node<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis, node: DBVTNodeId) {
+    fn visit_node<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis, node: DBVTNodeId) {
         match node {
             DBVTNodeId::Internal(i) => {
                 let internal = &self.internals[i];
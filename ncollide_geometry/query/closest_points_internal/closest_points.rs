@@ -0,0 +1,24 @@
+use std::mem;
+
+/// Closest points information.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ClosestPoints<P> {
+    /// The two objects are intersecting.
+    Intersecting,
+    /// The two objects are non-intersecting but closer than a given distance.
+    ///
+    /// The two points are the closest points of the first and second object, in world-space.
+    WithinMargin(P, P),
+    /// The two objects are non-intersecting and further than a given distance.
+    Disjoint,
+}
+
+impl<P> ClosestPoints<P> {
+    /// Swaps the two points of `self`, if any.
+    #[inline]
+    pub fn flip(&mut self) {
+        if let ClosestPoints::WithinMargin(ref mut p1, ref mut p2) = *self {
+            mem::swap(p1, p2)
+        }
+    }
+}
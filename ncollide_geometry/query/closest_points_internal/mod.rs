@@ -0,0 +1,27 @@
+//! Implementation details of the `closest_points` function.
+
+pub use self::closest_points::ClosestPoints;
+pub use self::ball_against_ball::ball_against_ball;
+pub use self::support_map_against_support_map::support_map_against_support_map;
+pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
+pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
+pub use self::torus_against_support_map::{support_map_against_torus, torus_against_support_map};
+pub use self::sdf_grid_against_support_map::{sdf_grid_against_support_map,
+                                             support_map_against_sdf_grid};
+pub use self::round_support_map_against_round_support_map::round_support_map_against_round_support_map;
+pub use self::shape_against_shape::shape_against_shape as closest_points;
+pub use self::shape_against_shape::shape_against_shape_with_dispatcher as closest_points_with_dispatcher;
+pub use self::shape_against_shape::try_shape_against_shape as try_closest_points;
+pub(crate) use self::shape_against_shape::default_shape_against_shape;
+pub use self::composite_shape_against_shape::{composite_shape_against_shape,
+                                              shape_against_composite_shape};
+
+mod closest_points;
+mod ball_against_ball;
+mod support_map_against_support_map;
+mod plane_against_support_map;
+mod torus_against_support_map;
+mod sdf_grid_against_support_map;
+mod round_support_map_against_round_support_map;
+mod shape_against_shape;
+mod composite_shape_against_shape;
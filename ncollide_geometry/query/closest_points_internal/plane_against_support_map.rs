@@ -0,0 +1,56 @@
+use alga::linear::Translation;
+use na;
+use math::{Isometry, Point};
+use query::ClosestPoints;
+use shape::{Plane, SupportMap};
+
+/// Closest points between a plane and a support-mapped shape.
+pub fn plane_against_support_map<P, M, G: ?Sized>(
+    mplane: &M,
+    plane: &Plane<P::Vector>,
+    mother: &M,
+    other: &G,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    assert!(
+        margin >= na::zero(),
+        "The closest points margin must be positive or null."
+    );
+
+    let plane_normal = mplane.rotate_vector(plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let deepest = other.support_point(mother, &-plane_normal);
+
+    let distance = na::dot(&plane_normal, &(deepest - plane_center));
+
+    if distance <= na::zero() {
+        ClosestPoints::Intersecting
+    } else if distance <= margin {
+        ClosestPoints::WithinMargin(deepest + plane_normal * (-distance), deepest)
+    } else {
+        ClosestPoints::Disjoint
+    }
+}
+
+/// Closest points between a support-mapped shape and a plane.
+pub fn support_map_against_plane<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    mplane: &M,
+    plane: &Plane<P::Vector>,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = plane_against_support_map(mplane, plane, mother, other, margin);
+    res.flip();
+    res
+}
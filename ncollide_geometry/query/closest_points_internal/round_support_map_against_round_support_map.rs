@@ -0,0 +1,46 @@
+use num::Zero;
+
+use na;
+use query::ClosestPoints;
+use query::closest_points_internal;
+use shape::SupportMap;
+use math::{Isometry, Point};
+
+/// Closest points between two support-mapped shapes dilated by balls of radius `radius1` and
+/// `radius2`.
+pub fn round_support_map_against_round_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    radius1: P::Real,
+    m2: &M,
+    g2: &G2,
+    radius2: P::Real,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let sum_radius = radius1 + radius2;
+    let core_margin = margin + sum_radius;
+
+    match closest_points_internal::support_map_against_support_map(m1, g1, m2, g2, core_margin) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            let p1p2 = p2 - p1;
+            let distance = na::norm(&p1p2);
+
+            if distance <= sum_radius || distance.is_zero() {
+                ClosestPoints::Intersecting
+            } else if distance - sum_radius <= margin {
+                let normal = p1p2 / distance;
+
+                ClosestPoints::WithinMargin(p1 + normal * radius1, p2 + normal * (-radius2))
+            } else {
+                ClosestPoints::Disjoint
+            }
+        }
+        res => res,
+    }
+}
@@ -0,0 +1,55 @@
+use na;
+use query::{contacts_internal, ClosestPoints};
+use shape::{SdfGrid, SupportMap};
+use math::{Isometry, Point};
+
+/// Closest points between a signed distance field grid and a support-mapped shape.
+///
+/// The closest points are those of the contact computed by
+/// `contacts_internal::sdf_grid_against_support_map`.
+pub fn sdf_grid_against_support_map<P, M, G: ?Sized>(
+    msdf: &M,
+    sdf: &SdfGrid<P>,
+    mother: &M,
+    other: &G,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    assert!(
+        margin >= na::zero(),
+        "The closest points margin must be positive or null."
+    );
+
+    match contacts_internal::sdf_grid_against_support_map(msdf, sdf, mother, other, margin) {
+        Some(c) => {
+            if c.depth >= na::zero() {
+                ClosestPoints::Intersecting
+            } else {
+                ClosestPoints::WithinMargin(c.world1, c.world2)
+            }
+        }
+        None => ClosestPoints::Disjoint,
+    }
+}
+
+/// Closest points between a support-mapped shape and a signed distance field grid.
+pub fn support_map_against_sdf_grid<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    msdf: &M,
+    sdf: &SdfGrid<P>,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = sdf_grid_against_support_map(msdf, sdf, mother, other, margin);
+    res.flip();
+    res
+}
@@ -0,0 +1,112 @@
+use num::Zero;
+
+use alga::linear::Translation;
+use na;
+use query::algorithms::gjk::GJKResult;
+use query::algorithms::gjk;
+use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::ClosestPoints;
+use shape::{self, AnnotatedPoint, SupportMap};
+use math::{Isometry, Point};
+
+/// Closest points between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            margin,
+            &mut VoronoiSimplex2::new(),
+            None,
+        ).0
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            margin,
+            &mut VoronoiSimplex3::new(),
+            None,
+        ).0
+    } else {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            margin,
+            &mut JohnsonSimplex::new_w_tls(),
+            None,
+        ).0
+    }
+}
+
+/// Closest points between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// This allows a more fine grained control other the underlying GJK algorigtm.
+/// The vector returned is the separating axis found by the GJK, or zero if the shapes intersect.
+pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+    simplex: &mut S,
+    init_dir: Option<P::Vector>,
+) -> (ClosestPoints<P>, P::Vector)
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    assert!(
+        margin >= na::zero(),
+        "The closest points margin must be positive or null."
+    );
+
+    let mut dir = match init_dir {
+        None => m1.translation().to_vector() - m2.translation().to_vector(),
+        Some(dir) => dir,
+    };
+
+    if dir.is_zero() {
+        dir[0] = na::one();
+    }
+
+    simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
+
+    match gjk::closest_points_with_max_dist(m1, g1, m2, g2, margin, simplex) {
+        GJKResult::Projection((p1, p2)) => {
+            let p1p2 = p2 - p1;
+            let distance_squared = na::norm_squared(&p1p2);
+
+            if distance_squared.is_zero() {
+                (ClosestPoints::Intersecting, na::zero())
+            } else if distance_squared <= margin * margin {
+                (ClosestPoints::WithinMargin(p1, p2), p1p2)
+            } else {
+                (ClosestPoints::Disjoint, p1p2)
+            }
+        }
+        GJKResult::NoIntersection(dir) => (ClosestPoints::Disjoint, dir),
+        GJKResult::Intersection => (ClosestPoints::Intersecting, na::zero()),
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+}
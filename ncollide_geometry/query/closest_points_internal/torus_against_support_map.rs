@@ -0,0 +1,66 @@
+use na;
+use query::ClosestPoints;
+use query::closest_points_internal;
+use shape::{SupportMap, Torus};
+use math::{Isometry, Point};
+
+/// Closest points between a torus and a support-mapped shape.
+///
+/// The core circle of the torus is approximated by a polygon which segments are dilated by the
+/// torus minor radius.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    mother: &M,
+    other: &G,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = ClosestPoints::Disjoint;
+    let mut res_dist = margin;
+
+    for i in 0..torus.num_core_segments::<P>() {
+        let segment = torus.core_segment::<P>(i);
+
+        match closest_points_internal::round_support_map_against_round_support_map(
+            mtorus,
+            &segment,
+            torus.minor_radius(),
+            mother,
+            other,
+            na::zero(),
+            res_dist,
+        ) {
+            ClosestPoints::Intersecting => return ClosestPoints::Intersecting,
+            ClosestPoints::WithinMargin(p1, p2) => {
+                res_dist = na::distance(&p1, &p2);
+                res = ClosestPoints::WithinMargin(p1, p2);
+            }
+            ClosestPoints::Disjoint => {}
+        }
+    }
+
+    res
+}
+
+/// Closest points between a support-mapped shape and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = torus_against_support_map(mtorus, torus, mother, other, margin);
+    res.flip();
+    res
+}
@@ -2,7 +2,7 @@ use na;
 use partitioning::BoundingVolumeInterferencesCollector;
 use bounding_volume::BoundingVolume;
 use shape::{CompositeShape, Shape};
-use query::Contact;
+use query::{Contact, QueryDispatcher, Unsupported};
 use query::contacts_internal;
 use math::{Isometry, Point};
 
@@ -64,12 +64,13 @@ pub fn manifold_shape_against_composite_shape<N, P, V, AV, M, G1, G2>(
 
 /// Best contact between a composite shape (`Mesh`, `Compound`) and any other shape.
 pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     g1: &G1,
     m2: &M,
     g2: &Shape<P, M>,
     prediction: P::Real,
-) -> Option<Contact<P>>
+) -> Result<Option<Contact<P>>, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
@@ -83,54 +84,70 @@ where
 
     {
         let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
-        g1.bvt().visit(&mut visitor);
+        g1.visit_parts(&mut visitor);
     }
 
     let mut res = None::<Contact<P>>;
+    let mut error = None;
 
     for i in interferences.into_iter() {
-        g1.map_part_at(i, &mut |_, part| match contacts_internal::contact_internal(
-            m1,
-            part,
-            m2,
-            g2,
-            prediction,
-        ) {
-            Some(c) => {
-                let replace = match res {
-                    Some(ref cbest) => c.depth > cbest.depth,
-                    None => true,
-                };
-
-                if replace {
-                    res = Some(c)
+        g1.map_transformed_part_at(i, m1, &mut |m1, part| {
+            match contacts_internal::contact_with_dispatcher(
+                dispatcher,
+                m1,
+                part,
+                m2,
+                g2,
+                prediction,
+            ) {
+                Ok(Some(c)) => {
+                    let replace = match res {
+                        Some(ref cbest) => c.depth > cbest.depth,
+                        None => true,
+                    };
+
+                    if replace {
+                        res = Some(c)
+                    }
                 }
+                Ok(None) => {}
+                Err(err) => error = Some(err),
             }
-            None => {}
         });
+
+        if let Some(err) = error {
+            return Err(err);
+        }
     }
 
-    res
+    Ok(res)
 }
 
 /// Best contact between a shape and a composite (`Mesh`, `Compound`) shape.
 pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     g1: &Shape<P, M>,
     m2: &M,
     g2: &G2,
     prediction: P::Real,
-) -> Option<Contact<P>>
+) -> Result<Option<Contact<P>>, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
     G2: CompositeShape<P, M>,
 {
-    let mut res = composite_shape_against_shape(m2, g2, m1, g1, prediction);
+    match composite_shape_against_shape(dispatcher, m2, g2, m1, g1, prediction) {
+        Ok(mut res) => {
+            for c in res.iter_mut() {
+                c.flip()
+            }
 
-    for c in res.iter_mut() {
-        c.flip()
+            Ok(res)
+        }
+        Err(mut err) => {
+            err.flip();
+            Err(err)
+        }
     }
-
-    res
 }
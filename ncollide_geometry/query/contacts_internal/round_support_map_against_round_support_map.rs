@@ -0,0 +1,37 @@
+use query::Contact;
+use query::contacts_internal;
+use shape::SupportMap;
+use math::{Isometry, Point};
+
+/// Contact between two support-mapped shapes dilated by balls of radius `radius1` and `radius2`.
+///
+/// The contact is computed between the core shapes and then offset by the radiuses. This is more
+/// accurate than the contact between the support mappings of the rounded shapes since the
+/// penetration depth is retrieved without resorting to the EPA as long as the core shapes do
+/// not intersect.
+pub fn round_support_map_against_round_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    radius1: P::Real,
+    m2: &M,
+    g2: &G2,
+    radius2: P::Real,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let total_radius = radius1 + radius2;
+
+    contacts_internal::support_map_against_support_map(m1, g1, m2, g2, prediction + total_radius)
+        .map(|mut contact| {
+            contact.world1 = contact.world1 + *contact.normal * radius1;
+            contact.world2 = contact.world2 + *contact.normal * (-radius2);
+            contact.depth = contact.depth + total_radius;
+
+            contact
+        })
+}
@@ -0,0 +1,147 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use alga::linear::Translation;
+use na::{self, Unit};
+use bounding_volume::{self, BoundingVolume};
+use query::Contact;
+use query::algorithms::{gjk, JohnsonSimplex, Simplex, VoronoiSimplex2, VoronoiSimplex3};
+use shape::{SdfGrid, SupportMap};
+use math::{Isometry, Point, Vector};
+
+/// Contact between a signed distance field grid and a support-mapped shape (Cuboid, ConvexHull,
+/// etc.)
+///
+/// The surface of the support-mapped shape is sampled by its support points toward a fixed set of
+/// directions, and the sample deepest inside of the distance field is kept. This sample is then
+/// refined by a projected gradient descent of the distance field on the support-mapped shape.
+pub fn sdf_grid_against_support_map<P, M, G: ?Sized>(
+    msdf: &M,
+    sdf: &SdfGrid<P>,
+    mother: &M,
+    other: &G,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let sdf_aabb = bounding_volume::aabb(sdf, msdf);
+    let other_aabb = bounding_volume::support_map_aabb(mother, other).loosened(prediction);
+
+    if !sdf_aabb.intersects(&other_aabb) {
+        return None;
+    }
+
+    let eps = P::Real::default_epsilon().sqrt();
+    let other_center = mother.translate_point(&P::origin());
+    let mut deepest = other.support_point(mother, &(sdf_aabb.center() - other_center));
+    let mut deepest_distance = sdf.distance_at(&msdf.inverse_transform_point(&deepest));
+
+    P::Vector::sample_sphere(|dir: P::Vector| {
+        let pt = other.support_point(mother, &dir);
+        let distance = sdf.distance_at(&msdf.inverse_transform_point(&pt));
+
+        if distance < deepest_distance {
+            deepest = pt;
+            deepest_distance = distance;
+        }
+    });
+
+    let tolerance = sdf.cell_size() * eps;
+
+    for _ in 0..50 {
+        let dir = match na::try_normalize(&sdf_gradient(msdf, sdf, &deepest), eps) {
+            Some(dir) => dir,
+            None => break,
+        };
+
+        let step = if deepest_distance.abs() > sdf.cell_size() {
+            deepest_distance.abs()
+        } else {
+            sdf.cell_size()
+        };
+
+        let pt = project_on_support_map(mother, other, &(deepest + dir * -step));
+        let distance = sdf.distance_at(&msdf.inverse_transform_point(&pt));
+
+        if distance < deepest_distance - tolerance {
+            deepest = pt;
+            deepest_distance = distance;
+        } else {
+            break;
+        }
+    }
+
+    if deepest_distance > prediction {
+        return None;
+    }
+
+    na::try_normalize(&sdf_gradient(msdf, sdf, &deepest), eps).map(|normal| {
+        let world1 = deepest + normal * -deepest_distance;
+
+        Contact::new(world1, deepest, Unit::new_unchecked(normal), -deepest_distance)
+    })
+}
+
+// The gradient of the distance field at a world-space point, expressed in world-space.
+fn sdf_gradient<P: Point, M: Isometry<P>>(msdf: &M, sdf: &SdfGrid<P>, pt: &P) -> P::Vector {
+    let local_gradient = sdf.distance_and_gradient_at(&msdf.inverse_transform_point(pt)).1;
+    msdf.rotate_vector(&local_gradient)
+}
+
+// The point of the solid support-mapped shape closest to `pt`.
+fn project_on_support_map<P, M, G: ?Sized>(m: &M, g: &G, pt: &P) -> P
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    match na::dimension::<P::Vector>() {
+        2 => project_on_support_map_with_simplex(m, g, pt, &mut VoronoiSimplex2::new()),
+        3 => project_on_support_map_with_simplex(m, g, pt, &mut VoronoiSimplex3::new()),
+        _ => project_on_support_map_with_simplex(m, g, pt, &mut JohnsonSimplex::new_w_tls()),
+    }
+}
+
+fn project_on_support_map_with_simplex<P, M, G: ?Sized, S>(
+    m: &M,
+    g: &G,
+    pt: &P,
+    simplex: &mut S,
+) -> P
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+    S: Simplex<P>,
+{
+    let m = m.append_translation(&M::Translation::from_vector(-pt.coordinates()).unwrap());
+
+    simplex.reset(g.support_point(&m, &-pt.coordinates()));
+
+    match gjk::project_origin(&m, g, simplex) {
+        Some(p) => p + pt.coordinates(),
+        None => *pt,
+    }
+}
+
+/// Contact between a support-mapped shape (Cuboid, ConvexHull, etc.) and a signed distance field
+/// grid.
+pub fn support_map_against_sdf_grid<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    msdf: &M,
+    sdf: &SdfGrid<P>,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    sdf_grid_against_support_map(msdf, sdf, mother, other, prediction).map(|mut c| {
+        c.flip();
+        c
+    })
+}
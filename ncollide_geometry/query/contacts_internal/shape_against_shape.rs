@@ -1,12 +1,16 @@
 use alga::linear::Translation;
+use na;
 use math::{Isometry, Point};
-use shape::{Ball, Plane, Shape};
+use shape::{Ball, Plane, SdfGrid, Shape, Torus};
 use query::contacts_internal;
 use query::contacts_internal::Contact;
+use query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
 
 /// Computes one contact point between two shapes.
 ///
 /// Returns `None` if the objects are separated by a distance greater than `prediction`.
+///
+/// Panics if the pair of shapes is not supported.
 pub fn shape_against_shape<P, M>(
     m1: &M,
     g1: &Shape<P, M>,
@@ -18,7 +22,74 @@ where
     P: Point,
     M: Isometry<P>,
 {
-    if let (Some(b1), Some(b2)) = (
+    match try_shape_against_shape(m1, g1, m2, g2, prediction) {
+        Ok(res) => res,
+        Err(err) => panic!(
+            "No algorithm known to compute a contact point between the given pair of shapes: {}.",
+            err
+        ),
+    }
+}
+
+/// Computes one contact point between two shapes, or returns an `Unsupported` error if the
+/// pair of shapes is not supported.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    shape_against_shape_with_dispatcher(&DefaultQueryDispatcher, m1, g1, m2, g2, prediction)
+}
+
+/// Computes one contact point between two shapes, using `dispatcher` to select the algorithm.
+///
+/// Composite shapes not handled by `dispatcher` are decomposed and each of their parts is
+/// dispatched through `dispatcher` as well. Returns an `Unsupported` error if the pair of shapes,
+/// or any pair made of their parts, is not supported.
+pub fn shape_against_shape_with_dispatcher<P, M>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = dispatcher.contact(m1, g1, m2, g2, prediction);
+
+    if res.is_ok() {
+        res
+    } else if let Some(c1) = g1.as_composite_shape() {
+        contacts_internal::composite_shape_against_shape(dispatcher, m1, c1, m2, g2, prediction)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        contacts_internal::shape_against_composite_shape(dispatcher, m1, g1, m2, c2, prediction)
+    } else {
+        res
+    }
+}
+
+// The contact point between two non-composite shapes, as computed by the `DefaultQueryDispatcher`.
+pub(crate) fn default_shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = if let (Some(b1), Some(b2)) = (
         g1.as_shape::<Ball<P::Real>>(),
         g2.as_shape::<Ball<P::Real>>(),
     ) {
@@ -30,13 +101,40 @@ where
         contacts_internal::plane_against_support_map(m1, p1, m2, s2, prediction)
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
         contacts_internal::support_map_against_plane(m1, s1, m2, p2, prediction)
+    } else if let (Some(t1), Some(s2)) = (g1.as_shape::<Torus<P::Real>>(), g2.as_support_map()) {
+        contacts_internal::torus_against_support_map(m1, t1, m2, s2, prediction)
+    } else if let (Some(s1), Some(t2)) = (g1.as_support_map(), g2.as_shape::<Torus<P::Real>>()) {
+        contacts_internal::support_map_against_torus(m1, s1, m2, t2, prediction)
+    } else if let (Some(d1), Some(s2)) = (g1.as_shape::<SdfGrid<P>>(), g2.as_support_map()) {
+        contacts_internal::sdf_grid_against_support_map(m1, d1, m2, s2, prediction)
+    } else if let (Some(s1), Some(d2)) = (g1.as_support_map(), g2.as_shape::<SdfGrid<P>>()) {
+        contacts_internal::support_map_against_sdf_grid(m1, s1, m2, d2, prediction)
+    } else if let (Some((c1, r1)), Some(s2)) = (g1.as_round_shape(), g2.as_support_map()) {
+        let (c2, r2) = g2.as_round_shape().unwrap_or((s2, na::zero()));
+        contacts_internal::round_support_map_against_round_support_map(
+            m1,
+            c1,
+            r1,
+            m2,
+            c2,
+            r2,
+            prediction,
+        )
+    } else if let (Some(s1), Some((c2, r2))) = (g1.as_support_map(), g2.as_round_shape()) {
+        contacts_internal::round_support_map_against_round_support_map(
+            m1,
+            s1,
+            na::zero(),
+            m2,
+            c2,
+            r2,
+            prediction,
+        )
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         contacts_internal::support_map_against_support_map(m1, s1, m2, s2, prediction)
-    } else if let Some(c1) = g1.as_composite_shape() {
-        contacts_internal::composite_shape_against_shape(m1, c1, m2, g2, prediction)
-    } else if let Some(c2) = g2.as_composite_shape() {
-        contacts_internal::shape_against_composite_shape(m1, g1, m2, c2, prediction)
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
-    }
+        return Err(Unsupported::new(g1, g2));
+    };
+
+    Ok(res)
 }
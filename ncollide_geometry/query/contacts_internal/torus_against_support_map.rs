@@ -0,0 +1,79 @@
+use bounding_volume::{self, BoundingVolume};
+use query::Contact;
+use query::contacts_internal;
+use shape::{SupportMap, Torus};
+use math::{Isometry, Point};
+
+/// Contact between a torus and a support-mapped shape (Cuboid, ConvexHull, etc.)
+///
+/// The core circle of the torus is approximated by a polygon. Each segment of this polygon
+/// dilated by the torus minor radius is tested against the support-mapped shape, and the deepest
+/// contact is returned.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    mother: &M,
+    other: &G,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let minor = torus.minor_radius();
+    let other_aabb =
+        bounding_volume::support_map_aabb(mother, other).loosened(minor + prediction);
+    let mut res: Option<Contact<P>> = None;
+
+    for i in 0..torus.num_core_segments::<P>() {
+        let segment = torus.core_segment::<P>(i);
+
+        if !bounding_volume::aabb(&segment, mtorus).intersects(&other_aabb) {
+            continue;
+        }
+
+        let contact = contacts_internal::support_map_against_support_map(
+            mtorus,
+            &segment,
+            mother,
+            other,
+            prediction + minor,
+        );
+
+        if let Some(mut contact) = contact {
+            contact.world1 = contact.world1 + *contact.normal * minor;
+            contact.depth = contact.depth + minor;
+
+            let is_deeper = match res {
+                Some(ref best) => contact.depth > best.depth,
+                None => true,
+            };
+
+            if is_deeper {
+                res = Some(contact)
+            }
+        }
+    }
+
+    res
+}
+
+/// Contact between a support-mapped shape (Cuboid, ConvexHull, etc.) and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    torus_against_support_map(mtorus, torus, mother, other, prediction).map(|mut c| {
+        c.flip();
+        c
+    })
+}
@@ -1,59 +1,74 @@
 use std::marker::PhantomData;
 
 use alga::general::Id;
+use num::Bounded;
 
 use na;
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
-use shape::{CompositeShape, Shape};
+use shape::{composite_shape, CompositeShape, Shape};
 use query::distance_internal;
-use query::PointQuery;
+use query::{PointQuery, QueryDispatcher, Unsupported};
 use math::{Isometry, Point};
 
 /// Smallest distance between a composite shape and any other shape.
+///
+/// Returns `P::Real::max_value()` if the composite shape is empty.
 pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     g1: &G1,
     m2: &M,
     g2: &Shape<P, M>,
-) -> P::Real
+) -> Result<P::Real, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
     G1: CompositeShape<P, M>,
 {
-    let mut cost_fn = CompositeShapeAgainstAnyDistCostFn::new(m1, g1, m2, g2);
+    let mut cost_fn = CompositeShapeAgainstAnyDistCostFn::new(dispatcher, m1, g1, m2, g2);
+    let res = composite_shape::best_first_search(g1, &mut cost_fn).map(|(_, res)| res);
 
-    g1.bvt()
-        .best_first_search(&mut cost_fn)
-        .map(|(_, res)| res)
-        .expect("The composite shape must not be empty.")
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res.unwrap_or_else(P::Real::max_value)),
+    }
 }
 
 /// Smallest distance between a shape and a composite shape.
+///
+/// Returns `P::Real::max_value()` if the composite shape is empty.
 pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     g1: &Shape<P, M>,
     m2: &M,
     g2: &G2,
-) -> P::Real
+) -> Result<P::Real, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
     G2: CompositeShape<P, M>,
 {
-    composite_shape_against_shape(m2, g2, m1, g1)
+    composite_shape_against_shape(dispatcher, m2, g2, m1, g1).map_err(|mut err| {
+        err.flip();
+        err
+    })
 }
 
 struct CompositeShapeAgainstAnyDistCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
     msum_shift: P::Vector,
     msum_margin: P::Vector,
 
+    dispatcher: &'a QueryDispatcher<P, M>,
     m1: &'a M,
     g1: &'a G1,
     m2: &'a M,
     g2: &'a Shape<P, M>,
 
+    // The first pair of parts not supported by the dispatcher.
+    error: Option<Unsupported>,
+
     point_type: PhantomData<P>,
 }
 
@@ -64,6 +79,7 @@ where
     G1: CompositeShape<P, M>,
 {
     pub fn new(
+        dispatcher: &'a QueryDispatcher<P, M>,
         m1: &'a M,
         g1: &'a G1,
         m2: &'a M,
@@ -75,10 +91,12 @@ where
         CompositeShapeAgainstAnyDistCostFn {
             msum_shift: -ls_aabb2.center().coordinates(),
             msum_margin: ls_aabb2.half_extents(),
+            dispatcher: dispatcher,
             m1: m1,
             g1: g1,
             m2: m2,
             g2: g2,
+            error: None,
             point_type: PhantomData,
         }
     }
@@ -94,6 +112,11 @@ where
     type UserData = P::Real;
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // No need to continue if some parts are not supported.
+        if self.error.is_some() {
+            return None;
+        }
+
         // Compute the minkowski sum of the two AABBs.
         let msum = AABB::new(
             *bv.mins() + self.msum_shift + (-self.msum_margin),
@@ -109,9 +132,16 @@ where
         let mut res = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
-            let distance = distance_internal::distance(m1, g1, self.m2, self.g2);
-
-            res = Some((distance, distance))
+            match distance_internal::distance_with_dispatcher(
+                self.dispatcher,
+                m1,
+                g1,
+                self.m2,
+                self.g2,
+            ) {
+                Ok(distance) => res = Some((distance, distance)),
+                Err(err) => self.error = Some(err),
+            }
         });
 
         res
@@ -0,0 +1,57 @@
+use num::Bounded;
+use na;
+use query::distance_internal;
+use shape::{SupportMap, Torus};
+use math::{Isometry, Point};
+
+/// Distance between a torus and a support-mapped shape.
+///
+/// The core circle of the torus is approximated by a polygon. The result is the smallest distance
+/// between the support-mapped shape and the segments of this polygon dilated by the torus minor
+/// radius.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    mother: &M,
+    other: &G,
+) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = P::Real::max_value();
+
+    for i in 0..torus.num_core_segments::<P>() {
+        let segment = torus.core_segment::<P>(i);
+        let dist =
+            distance_internal::support_map_against_support_map(mtorus, &segment, mother, other);
+
+        let dist = dist - torus.minor_radius();
+
+        if dist < res {
+            res = dist;
+        }
+
+        if res <= na::zero() {
+            return na::zero();
+        }
+    }
+
+    res
+}
+
+/// Distance between a support-mapped shape and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    torus_against_support_map(mtorus, torus, mother, other)
+}
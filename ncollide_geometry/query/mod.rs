@@ -5,25 +5,63 @@ pub use self::contacts_internal::{Contact, ContactPrediction};
 #[doc(inline)]
 pub use self::contacts_internal::contact_internal as contact;
 #[doc(inline)]
+pub use self::contacts_internal::{contact_with_dispatcher, try_contact};
+#[doc(inline)]
 pub use self::proximity_internal::Proximity;
 #[doc(inline)]
 pub use self::proximity_internal::proximity_internal as proximity;
 #[doc(inline)]
-pub use self::distance_internal::distance;
+pub use self::proximity_internal::{proximity_with_dispatcher, try_proximity};
+#[doc(inline)]
+pub use self::closest_points_internal::ClosestPoints;
+#[doc(inline)]
+pub use self::closest_points_internal::{closest_points, closest_points_with_dispatcher,
+                                        try_closest_points};
+#[doc(inline)]
+pub use self::distance_internal::{distance, distance_with_dispatcher, try_distance};
+#[doc(inline)]
+pub use self::signed_distance_internal::SignedDistance;
+#[doc(inline)]
+pub use self::signed_distance_internal::{signed_distance, signed_distance_with_dispatcher,
+                                         try_signed_distance};
+#[doc(inline)]
+pub use self::time_of_impact_internal::{time_of_impact, time_of_impact_with_dispatcher,
+                                         try_time_of_impact};
+#[doc(inline)]
+pub use self::time_of_impact_internal::{nonlinear_time_of_impact,
+                                         nonlinear_time_of_impact_with_dispatcher,
+                                         try_nonlinear_time_of_impact};
+#[doc(inline)]
+pub use self::time_of_impact_internal::{ConstantVelocityRigidMotion,
+                                         ConstantVelocityRigidMotion2,
+                                         ConstantVelocityRigidMotion3, RigidMotion};
+#[doc(inline)]
+pub use self::shape_cast_internal::{TOIStatus, TOI};
+#[doc(inline)]
+pub use self::shape_cast_internal::{shape_cast, shape_cast_with_dispatcher, try_shape_cast};
+#[doc(inline)]
+pub use self::query_dispatcher::{DefaultQueryDispatcher, QueryDispatcher, QueryDispatcherChain};
 #[doc(inline)]
-pub use self::time_of_impact_internal::time_of_impact;
+pub use self::unsupported::Unsupported;
 #[doc(inline)]
-pub use self::ray_internal::{Ray, Ray2, Ray3, RayCast, RayInterferencesCollector, RayIntersection,
-                             RayIntersection2, RayIntersection3, RayIntersectionCostFn};
+pub use self::ray_internal::{CullMode, Ray, Ray2, Ray3, RayCast, RayInterferencesCollector,
+                             RayIntersection, RayIntersection2, RayIntersection3,
+                             RayIntersectionCostFn, TetMeshRayIntersection};
 #[doc(inline)]
-pub use self::point_internal::{PointInterferencesCollector, PointProjection, PointQuery,
-                               PointQueryWithLocation, SegmentPointLocation,
-                               TetrahedronPointLocation, TrianglePointLocation};
+pub use self::point_internal::{PointInterferencesCollector, PointProjection,
+                               PointProjectionInfo, PointQuery, PointQueryWithLocation,
+                               SegmentPointLocation, TetrahedronPointLocation,
+                               TrianglePointLocation};
 
 pub mod algorithms;
 pub mod contacts_internal;
 pub mod distance_internal;
+pub mod closest_points_internal;
+pub mod signed_distance_internal;
 pub mod proximity_internal;
 pub mod time_of_impact_internal;
+pub mod shape_cast_internal;
 pub mod ray_internal;
 pub mod point_internal;
+mod query_dispatcher;
+mod unsupported;
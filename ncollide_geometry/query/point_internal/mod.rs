@@ -3,6 +3,7 @@
 #[doc(inline)]
 pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation};
 pub use self::point_bvt::PointInterferencesCollector;
+pub use self::point_mesh::PointProjectionInfo;
 pub use self::point_segment::SegmentPointLocation;
 pub use self::point_triangle::TrianglePointLocation;
 pub use self::point_tetrahedron::TetrahedronPointLocation;
@@ -11,6 +12,10 @@ pub use self::point_tetrahedron::TetrahedronPointLocation;
 pub mod point_query;
 mod point_plane;
 mod point_ball;
+mod point_ellipsoid;
+mod point_torus;
+mod point_round_shape;
+mod point_scaled;
 mod point_cuboid;
 mod point_aabb;
 mod point_bounding_sphere;
@@ -18,7 +23,12 @@ mod point_support_map;
 mod point_segment;
 mod point_triangle;
 mod point_tetrahedron;
+mod point_composite_shape;
 mod point_compound;
+mod point_dynamic_compound;
 mod point_mesh;
+mod point_tet_mesh;
+mod point_heightfield;
+mod point_sdf_grid;
 mod point_shape;
 mod point_bvt;
@@ -0,0 +1,139 @@
+use alga::general::Id;
+use na;
+use query::{PointProjection, PointQuery};
+use bounding_volume::AABB;
+use shape::{composite_shape, CompositeShape};
+use partitioning::{BVTCostFn, BVTVisitor};
+use math::{Isometry, Point};
+
+/// Projects a point on a composite shape.
+///
+/// A composite shape without any part is considered to be a single point at its local origin.
+pub(crate) fn composite_shape_project_point<P, M, G>(
+    m: &M,
+    shape: &G,
+    point: &P,
+    solid: bool,
+) -> PointProjection<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    // XXX: if solid == false, this might return internal projection.
+    let ls_pt = m.inverse_transform_point(point);
+    let mut cost_fn = CompositeShapePointProjCostFn::new(shape, &ls_pt, solid);
+
+    match composite_shape::best_first_search::<P, M, _, _>(shape, &mut cost_fn) {
+        Some((_, mut proj)) => {
+            proj.point = m.transform_point(&proj.point);
+            proj
+        }
+        None => PointProjection::new(false, m.transform_point(&P::origin())),
+    }
+}
+
+/// Tests if a point is inside of any part of a composite shape.
+pub(crate) fn composite_shape_contains_point<P, M, G>(m: &M, shape: &G, point: &P) -> bool
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    let ls_pt = m.inverse_transform_point(point);
+    let mut test = PointContainementTest::new(shape, &ls_pt);
+
+    shape.visit_parts(&mut test);
+
+    test.found
+}
+
+/*
+ * Costs function.
+ */
+/// A search for the part of a composite shape closest to a point.
+pub(crate) struct CompositeShapePointProjCostFn<'a, P: 'a + Point, M: 'a> {
+    shape: &'a CompositeShape<P, M>,
+    point: &'a P,
+    solid: bool,
+}
+
+impl<'a, P: Point, M> CompositeShapePointProjCostFn<'a, P, M> {
+    /// Creates a search for the part of `shape` closest to the local-space `point`.
+    pub(crate) fn new(shape: &'a CompositeShape<P, M>, point: &'a P, solid: bool) -> Self {
+        CompositeShapePointProjCostFn {
+            shape: shape,
+            point: point,
+            solid: solid,
+        }
+    }
+}
+
+impl<'a, P, M> BVTCostFn<P::Real, usize, AABB<P>> for CompositeShapePointProjCostFn<'a, P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, PointProjection<P>)> {
+        let mut res = None;
+
+        self.shape.map_part_at(*b, &mut |objm, obj| {
+            let proj = obj.project_point(objm, self.point, self.solid);
+
+            res = Some((na::distance(self.point, &proj.point), proj));
+        });
+
+        res
+    }
+}
+
+/*
+ * Visitor.
+ */
+/// Bounding Volume Tree visitor testing if a point is inside of any part of a composite shape.
+pub(crate) struct PointContainementTest<'a, P: 'a + Point, M: 'a> {
+    shape: &'a CompositeShape<P, M>,
+    point: &'a P,
+    found: bool,
+}
+
+impl<'a, P: Point, M> PointContainementTest<'a, P, M> {
+    /// Creates a test for the local-space `point`.
+    pub(crate) fn new(shape: &'a CompositeShape<P, M>, point: &'a P) -> Self {
+        PointContainementTest {
+            shape: shape,
+            point: point,
+            found: false,
+        }
+    }
+}
+
+impl<'a, P, M> BVTVisitor<usize, AABB<P>> for PointContainementTest<'a, P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        !self.found && bv.contains_point(&Id::new(), self.point)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        if !self.found && bv.contains_point(&Id::new(), self.point) {
+            self.shape.map_part_at(*b, &mut |objm, obj| {
+                if obj.contains_point(objm, self.point) {
+                    self.found = true;
+                }
+            })
+        }
+    }
+}
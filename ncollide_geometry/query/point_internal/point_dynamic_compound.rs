@@ -0,0 +1,17 @@
+use query::{PointProjection, PointQuery};
+use query::point_internal::point_composite_shape::{composite_shape_contains_point,
+                                                   composite_shape_project_point};
+use shape::DynamicCompound;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for DynamicCompound<P, M> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        composite_shape_project_point(m, self, point, solid)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        composite_shape_contains_point(m, self, point)
+    }
+}
@@ -0,0 +1,158 @@
+use num::Zero;
+use alga::general::Real;
+use na;
+
+use query::{PointProjection, PointQuery};
+use shape::Ellipsoid;
+use math::{Isometry, Point, Vector};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let inside = contains_local_point(self, &ls_pt.coordinates());
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let ls_proj = project_local_point_on_boundary(self.radii(), &ls_pt.coordinates());
+
+            PointProjection::new(inside, m.transform_point(&P::from_coordinates(ls_proj)))
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        contains_local_point(self, &m.inverse_transform_point(pt).coordinates())
+    }
+}
+
+#[inline]
+fn contains_local_point<V: Vector>(ellipsoid: &Ellipsoid<V>, pt: &V) -> bool {
+    let mut sum = V::Real::zero();
+
+    for i in 0..na::dimension::<V>() {
+        let x = pt[i] / ellipsoid.radii()[i];
+        sum += x * x;
+    }
+
+    sum <= na::one()
+}
+
+/// Projects a point expressed in the local space of an ellipsoid on its boundary.
+///
+/// The projection is `x_i = r_i² y_i / (t + r_i²)` where `t` is the root of the decreasing
+/// function `F(t) = sum((r_i y_i / (t + r_i²))²) - 1` on `]-r_min², +inf[`. It is found by
+/// bisection.
+fn project_local_point_on_boundary<V: Vector>(radii: &V, pt: &V) -> V {
+    let dim = na::dimension::<V>();
+    let _0 = V::Real::zero();
+    let _1: V::Real = na::one();
+
+    // Work on the first orthant and restore the signs at the end.
+    let mut y = *pt;
+    let mut r_min = radii[0];
+    let mut r_max = radii[0];
+
+    for i in 0..dim {
+        y[i] = y[i].abs();
+
+        if radii[i] < r_min {
+            r_min = radii[i];
+        }
+        if radii[i] > r_max {
+            r_max = radii[i];
+        }
+    }
+
+    let r_min2 = r_min * r_min;
+    let mut res = na::zero::<V>();
+
+    // If the point lies on the hyperplane orthogonal to all the smallest axes, the root may not
+    // exist. This happens for interior points close to this hyperplane: the projection is then
+    // on the boundary of the ellipsoid section orthogonal to one of the smallest axes.
+    let mut smallest_axis = None;
+    let mut f_lower = -_1;
+
+    for i in 0..dim {
+        if radii[i] == r_min {
+            if !y[i].is_zero() {
+                smallest_axis = None;
+                break;
+            }
+
+            if smallest_axis.is_none() {
+                smallest_axis = Some(i);
+            }
+        } else {
+            let x = radii[i] * y[i] / (radii[i] * radii[i] - r_min2);
+            f_lower += x * x;
+        }
+    }
+
+    if let Some(k) = smallest_axis {
+        if f_lower <= _0 {
+            for i in 0..dim {
+                if radii[i] != r_min {
+                    let r2 = radii[i] * radii[i];
+                    res[i] = r2 * y[i] / (r2 - r_min2);
+                }
+            }
+
+            res[k] = r_min * (-f_lower).sqrt();
+
+            return restore_signs(res, pt);
+        }
+    }
+
+    let eval = |t: V::Real| {
+        let mut f = -_1;
+
+        for i in 0..dim {
+            let x = radii[i] * y[i] / (t + radii[i] * radii[i]);
+            f += x * x;
+        }
+
+        f
+    };
+
+    // `F(r_max * |y|) <= 0` so the root lies in `]-r_min², r_max * |y|]`.
+    let mut lower = -r_min2;
+    let mut upper = r_max * na::norm(&y);
+
+    // The iteration count is a safeguard: the loop normally stops when the interval can no
+    // longer be split because of the floating point precision.
+    for _ in 0..200 {
+        let mid = (lower + upper) * na::convert(0.5f64);
+
+        if mid == lower || mid == upper {
+            break;
+        }
+
+        if eval(mid) > _0 {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+    }
+
+    let t = (lower + upper) * na::convert(0.5f64);
+
+    for i in 0..dim {
+        let r2 = radii[i] * radii[i];
+        res[i] = r2 * y[i] / (t + r2);
+    }
+
+    restore_signs(res, pt)
+}
+
+#[inline]
+fn restore_signs<V: Vector>(mut res: V, pt: &V) -> V {
+    for i in 0..na::dimension::<V>() {
+        if pt[i] < V::Real::zero() {
+            res[i] = -res[i];
+        }
+    }
+
+    res
+}
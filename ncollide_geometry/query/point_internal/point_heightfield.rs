@@ -0,0 +1,105 @@
+use alga::general::Id;
+use na;
+use query::{PointProjection, PointQuery};
+use shape::{composite_shape, CompositeShape, HeightField};
+use bounding_volume::AABB;
+use partitioning::{BVTCostFn, BVTVisitor};
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for HeightField<P> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut cost_fn = HeightFieldPointProjCostFn {
+            heightfield: self,
+            point: &ls_pt,
+            solid: solid,
+        };
+
+        let mut proj = composite_shape::best_first_search::<P, M, _, _>(self, &mut cost_fn)
+            .unwrap()
+            .1;
+        proj.point = m.transform_point(&proj.point);
+
+        proj
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut test = PointContainementTest {
+            heightfield: self,
+            point: &ls_pt,
+            found: false,
+        };
+
+        CompositeShape::<P, M>::visit_parts(self, &mut test);
+
+        test.found
+    }
+}
+
+/*
+ * Costs function.
+ */
+struct HeightFieldPointProjCostFn<'a, P: 'a + Point> {
+    heightfield: &'a HeightField<P>,
+    point: &'a P,
+    solid: bool,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for HeightFieldPointProjCostFn<'a, P> {
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, PointProjection<P>)> {
+        let proj = if na::dimension::<P::Vector>() == 2 {
+            self.heightfield
+                .segment_at(*b)
+                .project_point(&Id::new(), self.point, self.solid)
+        } else {
+            self.heightfield
+                .triangle_at(*b)
+                .project_point(&Id::new(), self.point, self.solid)
+        };
+
+        Some((na::distance(self.point, &proj.point), proj))
+    }
+}
+
+/*
+ * Visitor.
+ */
+/// Bounding Volume Tree visitor collecting nodes that may contain a given point.
+struct PointContainementTest<'a, P: 'a + Point> {
+    heightfield: &'a HeightField<P>,
+    point: &'a P,
+    found: bool,
+}
+
+impl<'a, P: Point> BVTVisitor<usize, AABB<P>> for PointContainementTest<'a, P> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        !self.found && bv.contains_point(&Id::new(), self.point)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        if !self.found && bv.contains_point(&Id::new(), self.point) {
+            self.found = if na::dimension::<P::Vector>() == 2 {
+                self.heightfield
+                    .segment_at(*b)
+                    .contains_point(&Id::new(), self.point)
+            } else {
+                self.heightfield
+                    .triangle_at(*b)
+                    .contains_point(&Id::new(), self.point)
+            }
+        }
+    }
+}
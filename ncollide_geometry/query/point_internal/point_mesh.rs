@@ -1,7 +1,8 @@
 use alga::general::Id;
 use na;
-use query::{PointProjection, PointQuery, PointQueryWithLocation};
-use shape::{BaseMesh, BaseMeshElement, Polyline, Segment, TriMesh};
+use query::{PointProjection, PointQuery, PointQueryWithLocation, SegmentPointLocation,
+            TrianglePointLocation};
+use shape::{BaseMesh, BaseMeshElement, Polyline, Segment, TriMesh, Triangle};
 use bounding_volume::AABB;
 use partitioning::{BVTCostFn, BVTVisitor};
 use math::{Isometry, Point};
@@ -151,17 +152,49 @@ pub struct PointProjectionInfo<C> {
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for TriMesh<P> {
     #[inline]
     fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
-        self.base_mesh().project_point(m, point, solid)
+        let (projection, _) = self.project_point_with_location(m, point, solid);
+        projection
     }
 
     #[inline]
-    fn distance_to_point(&self, m: &M, point: &P, solid: bool) -> P::Real {
-        self.base_mesh().distance_to_point(m, point, solid)
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        if self.is_closed() {
+            self.project_point(m, point, false).is_inside
+        } else {
+            self.base_mesh().contains_point(m, point)
+        }
     }
+}
+
+impl<P: Point, M: Isometry<P>> PointQueryWithLocation<P, M> for TriMesh<P> {
+    type Location = PointProjectionInfo<<Triangle<P> as PointQueryWithLocation<P, M>>::Location>;
 
     #[inline]
-    fn contains_point(&self, m: &M, point: &P) -> bool {
-        self.base_mesh().contains_point(m, point)
+    fn project_point_with_location(
+        &self,
+        m: &M,
+        point: &P,
+        solid: bool,
+    ) -> (PointProjection<P>, Self::Location) {
+        let (projection, location) = self.base_mesh()
+            .project_point_with_location(m, point, solid);
+
+        match self.base_mesh().pseudo_normals() {
+            Some(normals) => {
+                let i = location.element_index;
+                let idx = &self.indices()[i];
+                let pseudo_normal = match location.barycentric_coordinates {
+                    TrianglePointLocation::OnVertex(k) => &normals.vertices()[idx[k]],
+                    TrianglePointLocation::OnEdge(k, _) => &normals.edges()[i * 3 + k],
+                    _ => &normals.faces()[i],
+                };
+
+                let projection = closed_mesh_projection(m, point, projection, pseudo_normal, solid);
+
+                (projection, location)
+            }
+            None => (projection, location),
+        }
     }
 }
 
@@ -172,14 +205,13 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Polyline<P> {
         projection
     }
 
-    #[inline]
-    fn distance_to_point(&self, m: &M, point: &P, solid: bool) -> P::Real {
-        self.base_mesh().distance_to_point(m, point, solid)
-    }
-
     #[inline]
     fn contains_point(&self, m: &M, point: &P) -> bool {
-        self.base_mesh().contains_point(m, point)
+        if self.is_closed() {
+            self.project_point(m, point, false).is_inside
+        } else {
+            self.base_mesh().contains_point(m, point)
+        }
     }
 }
 
@@ -193,7 +225,42 @@ impl<P: Point, M: Isometry<P>> PointQueryWithLocation<P, M> for Polyline<P> {
         point: &P,
         solid: bool,
     ) -> (PointProjection<P>, Self::Location) {
-        self.base_mesh()
-            .project_point_with_location(m, point, solid)
+        let (projection, location) = self.base_mesh()
+            .project_point_with_location(m, point, solid);
+
+        match self.base_mesh().pseudo_normals() {
+            Some(normals) => {
+                let i = location.element_index;
+                let pseudo_normal = match location.barycentric_coordinates {
+                    SegmentPointLocation::OnVertex(k) => &normals.vertices()[self.indices()[i][k]],
+                    _ => &normals.faces()[i],
+                };
+
+                let projection = closed_mesh_projection(m, point, projection, pseudo_normal, solid);
+
+                (projection, location)
+            }
+            None => (projection, location),
+        }
     }
 }
+
+// Determines if `point` is inside of a closed mesh given its projection on the mesh and the
+// pseudo-normal of the feature it has been projected on.
+#[inline]
+fn closed_mesh_projection<P: Point, M: Isometry<P>>(
+    m: &M,
+    point: &P,
+    mut projection: PointProjection<P>,
+    pseudo_normal: &P::Vector,
+    solid: bool,
+) -> PointProjection<P> {
+    let dpt = m.inverse_rotate_vector(&(*point - projection.point));
+    projection.is_inside = na::dot(&dpt, pseudo_normal) <= na::zero();
+
+    if projection.is_inside && solid {
+        projection.point = *point;
+    }
+
+    projection
+}
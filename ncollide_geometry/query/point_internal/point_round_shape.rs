@@ -0,0 +1,71 @@
+use na::{self, Unit};
+
+use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::{PointProjection, PointQuery};
+use query::point_internal::point_support_map::support_map_point_projection;
+use shape::{RoundShape, SupportMap};
+use math::{Isometry, Point};
+
+impl<P, M, S> PointQuery<P, M> for RoundShape<P::Real, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
+{
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        if na::dimension::<P::Vector>() == 2 {
+            round_shape_point_projection(m, self, &mut VoronoiSimplex2::<P>::new(), point, solid)
+        } else if na::dimension::<P::Vector>() == 3 {
+            round_shape_point_projection(m, self, &mut VoronoiSimplex3::<P>::new(), point, solid)
+        } else {
+            round_shape_point_projection(
+                m,
+                self,
+                &mut JohnsonSimplex::<P>::new_w_tls(),
+                point,
+                solid,
+            )
+        }
+    }
+}
+
+// Projects the point on the core shape and offsets the projection by the radius.
+fn round_shape_point_projection<P, M, S, G>(
+    m: &M,
+    shape: &RoundShape<P::Real, G>,
+    simplex: &mut S,
+    point: &P,
+    solid: bool,
+) -> PointProjection<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<P>,
+    G: SupportMap<P, M>,
+{
+    let radius = shape.radius();
+    let core_proj = support_map_point_projection(m, shape.inner(), simplex, point, true);
+
+    if !core_proj.is_inside {
+        let dpt = *point - core_proj.point;
+
+        if let Some((dir, dist)) = Unit::try_new_and_get(dpt, na::zero()) {
+            let is_inside = dist <= radius;
+
+            if is_inside && solid {
+                return PointProjection::new(true, *point);
+            } else {
+                return PointProjection::new(is_inside, core_proj.point + *dir * radius);
+            }
+        }
+    }
+
+    // The point lies inside of the core shape so the direction of the closest feature is not
+    // known. Fall back to the projection on the whole rounded shape.
+    if solid {
+        PointProjection::new(true, *point)
+    } else {
+        support_map_point_projection(m, shape, simplex, point, false)
+    }
+}
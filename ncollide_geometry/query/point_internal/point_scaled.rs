@@ -0,0 +1,82 @@
+use na;
+
+use query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::{PointProjection, PointQuery};
+use query::point_internal::point_support_map::support_map_point_projection;
+use query::point_internal::point_composite_shape::{composite_shape_contains_point,
+                                                   composite_shape_project_point};
+use shape::{Scaled, ScaledCompoundPart, Shape, SupportMap};
+use math::{Isometry, Point};
+
+impl<P, M, S> PointQuery<P, M> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        // NOTE: the projection is not invariant under non-uniform scaling so we cannot rely on
+        // the projection on the inner shape.
+        if self.inner().is_support_map() {
+            scaled_support_map_point_projection(m, self, point, solid)
+        } else {
+            composite_shape_project_point(m, self, point, solid)
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        if self.inner().is_support_map() {
+            self.project_point(m, point, true).is_inside
+        } else {
+            composite_shape_contains_point(m, self, point)
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for ScaledCompoundPart<P, M> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        if self.shape().is_support_map() {
+            scaled_support_map_point_projection(m, self, point, solid)
+        } else {
+            composite_shape_project_point(m, self, point, solid)
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        if self.shape().is_support_map() {
+            self.project_point(m, point, true).is_inside
+        } else {
+            composite_shape_contains_point(m, self, point)
+        }
+    }
+}
+
+fn scaled_support_map_point_projection<P, M, G>(
+    m: &M,
+    shape: &G,
+    point: &P,
+    solid: bool,
+) -> PointProjection<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_point_projection(m, shape, &mut VoronoiSimplex2::<P>::new(), point, solid)
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_point_projection(m, shape, &mut VoronoiSimplex3::<P>::new(), point, solid)
+    } else {
+        support_map_point_projection(
+            m,
+            shape,
+            &mut JohnsonSimplex::<P>::new_w_tls(),
+            point,
+            solid,
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+use query::{PointProjection, PointQuery};
+use shape::SdfGrid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for SdfGrid<P> {
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let (distance, _) = self.distance_and_gradient_at(&ls_pt);
+        let inside = distance <= na::zero();
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let ls_proj = project_local_point_on_zero_level(self, &ls_pt);
+
+            PointProjection::new(inside, m.transform_point(&ls_proj))
+        }
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &M, pt: &P, solid: bool) -> P::Real {
+        let distance = self.distance_at(&m.inverse_transform_point(pt));
+
+        if solid && distance < na::zero() {
+            na::zero()
+        } else {
+            distance
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        self.distance_at(&m.inverse_transform_point(pt)) <= na::zero()
+    }
+}
+
+/// Projects a point on the zero level set of the distance field by following its gradient.
+///
+/// The point is moved by its signed distance along the normalized gradient until the distance is
+/// negligible with regard to the grid cell size.
+fn project_local_point_on_zero_level<P: Point>(sdf: &SdfGrid<P>, pt: &P) -> P {
+    let eps = P::Real::default_epsilon().sqrt();
+    let tolerance = sdf.cell_size() * eps;
+    let mut proj = *pt;
+
+    for _ in 0..50 {
+        let (distance, gradient) = sdf.distance_and_gradient_at(&proj);
+
+        if distance.abs() <= tolerance {
+            break;
+        }
+
+        match na::try_normalize(&gradient, eps) {
+            Some(dir) => proj = proj + dir * -distance,
+            None => break,
+        }
+    }
+
+    proj
+}
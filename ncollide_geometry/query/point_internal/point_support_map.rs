@@ -1,11 +1,12 @@
+use alga::general::Real;
 use alga::linear::Translation;
-use na;
+use na::{self, Point2, Point3};
 
 use query::algorithms::gjk;
 use query::algorithms::minkowski_sampling;
 use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use query::{PointProjection, PointQuery};
-use shape::{Capsule, Cone, ConvexHull, Cylinder, SupportMap};
+use shape::{Capsule, Cone, ConvexHull, ConvexPolygon, ConvexPolyhedron, Cylinder, SupportMap};
 use math::{Isometry, Point};
 
 /// Projects a point on a shape using the GJK algorithm.
@@ -125,3 +126,17 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for ConvexHull<P> {
         }
     }
 }
+
+impl<N: Real, M: Isometry<Point3<N>>> PointQuery<Point3<N>, M> for ConvexPolyhedron<N> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &Point3<N>, solid: bool) -> PointProjection<Point3<N>> {
+        support_map_point_projection(m, self, &mut VoronoiSimplex3::new(), point, solid)
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> PointQuery<Point2<N>, M> for ConvexPolygon<N> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &Point2<N>, solid: bool) -> PointProjection<Point2<N>> {
+        support_map_point_projection(m, self, &mut VoronoiSimplex2::new(), point, solid)
+    }
+}
@@ -0,0 +1,155 @@
+use alga::general::Id;
+use na;
+use query::{PointProjection, PointProjectionInfo, PointQuery, PointQueryWithLocation,
+            TetrahedronPointLocation};
+use shape::TetMesh;
+use bounding_volume::AABB;
+use partitioning::{BVTCostFn, BVTVisitor};
+use math::{Isometry, Point};
+
+impl<P: Point> TetMesh<P> {
+    /// Finds a tetrahedron of this mesh containing the given point.
+    ///
+    /// Returns the index of this tetrahedron together with the barycentric coordinates of the
+    /// point wrt. its four vertices, or `None` if the point is outside of this mesh.
+    pub fn locate_point<M: Isometry<P>>(
+        &self,
+        m: &M,
+        point: &P,
+    ) -> Option<PointProjectionInfo<[P::Real; 4]>> {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut locator = TetMeshPointLocator {
+            mesh: self,
+            point: &ls_pt,
+            found: None,
+        };
+
+        self.bvt().visit(&mut locator);
+
+        locator.found
+    }
+}
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for TetMesh<P> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        let (projection, _) = self.project_point_with_location(m, point, solid);
+        projection
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        self.base_mesh().contains_point(m, point)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> PointQueryWithLocation<P, M> for TetMesh<P> {
+    type Location = PointProjectionInfo<TetrahedronPointLocation<P::Real>>;
+
+    #[inline]
+    fn project_point_with_location(
+        &self,
+        m: &M,
+        point: &P,
+        solid: bool,
+    ) -> (PointProjection<P>, Self::Location) {
+        let (projection, location) = self.base_mesh()
+            .project_point_with_location(m, point, true);
+
+        if solid || !projection.is_inside {
+            return (projection, location);
+        }
+
+        // The point is inside of the mesh: project it on the closest boundary face.
+        let ls_pt = m.inverse_transform_point(point);
+        let mut cost_fn = TetMeshBoundaryProjCostFn {
+            mesh: self,
+            point: &ls_pt,
+        };
+
+        match self.bvt().best_first_search(&mut cost_fn) {
+            Some((_, (proj, location))) => {
+                (PointProjection::new(true, m.transform_point(&proj)), location)
+            }
+            None => (projection, location),
+        }
+    }
+}
+
+/*
+ * Costs function.
+ */
+struct TetMeshBoundaryProjCostFn<'a, P: 'a + Point> {
+    mesh: &'a TetMesh<P>,
+    point: &'a P,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for TetMeshBoundaryProjCostFn<'a, P> {
+    type UserData = (P, PointProjectionInfo<TetrahedronPointLocation<P::Real>>);
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Self::UserData)> {
+        let tetrahedron = self.mesh.tetrahedron_at(*b);
+        let boundary = &self.mesh.boundary_faces()[*b];
+        let mut best = None;
+
+        for i in 0..4 {
+            if boundary[i] {
+                let (proj, loc) = tetrahedron
+                    .face(i)
+                    .project_point_with_location(&Id::new(), self.point, false);
+                let dist = na::distance(self.point, &proj.point);
+
+                if best.as_ref().map(|&(best_dist, _)| dist < best_dist).unwrap_or(true) {
+                    let loc = TetrahedronPointLocation::from_face_location(i, loc);
+                    let info = PointProjectionInfo {
+                        element_index: *b,
+                        barycentric_coordinates: loc,
+                    };
+
+                    best = Some((dist, (proj.point, info)));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/*
+ * Visitor.
+ */
+/// Bounding Volume Tree visitor looking for a tetrahedron containing a given point.
+struct TetMeshPointLocator<'a, P: 'a + Point> {
+    mesh: &'a TetMesh<P>,
+    point: &'a P,
+    found: Option<PointProjectionInfo<[P::Real; 4]>>,
+}
+
+impl<'a, P: Point> BVTVisitor<usize, AABB<P>> for TetMeshPointLocator<'a, P> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        self.found.is_none() && bv.contains_point(&Id::new(), self.point)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        if self.found.is_none() && bv.contains_point(&Id::new(), self.point) {
+            let tetrahedron = self.mesh.tetrahedron_at(*b);
+
+            if tetrahedron.contains_point(&Id::new(), self.point) {
+                if let Some(bcoords) = tetrahedron.barycentric_coordinates(self.point) {
+                    self.found = Some(PointProjectionInfo {
+                        element_index: *b,
+                        barycentric_coordinates: bcoords,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -11,6 +11,11 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Tetrahedron<P> {
         let (projection, _) = self.project_point_with_location(m, pt, solid);
         projection
     }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        self.project_point(m, pt, true).is_inside
+    }
 }
 
 /// Logical description of the location of a point on a triangle.
@@ -25,7 +30,7 @@ pub enum TetrahedronPointLocation<N: Real> {
     /// The first face is the triangle ABC.
     /// The second face is the triangle ABD.
     /// The third face is the triangle ACD.
-    /// The fourth face is the triangle BDC.
+    /// The fourth face is the triangle BCD.
     OnFace(usize, [N; 3]),
     /// The point lies inside of the tetrahedron.
     OnSolid,
@@ -48,6 +53,30 @@ impl<N: Real> TetrahedronPointLocation<N> {
             _ => false,
         }
     }
+
+    /// Converts the location of a point on the `i`-th face of a tetrahedron (as given by
+    /// `Tetrahedron::face`) into its location on the tetrahedron itself.
+    pub fn from_face_location(i: usize, loc: TrianglePointLocation<N>) -> Self {
+        // The vertices of each face, in the same order as `Tetrahedron::face`.
+        const FACE_VERTICES: [[usize; 3]; 4] = [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]];
+        // The two vertices of each triangle edge, in the same order as `TrianglePointLocation`.
+        const TRIANGLE_EDGES: [[usize; 2]; 3] = [[0, 1], [1, 2], [0, 2]];
+
+        let face = &FACE_VERTICES[i];
+
+        match loc {
+            TrianglePointLocation::OnVertex(k) => TetrahedronPointLocation::OnVertex(face[k]),
+            TrianglePointLocation::OnEdge(k, bcoords) => {
+                let (a, b) = (face[TRIANGLE_EDGES[k][0]], face[TRIANGLE_EDGES[k][1]]);
+                // Index of the edge `ab` with `a < b`, in the same order as `Tetrahedron::edge`.
+                let edge = if a == 0 { b - 1 } else { a + b };
+
+                TetrahedronPointLocation::OnEdge(edge, bcoords)
+            }
+            TrianglePointLocation::OnFace(bcoords) => TetrahedronPointLocation::OnFace(i, bcoords),
+            TrianglePointLocation::OnSolid => TetrahedronPointLocation::OnSolid,
+        }
+    }
 }
 
 impl<P: Point> Tetrahedron<P> {
@@ -566,7 +595,22 @@ impl<P: Point, M: Isometry<P>> PointQueryWithLocation<P, M> for Tetrahedron<P> {
         }
 
         if !solid {
-            unimplemented!("Non-solid ray-cast on a tetrahedron is not yet implemented.")
+            // Project on the closest face.
+            let mut best = None;
+            let mut best_dist = P::Real::max_value();
+
+            for i in 0..4 {
+                let (proj, loc) = self.face(i).project_point_with_location(m, pt, false);
+                let dist = na::distance_squared(pt, &proj.point);
+
+                if dist < best_dist {
+                    best_dist = dist;
+                    best = Some((proj.point, TetrahedronPointLocation::from_face_location(i, loc)));
+                }
+            }
+
+            let (proj, loc) = best.unwrap();
+            return (PointProjection::new(true, proj), loc);
         }
 
         let proj = PointProjection::new(true, m.transform_point(&p));
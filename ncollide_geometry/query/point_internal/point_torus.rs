@@ -0,0 +1,57 @@
+use approx::ApproxEq;
+use na;
+
+use query::{PointProjection, PointQuery};
+use shape::Torus;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Torus<P::Real> {
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = self.core_circle_point(&ls_pt);
+        let dcore = ls_pt - core;
+        let distance_squared = na::norm_squared(&dcore);
+
+        let inside = distance_squared <= self.minor_radius() * self.minor_radius();
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let dir = match na::try_normalize(&dcore, P::Real::default_epsilon()) {
+                Some(dir) => dir,
+                None => {
+                    // The point is on the core circle: any direction orthogonal to it works.
+                    let mut dir = na::zero::<P::Vector>();
+                    dir[1] = na::one();
+                    dir
+                }
+            };
+
+            let ls_proj = core + dir * self.minor_radius();
+
+            PointProjection::new(inside, m.transform_point(&ls_proj))
+        }
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &M, pt: &P, solid: bool) -> P::Real {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = self.core_circle_point(&ls_pt);
+        let dist = na::distance(&ls_pt, &core) - self.minor_radius();
+
+        if solid && dist < na::zero() {
+            na::zero()
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = self.core_circle_point(&ls_pt);
+
+        na::distance_squared(&ls_pt, &core) <= self.minor_radius() * self.minor_radius()
+    }
+}
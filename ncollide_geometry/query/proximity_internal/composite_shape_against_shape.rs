@@ -5,19 +5,20 @@ use na;
 
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
-use shape::{CompositeShape, Shape};
-use query::{PointQuery, Proximity};
+use shape::{composite_shape, CompositeShape, Shape};
+use query::{PointQuery, Proximity, QueryDispatcher, Unsupported};
 use query::proximity_internal;
 use math::{Isometry, Point};
 
 /// Proximity between a composite shape (`Mesh`, `Compound`) and any other shape.
 pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     g1: &G1,
     m2: &M,
     g2: &Shape<P, M>,
     margin: P::Real,
-) -> Proximity
+) -> Result<Proximity, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
@@ -28,34 +29,41 @@ where
         "The proximity margin must be positive or null."
     );
 
-    let mut cost_fn = CompositeShapeAgainstAnyInterfCostFn::new(m1, g1, m2, g2, margin);
+    let mut cost_fn = CompositeShapeAgainstAnyInterfCostFn::new(dispatcher, m1, g1, m2, g2, margin);
 
-    match g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res) {
-        None => Proximity::Disjoint,
-        Some(prox) => prox,
+    let res = composite_shape::best_first_search(g1, &mut cost_fn).map(|(_, res)| res);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res.unwrap_or(Proximity::Disjoint)),
     }
 }
 
 /// Proximity between a shape and a composite (`Mesh`, `Compound`) shape.
 pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     g1: &Shape<P, M>,
     m2: &M,
     g2: &G2,
     margin: P::Real,
-) -> Proximity
+) -> Result<Proximity, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
     G2: CompositeShape<P, M>,
 {
-    composite_shape_against_shape(m2, g2, m1, g1, margin)
+    composite_shape_against_shape(dispatcher, m2, g2, m1, g1, margin).map_err(|mut err| {
+        err.flip();
+        err
+    })
 }
 
 struct CompositeShapeAgainstAnyInterfCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
     msum_shift: P::Vector,
     msum_margin: P::Vector,
 
+    dispatcher: &'a QueryDispatcher<P, M>,
     m1: &'a M,
     g1: &'a G1,
     m2: &'a M,
@@ -64,6 +72,9 @@ struct CompositeShapeAgainstAnyInterfCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized
 
     found_intersection: bool,
 
+    // The first pair of parts not supported by the dispatcher.
+    error: Option<Unsupported>,
+
     point_type: PhantomData<P>,
 }
 
@@ -74,6 +85,7 @@ where
     G1: CompositeShape<P, M>,
 {
     pub fn new(
+        dispatcher: &'a QueryDispatcher<P, M>,
         m1: &'a M,
         g1: &'a G1,
         m2: &'a M,
@@ -86,12 +98,14 @@ where
         CompositeShapeAgainstAnyInterfCostFn {
             msum_shift: -ls_aabb2.center().coordinates(),
             msum_margin: ls_aabb2.half_extents(),
+            dispatcher: dispatcher,
             m1: m1,
             g1: g1,
             m2: m2,
             g2: g2,
             margin: margin,
             found_intersection: false,
+            error: None,
             point_type: PhantomData,
         }
     }
@@ -108,8 +122,8 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
-        // No need to continue if some parts intersect.
-        if self.found_intersection {
+        // No need to continue if some parts intersect or are not supported.
+        if self.found_intersection || self.error.is_some() {
             return None;
         }
 
@@ -133,16 +147,27 @@ where
         let mut res = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
-            res =
-                match proximity_internal::proximity_internal(m1, g1, self.m2, self.g2, self.margin)
-                {
-                    Proximity::Disjoint => None,
-                    Proximity::WithinMargin => Some((self.margin, Proximity::WithinMargin)),
-                    Proximity::Intersecting => {
-                        self.found_intersection = true;
-                        Some((na::zero(), Proximity::Intersecting))
-                    }
+            let proximity = proximity_internal::proximity_with_dispatcher(
+                self.dispatcher,
+                m1,
+                g1,
+                self.m2,
+                self.g2,
+                self.margin,
+            );
+
+            res = match proximity {
+                Ok(Proximity::Disjoint) => None,
+                Ok(Proximity::WithinMargin) => Some((self.margin, Proximity::WithinMargin)),
+                Ok(Proximity::Intersecting) => {
+                    self.found_intersection = true;
+                    Some((na::zero(), Proximity::Intersecting))
+                }
+                Err(err) => {
+                    self.error = Some(err);
+                    None
                 }
+            }
         });
 
         res
@@ -0,0 +1,40 @@
+use na;
+use query::Proximity;
+use query::distance_internal;
+use shape::SupportMap;
+use math::{Isometry, Point};
+
+/// Proximity between two support-mapped shapes dilated by balls of radius `radius1` and
+/// `radius2`.
+pub fn round_support_map_against_round_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    radius1: P::Real,
+    m2: &M,
+    g2: &G2,
+    radius2: P::Real,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let dist = distance_internal::round_support_map_against_round_support_map(
+        m1,
+        g1,
+        radius1,
+        m2,
+        g2,
+        radius2,
+    );
+
+    if dist <= na::zero() {
+        Proximity::Intersecting
+    } else if dist <= margin {
+        Proximity::WithinMargin
+    } else {
+        Proximity::Disjoint
+    }
+}
@@ -0,0 +1,45 @@
+use na;
+use query::Proximity;
+use query::distance_internal;
+use shape::{SupportMap, Torus};
+use math::{Isometry, Point};
+
+/// Proximity between a torus and a support-mapped shape.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    mother: &M,
+    other: &G,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let dist = distance_internal::torus_against_support_map(mtorus, torus, mother, other);
+
+    if dist <= na::zero() {
+        Proximity::Intersecting
+    } else if dist <= margin {
+        Proximity::WithinMargin
+    } else {
+        Proximity::Disjoint
+    }
+}
+
+/// Proximity between a support-mapped shape and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    mtorus: &M,
+    torus: &Torus<P::Real>,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    torus_against_support_map(mtorus, torus, mother, other, margin)
+}
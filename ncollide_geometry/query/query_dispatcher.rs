@@ -0,0 +1,302 @@
+use math::{Isometry, Point};
+use shape::Shape;
+use query::{closest_points_internal, contacts_internal, distance_internal, proximity_internal,
+            shape_cast_internal, time_of_impact_internal, ClosestPoints, Contact, Proximity,
+            Unsupported, TOI};
+
+/// Dispatcher selecting the algorithm used by a pairwise geometric query.
+///
+/// Each method returns an `Unsupported` error if this dispatcher does not know how to handle the
+/// given pair of shapes. Composite shapes need not be handled: the `query::*_with_dispatcher`
+/// functions decompose them and dispatch each of their parts through the same dispatcher.
+/// Dispatchers can be combined with `.chain(...)` so that custom shapes can be added on top of the
+/// `DefaultQueryDispatcher`.
+pub trait QueryDispatcher<P: Point, M: 'static>: Send + Sync {
+    /// Computes one contact point between two shapes.
+    ///
+    /// Returns `Ok(None)` if the objects are separated by a distance greater than `prediction`.
+    fn contact(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        prediction: P::Real,
+    ) -> Result<Option<Contact<P>>, Unsupported>;
+
+    /// Computes the minimum distance separating two shapes.
+    fn distance(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+    ) -> Result<P::Real, Unsupported>;
+
+    /// Computes the pair of closest points between two shapes.
+    ///
+    /// Returns `Ok(ClosestPoints::Disjoint)` if the shapes are separated by a distance greater
+    /// than `margin`. The default implementation does not support any pair of shapes.
+    fn closest_points(
+        &self,
+        _: &M,
+        g1: &Shape<P, M>,
+        _: &M,
+        g2: &Shape<P, M>,
+        _: P::Real,
+    ) -> Result<ClosestPoints<P>, Unsupported> {
+        Err(Unsupported::new(g1, g2))
+    }
+
+    /// Tests whether two shapes are intersecting or separated by a distance smaller than
+    /// `margin`.
+    fn proximity(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        margin: P::Real,
+    ) -> Result<Proximity, Unsupported>;
+
+    /// Computes the smallest time of impact of two shapes under translational movement.
+    ///
+    /// Returns `Ok(None)` if the shapes never collide.
+    fn time_of_impact(
+        &self,
+        m1: &M,
+        vel1: &P::Vector,
+        g1: &Shape<P, M>,
+        m2: &M,
+        vel2: &P::Vector,
+        g2: &Shape<P, M>,
+    ) -> Result<Option<P::Real>, Unsupported>;
+
+    /// Computes the smallest time of impact of two shapes under translational movement, with the
+    /// witness points and normal at the impact.
+    ///
+    /// Returns `Ok(None)` if the shapes never collide. The default implementation does not support
+    /// any pair of shapes.
+    fn shape_cast(
+        &self,
+        _: &M,
+        _: &P::Vector,
+        g1: &Shape<P, M>,
+        _: &M,
+        _: &P::Vector,
+        g2: &Shape<P, M>,
+    ) -> Result<Option<TOI<P>>, Unsupported> {
+        Err(Unsupported::new(g1, g2))
+    }
+
+    /// Builds a dispatcher that uses `self` first and falls back to `other` for the pairs of
+    /// shapes `self` does not handle.
+    fn chain<D: QueryDispatcher<P, M>>(self, other: D) -> QueryDispatcherChain<Self, D>
+    where
+        Self: Sized,
+    {
+        QueryDispatcherChain::new(self, other)
+    }
+}
+
+/// The query dispatcher handling all the pairs of non-composite shapes defined by `ncollide`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DefaultQueryDispatcher;
+
+impl<P: Point, M: Isometry<P>> QueryDispatcher<P, M> for DefaultQueryDispatcher {
+    #[inline]
+    fn contact(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        prediction: P::Real,
+    ) -> Result<Option<Contact<P>>, Unsupported> {
+        contacts_internal::default_shape_against_shape(m1, g1, m2, g2, prediction)
+    }
+
+    #[inline]
+    fn distance(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+    ) -> Result<P::Real, Unsupported> {
+        distance_internal::default_shape_against_shape(m1, g1, m2, g2)
+    }
+
+    #[inline]
+    fn closest_points(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        margin: P::Real,
+    ) -> Result<ClosestPoints<P>, Unsupported> {
+        closest_points_internal::default_shape_against_shape(m1, g1, m2, g2, margin)
+    }
+
+    #[inline]
+    fn proximity(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        margin: P::Real,
+    ) -> Result<Proximity, Unsupported> {
+        proximity_internal::default_shape_against_shape(m1, g1, m2, g2, margin)
+    }
+
+    #[inline]
+    fn time_of_impact(
+        &self,
+        m1: &M,
+        vel1: &P::Vector,
+        g1: &Shape<P, M>,
+        m2: &M,
+        vel2: &P::Vector,
+        g2: &Shape<P, M>,
+    ) -> Result<Option<P::Real>, Unsupported> {
+        time_of_impact_internal::default_shape_against_shape(m1, vel1, g1, m2, vel2, g2)
+    }
+
+    #[inline]
+    fn shape_cast(
+        &self,
+        m1: &M,
+        vel1: &P::Vector,
+        g1: &Shape<P, M>,
+        m2: &M,
+        vel2: &P::Vector,
+        g2: &Shape<P, M>,
+    ) -> Result<Option<TOI<P>>, Unsupported> {
+        shape_cast_internal::default_shape_against_shape(m1, vel1, g1, m2, vel2, g2)
+    }
+}
+
+/// A query dispatcher that tries a first dispatcher and falls back to a second one.
+#[derive(Copy, Clone, Debug)]
+pub struct QueryDispatcherChain<D1, D2> {
+    first: D1,
+    second: D2,
+}
+
+impl<D1, D2> QueryDispatcherChain<D1, D2> {
+    /// Creates a dispatcher that uses `first` and falls back to `second`.
+    #[inline]
+    pub fn new(first: D1, second: D2) -> QueryDispatcherChain<D1, D2> {
+        QueryDispatcherChain {
+            first: first,
+            second: second,
+        }
+    }
+
+    /// The dispatcher used first.
+    #[inline]
+    pub fn first(&self) -> &D1 {
+        &self.first
+    }
+
+    /// The dispatcher used for the pairs of shapes the first one does not handle.
+    #[inline]
+    pub fn second(&self) -> &D2 {
+        &self.second
+    }
+}
+
+impl<P, M, D1, D2> QueryDispatcher<P, M> for QueryDispatcherChain<D1, D2>
+where
+    P: Point,
+    M: 'static,
+    D1: QueryDispatcher<P, M>,
+    D2: QueryDispatcher<P, M>,
+{
+    #[inline]
+    fn contact(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        prediction: P::Real,
+    ) -> Result<Option<Contact<P>>, Unsupported> {
+        self.first
+            .contact(m1, g1, m2, g2, prediction)
+            .or_else(|_| self.second.contact(m1, g1, m2, g2, prediction))
+    }
+
+    #[inline]
+    fn distance(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+    ) -> Result<P::Real, Unsupported> {
+        self.first
+            .distance(m1, g1, m2, g2)
+            .or_else(|_| self.second.distance(m1, g1, m2, g2))
+    }
+
+    #[inline]
+    fn closest_points(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        margin: P::Real,
+    ) -> Result<ClosestPoints<P>, Unsupported> {
+        self.first
+            .closest_points(m1, g1, m2, g2, margin)
+            .or_else(|_| self.second.closest_points(m1, g1, m2, g2, margin))
+    }
+
+    #[inline]
+    fn proximity(
+        &self,
+        m1: &M,
+        g1: &Shape<P, M>,
+        m2: &M,
+        g2: &Shape<P, M>,
+        margin: P::Real,
+    ) -> Result<Proximity, Unsupported> {
+        self.first
+            .proximity(m1, g1, m2, g2, margin)
+            .or_else(|_| self.second.proximity(m1, g1, m2, g2, margin))
+    }
+
+    #[inline]
+    fn time_of_impact(
+        &self,
+        m1: &M,
+        vel1: &P::Vector,
+        g1: &Shape<P, M>,
+        m2: &M,
+        vel2: &P::Vector,
+        g2: &Shape<P, M>,
+    ) -> Result<Option<P::Real>, Unsupported> {
+        self.first
+            .time_of_impact(m1, vel1, g1, m2, vel2, g2)
+            .or_else(|_| self.second.time_of_impact(m1, vel1, g1, m2, vel2, g2))
+    }
+
+    #[inline]
+    fn shape_cast(
+        &self,
+        m1: &M,
+        vel1: &P::Vector,
+        g1: &Shape<P, M>,
+        m2: &M,
+        vel2: &P::Vector,
+        g2: &Shape<P, M>,
+    ) -> Result<Option<TOI<P>>, Unsupported> {
+        self.first
+            .shape_cast(m1, vel1, g1, m2, vel2, g2)
+            .or_else(|_| self.second.shape_cast(m1, vel1, g1, m2, vel2, g2))
+    }
+}
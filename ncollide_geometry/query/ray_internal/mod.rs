@@ -1,10 +1,18 @@
 //! Ray-casting related definitions and implementations.
 #[doc(inline)]
-pub use self::ray::{Ray, RayCast, RayIntersection};
+pub use self::ray::{CullMode, Ray, RayCast, RayIntersection};
 pub use self::ray_plane::plane_toi_with_ray;
+pub use self::ray_aabb::clip_ray_with_aabb;
 pub use self::ray_triangle::triangle_ray_intersection;
+pub use self::ray_tetrahedron::clip_ray_with_tetrahedron;
+pub use self::ray_tet_mesh::TetMeshRayIntersection;
 pub use self::ray_support_map::implicit_toi_and_normal_with_ray;
 pub use self::ray_ball::ball_toi_with_ray;
+pub use self::ray_torus::torus_toi_and_normal_with_ray;
+pub use self::ray_heightfield::{heightfield_toi_and_normal_with_bounded_ray,
+                                heightfield_toi_and_normal_with_ray};
+pub use self::ray_sdf_grid::sdf_grid_toi_and_normal_with_ray;
+pub use self::ray_intersections::ray_intersections_with_shape;
 pub use self::ray_bvt::{RayInterferencesCollector, RayIntersectionCostFn};
 
 use na::{Point2, Point3, Vector2, Vector3};
@@ -13,15 +21,27 @@ use na::{Point2, Point3, Vector2, Vector3};
 pub mod ray;
 mod ray_plane;
 mod ray_ball;
+mod ray_ellipsoid;
+mod ray_torus;
+mod ray_round_shape;
+mod ray_scaled;
 mod ray_cuboid;
 mod ray_aabb;
 mod ray_bounding_sphere;
 mod ray_support_map;
+mod ray_convex_polytope;
 mod ray_triangle;
+mod ray_tetrahedron;
+mod ray_composite_shape;
 mod ray_compound;
+mod ray_dynamic_compound;
 mod ray_mesh;
+mod ray_tet_mesh;
+mod ray_heightfield;
+mod ray_sdf_grid;
 mod ray_shape;
 mod ray_bvt;
+mod ray_intersections;
 
 /*
  *
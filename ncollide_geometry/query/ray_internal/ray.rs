@@ -1,11 +1,13 @@
 //! Traits and structure needed to cast rays.
 
-use na::Point2;
+use na::{self, Point2};
 
 use math::{Isometry, Point, Vector};
+use shape::FeatureId;
 
 /// A Ray.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Ray<P: Point> {
     /// Starting point of the ray.
     pub origin: P,
@@ -48,6 +50,34 @@ impl<P: Point> Ray<P> {
     }
 }
 
+/// The faces ignored by a culled ray cast.
+///
+/// The front faces of a shape are the faces hit by a ray coming from the side their normal points
+/// to. The back faces are hit by a ray coming from the other side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub enum CullMode {
+    /// No face is ignored.
+    None,
+    /// The back faces are ignored.
+    Back,
+    /// The front faces are ignored.
+    Front,
+}
+
+impl CullMode {
+    /// Tests whether a face with the normal `normal` is ignored when hit by a ray with the
+    /// direction `dir`.
+    #[inline]
+    pub fn culls<V: Vector>(&self, normal: &V, dir: &V) -> bool {
+        match *self {
+            CullMode::None => false,
+            CullMode::Back => na::dot(normal, dir) > na::zero(),
+            CullMode::Front => na::dot(normal, dir) < na::zero(),
+        }
+    }
+}
+
 /// Structure containing the result of a successful ray cast.
 pub struct RayIntersection<V: Vector> {
     /// The time of impact of the ray with the object.  The exact contact point can be computed
@@ -63,6 +93,20 @@ pub struct RayIntersection<V: Vector> {
     /// The textures coordinates at the intersection point.  This is an `Option` because some shape
     /// do not support texture coordinates.
     pub uvs: Option<Point2<V::Real>>,
+
+    /// The feature hit by the ray.
+    ///
+    /// For composite shapes, this is the feature of the part identified by `part`. It is
+    /// `FeatureId::Unknown` for shapes that do not report the feature hit.
+    pub feature: FeatureId,
+
+    /// Index of the part of a composite shape hit by the ray, e.g., the triangle of a `TriMesh`,
+    /// the segment of a `Polyline`, or the child shape of a `Compound`.
+    ///
+    /// This is `None` for non-composite shapes. For nested composite shapes, e.g., a `TriMesh`
+    /// part of a `Compound`, this is the index of the part of the outermost composite shape: the
+    /// index of the part hit inside of the nested composite shape is not reported.
+    pub part: Option<usize>,
 }
 
 impl<V: Vector> RayIntersection<V> {
@@ -77,6 +121,8 @@ impl<V: Vector> RayIntersection<V> {
             toi: toi,
             normal: normal,
             uvs: uvs,
+            feature: FeatureId::Unknown,
+            part: None,
         }
     }
 
@@ -87,6 +133,8 @@ impl<V: Vector> RayIntersection<V> {
             toi: toi,
             normal: normal,
             uvs: None,
+            feature: FeatureId::Unknown,
+            part: None,
         }
     }
 }
@@ -125,4 +173,73 @@ pub trait RayCast<P: Point, M> {
     fn intersects_ray(&self, m: &M, ray: &Ray<P>) -> bool {
         self.toi_with_ray(m, ray, true).is_some()
     }
+
+    /// Computes the time of impact between this transform shape and a ray, ignoring the
+    /// intersections with a time of impact greater than `max_toi`.
+    ///
+    /// Composite shapes do not traverse their parts farther than `max_toi` along the ray.
+    #[inline]
+    fn toi_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<P::Real> {
+        self.toi_with_ray(m, ray, solid)
+            .and_then(|toi| if toi <= max_toi { Some(toi) } else { None })
+    }
+
+    /// Computes the time of impact, and normal between this transformed shape and a ray, ignoring
+    /// the intersections with a time of impact greater than `max_toi`.
+    #[inline]
+    fn toi_and_normal_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_ray(m, ray, solid)
+            .and_then(|inter| if inter.toi <= max_toi { Some(inter) } else { None })
+    }
+
+    /// Computes time of impact, normal, and texture coordinates (uv) between this transformed
+    /// shape and a ray, ignoring the intersections with a time of impact greater than `max_toi`.
+    #[inline]
+    fn toi_and_normal_and_uv_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_and_uv_with_ray(m, ray, solid)
+            .and_then(|inter| if inter.toi <= max_toi { Some(inter) } else { None })
+    }
+
+    /// Tests whether a ray intersects this transformed shape with a time of impact smaller than
+    /// `max_toi`.
+    #[inline]
+    fn intersects_bounded_ray(&self, m: &M, ray: &Ray<P>, max_toi: P::Real) -> bool {
+        self.toi_with_bounded_ray(m, ray, max_toi, true).is_some()
+    }
+
+    /// Computes the time of impact, and normal between this transformed shape and a ray, ignoring
+    /// the faces selected by `cull`.
+    ///
+    /// Only the shapes with oriented faces support culling: `Plane`, as well as `Triangle` and
+    /// `TriMesh` in 3D. `Compound`, `DynamicCompound` and `Scaled` apply `cull` to the shapes they
+    /// wrap. The other shapes ignore `cull`. If `solid` is `true`, a ray starting inside of a solid
+    /// shape still hits it with a zero time of impact.
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        _cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_ray(m, ray, solid)
+    }
 }
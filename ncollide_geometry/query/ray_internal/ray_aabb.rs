@@ -12,38 +12,13 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
         let ls_ray = ray.inverse_transform_by(m);
 
-        let mut tmin: P::Real = na::zero();
-        let mut tmax: P::Real = Bounded::max_value();
-
-        for i in 0usize..na::dimension::<P::Vector>() {
-            if ls_ray.dir[i].is_zero() {
-                if ls_ray.origin[i] < self.mins()[i] || ls_ray.origin[i] > self.maxs()[i] {
-                    return None;
-                }
+        clip_ray_with_aabb(self, &ls_ray).map(|(tmin, tmax)| {
+            if tmin.is_zero() && !solid {
+                tmax
             } else {
-                let _1: P::Real = na::one();
-                let denom = _1 / ls_ray.dir[i];
-                let mut inter_with_near_plane = (self.mins()[i] - ls_ray.origin[i]) * denom;
-                let mut inter_with_far_plane = (self.maxs()[i] - ls_ray.origin[i]) * denom;
-
-                if inter_with_near_plane > inter_with_far_plane {
-                    mem::swap(&mut inter_with_near_plane, &mut inter_with_far_plane)
-                }
-
-                tmin = tmin.max(inter_with_near_plane);
-                tmax = tmax.min(inter_with_far_plane);
-
-                if tmin > tmax {
-                    return None;
-                }
+                tmin
             }
-        }
-
-        if tmin.is_zero() && !solid {
-            Some(tmax)
-        } else {
-            Some(tmin)
-        }
+        })
     }
 
     #[inline]
@@ -68,6 +43,41 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
     }
 }
 
+/// Computes the parameters of the ray entry and exit points on an AABB.
+///
+/// Returns `None` if the ray does not intersect the AABB. Otherwise, the returned entry parameter
+/// is zero if the ray origin is inside of the AABB.
+pub fn clip_ray_with_aabb<P: Point>(aabb: &AABB<P>, ray: &Ray<P>) -> Option<(P::Real, P::Real)> {
+    let mut tmin: P::Real = na::zero();
+    let mut tmax: P::Real = Bounded::max_value();
+
+    for i in 0usize..na::dimension::<P::Vector>() {
+        if ray.dir[i].is_zero() {
+            if ray.origin[i] < aabb.mins()[i] || ray.origin[i] > aabb.maxs()[i] {
+                return None;
+            }
+        } else {
+            let _1: P::Real = na::one();
+            let denom = _1 / ray.dir[i];
+            let mut inter_with_near_plane = (aabb.mins()[i] - ray.origin[i]) * denom;
+            let mut inter_with_far_plane = (aabb.maxs()[i] - ray.origin[i]) * denom;
+
+            if inter_with_near_plane > inter_with_far_plane {
+                mem::swap(&mut inter_with_near_plane, &mut inter_with_far_plane)
+            }
+
+            tmin = tmin.max(inter_with_near_plane);
+            tmax = tmax.min(inter_with_far_plane);
+
+            if tmin > tmax {
+                return None;
+            }
+        }
+    }
+
+    Some((tmin, tmax))
+}
+
 fn do_toi_and_normal_and_uv_with_ray<M, P>(
     m: &M,
     aabb: &AABB<P>,
@@ -1,3 +1,5 @@
+use num::Bounded;
+
 use alga::general::Id;
 
 use math::Point;
@@ -7,6 +9,7 @@ use query::{Ray, RayCast, RayIntersection};
 /// A search thet selects the objects that has the smallest time of impact with a given ray.
 pub struct RayIntersectionCostFn<'a, P: 'a + Point> {
     ray: &'a Ray<P>,
+    max_toi: P::Real,
     solid: bool,
     uvs: bool,
 }
@@ -14,8 +17,22 @@ pub struct RayIntersectionCostFn<'a, P: 'a + Point> {
 impl<'a, P: Point> RayIntersectionCostFn<'a, P> {
     /// Creates a new `BestRayInterferenceSearch`.
     pub fn new(ray: &'a Ray<P>, solid: bool, uvs: bool) -> RayIntersectionCostFn<'a, P> {
+        Self::new_with_max_toi(ray, P::Real::max_value(), solid, uvs)
+    }
+
+    /// Creates a new `BestRayInterferenceSearch` ignoring the objects farther than `max_toi` along
+    /// the ray.
+    ///
+    /// The bounding volumes farther than `max_toi` are pruned from the search.
+    pub fn new_with_max_toi(
+        ray: &'a Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+        uvs: bool,
+    ) -> RayIntersectionCostFn<'a, P> {
         RayIntersectionCostFn {
             ray: ray,
+            max_toi: max_toi,
             solid: solid,
             uvs: uvs,
         }
@@ -32,16 +49,16 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, bv: &BV) -> Option<P::Real> {
-        bv.toi_with_ray(&Id::new(), self.ray, true)
+        bv.toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
     }
 
     #[inline]
     fn compute_b_cost(&mut self, b: &B) -> Option<(P::Real, RayIntersection<P::Vector>)> {
         if self.uvs {
-            b.toi_and_normal_and_uv_with_ray(&Id::new(), self.ray, self.solid)
+            b.toi_and_normal_and_uv_with_bounded_ray(&Id::new(), self.ray, self.max_toi, self.solid)
                 .map(|i| (i.toi, i))
         } else {
-            b.toi_and_normal_with_ray(&Id::new(), self.ray, self.solid)
+            b.toi_and_normal_with_bounded_ray(&Id::new(), self.ray, self.max_toi, self.solid)
                 .map(|i| (i.toi, i))
         }
     }
@@ -50,6 +67,7 @@ where
 /// Bounding Volume Tree visitor collecting interferences with a given ray.
 pub struct RayInterferencesCollector<'a, P: 'a + Point, B: 'a> {
     ray: &'a Ray<P>,
+    max_toi: P::Real,
     collector: &'a mut Vec<B>,
 }
 
@@ -57,8 +75,20 @@ impl<'a, P: Point, B> RayInterferencesCollector<'a, P, B> {
     /// Creates a new `RayInterferencesCollector`.
     #[inline]
     pub fn new(ray: &'a Ray<P>, buffer: &'a mut Vec<B>) -> RayInterferencesCollector<'a, P, B> {
+        Self::new_with_max_toi(ray, P::Real::max_value(), buffer)
+    }
+
+    /// Creates a new `RayInterferencesCollector` ignoring the bounding volumes farther than
+    /// `max_toi` along the ray.
+    #[inline]
+    pub fn new_with_max_toi(
+        ray: &'a Ray<P>,
+        max_toi: P::Real,
+        buffer: &'a mut Vec<B>,
+    ) -> RayInterferencesCollector<'a, P, B> {
         RayInterferencesCollector {
             ray: ray,
+            max_toi: max_toi,
             collector: buffer,
         }
     }
@@ -72,12 +102,12 @@ where
 {
     #[inline]
     fn visit_internal(&mut self, bv: &BV) -> bool {
-        bv.intersects_ray(&Id::new(), self.ray)
+        bv.intersects_bounded_ray(&Id::new(), self.ray, self.max_toi)
     }
 
     #[inline]
     fn visit_leaf(&mut self, b: &B, bv: &BV) {
-        if bv.intersects_ray(&Id::new(), self.ray) {
+        if bv.intersects_bounded_ray(&Id::new(), self.ray, self.max_toi) {
             self.collector.push(b.clone())
         }
     }
@@ -0,0 +1,204 @@
+use num::Bounded;
+
+use alga::general::Id;
+use bounding_volume::AABB;
+use shape::{composite_shape, CompositeShape};
+use partitioning::BVTCostFn;
+use query::{CullMode, Ray, RayCast, RayIntersection};
+use math::{Isometry, Point};
+
+/// Computes the time of impact of a ray with a composite shape.
+// XXX: if solid == false, this might return internal intersection.
+pub(crate) fn composite_shape_toi_with_bounded_ray<P, M, G>(
+    m: &M,
+    shape: &G,
+    ray: &Ray<P>,
+    max_toi: P::Real,
+    solid: bool,
+) -> Option<P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    let ls_ray = ray.inverse_transform_by(m);
+    let mut cost_fn = CompositeShapeRayToiCostFn::new(shape, &ls_ray, max_toi, solid);
+
+    composite_shape::best_first_search::<P, M, _, _>(shape, &mut cost_fn).map(|(_, res)| res)
+}
+
+/// Computes the time of impact and normal of a ray with a composite shape.
+///
+/// The `part` of the returned intersection is the index of the part hit.
+// XXX: if solid == false, this might return internal intersection.
+pub(crate) fn composite_shape_toi_and_normal_with_bounded_ray<P, M, G>(
+    m: &M,
+    shape: &G,
+    ray: &Ray<P>,
+    max_toi: P::Real,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    composite_shape_toi_and_normal_with_ray(m, shape, ray, max_toi, CullMode::None, solid)
+}
+
+/// Computes the time of impact and normal of a ray with a composite shape, ignoring the faces of
+/// its parts selected by `cull`.
+///
+/// The `part` of the returned intersection is the index of the part hit.
+pub(crate) fn composite_shape_toi_and_normal_with_culled_ray<P, M, G>(
+    m: &M,
+    shape: &G,
+    ray: &Ray<P>,
+    cull: CullMode,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    composite_shape_toi_and_normal_with_ray(m, shape, ray, P::Real::max_value(), cull, solid)
+}
+
+fn composite_shape_toi_and_normal_with_ray<P, M, G>(
+    m: &M,
+    shape: &G,
+    ray: &Ray<P>,
+    max_toi: P::Real,
+    cull: CullMode,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    let ls_ray = ray.inverse_transform_by(m);
+    let mut cost_fn =
+        CompositeShapeRayToiAndNormalCostFn::new(shape, &ls_ray, max_toi, cull, solid);
+
+    composite_shape::best_first_search::<P, M, _, _>(shape, &mut cost_fn).map(|(_, mut res)| {
+        res.normal = m.rotate_vector(&res.normal);
+        res
+    })
+}
+
+/*
+ * Costs functions.
+ */
+/// A search for the part of a composite shape with the smallest time of impact with a ray.
+pub(crate) struct CompositeShapeRayToiCostFn<'a, P: 'a + Point, M: 'a> {
+    shape: &'a CompositeShape<P, M>,
+    ray: &'a Ray<P>,
+    max_toi: P::Real,
+    solid: bool,
+}
+
+impl<'a, P: Point, M> CompositeShapeRayToiCostFn<'a, P, M> {
+    /// Creates a search for the local-space `ray`.
+    pub(crate) fn new(
+        shape: &'a CompositeShape<P, M>,
+        ray: &'a Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Self {
+        CompositeShapeRayToiCostFn {
+            shape: shape,
+            ray: ray,
+            max_toi: max_toi,
+            solid: solid,
+        }
+    }
+}
+
+impl<'a, P, M> BVTCostFn<P::Real, usize, AABB<P>> for CompositeShapeRayToiCostFn<'a, P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    type UserData = P::Real;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        aabb.toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+        let mut res = None;
+
+        self.shape.map_part_at(*b, &mut |objm, obj| {
+            res = obj.toi_with_bounded_ray(objm, self.ray, self.max_toi, self.solid)
+                .map(|toi| (toi, toi))
+        });
+
+        res
+    }
+}
+
+/// A search for the part of a composite shape with the smallest time of impact with a ray, also
+/// computing the normal at the impact point.
+pub(crate) struct CompositeShapeRayToiAndNormalCostFn<'a, P: 'a + Point, M: 'a> {
+    shape: &'a CompositeShape<P, M>,
+    ray: &'a Ray<P>,
+    max_toi: P::Real,
+    cull: CullMode,
+    solid: bool,
+}
+
+impl<'a, P: Point, M> CompositeShapeRayToiAndNormalCostFn<'a, P, M> {
+    /// Creates a search for the local-space `ray`, ignoring the faces of the parts selected by
+    /// `cull`.
+    pub(crate) fn new(
+        shape: &'a CompositeShape<P, M>,
+        ray: &'a Ray<P>,
+        max_toi: P::Real,
+        cull: CullMode,
+        solid: bool,
+    ) -> Self {
+        CompositeShapeRayToiAndNormalCostFn {
+            shape: shape,
+            ray: ray,
+            max_toi: max_toi,
+            cull: cull,
+            solid: solid,
+        }
+    }
+}
+
+impl<'a, P: Point, M: Isometry<P>> BVTCostFn<P::Real, usize, AABB<P>>
+    for CompositeShapeRayToiAndNormalCostFn<'a, P, M> {
+    type UserData = RayIntersection<P::Vector>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        aabb.toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
+        let mut res = None;
+
+        self.shape.map_part_at(*b, &mut |objm, obj| {
+            let inter = match self.cull {
+                CullMode::None => {
+                    obj.toi_and_normal_with_bounded_ray(objm, self.ray, self.max_toi, self.solid)
+                }
+                cull => obj.toi_and_normal_with_culled_ray(objm, self.ray, cull, self.solid)
+                    .and_then(|inter| if inter.toi <= self.max_toi { Some(inter) } else { None }),
+            };
+
+            res = inter.map(|mut inter| {
+                inter.part = Some(*b);
+                (inter.toi, inter)
+            })
+        });
+
+        res
+    }
+}
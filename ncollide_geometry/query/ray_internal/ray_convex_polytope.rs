@@ -0,0 +1,164 @@
+use num::{Bounded, Zero};
+
+use alga::general::Real;
+use na::{self, Point2, Point3};
+
+use query::{Ray, RayCast, RayIntersection};
+use shape::{ConvexPolygon, ConvexPolyhedron, FeatureId};
+use math::{Isometry, Point};
+
+impl<N: Real, M: Isometry<Point3<N>>> RayCast<Point3<N>, M> for ConvexPolyhedron<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<Point3<N>>,
+        solid: bool,
+    ) -> Option<RayIntersection<na::Vector3<N>>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let faces = self.faces()
+            .iter()
+            .map(|f| (self.points()[f.vertices()[0]], *f.normal().as_ref()));
+
+        polytope_toi_and_normal_with_ray(&ls_ray, faces, solid).map(|(toi, normal, face)| {
+            let mut res = RayIntersection::new(toi, m.rotate_vector(&normal));
+
+            if let Some(face) = face {
+                res.feature = polyhedron_feature(self, face, &(ls_ray.origin + ls_ray.dir * toi));
+            }
+
+            res
+        })
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> RayCast<Point2<N>, M> for ConvexPolygon<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<Point2<N>>,
+        solid: bool,
+    ) -> Option<RayIntersection<na::Vector2<N>>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let faces = self.points()
+            .iter()
+            .zip(self.normals().iter())
+            .map(|(pt, n)| (*pt, *n.as_ref()));
+
+        polytope_toi_and_normal_with_ray(&ls_ray, faces, solid).map(|(toi, normal, face)| {
+            let mut res = RayIntersection::new(toi, m.rotate_vector(&normal));
+
+            if let Some(face) = face {
+                res.feature = polygon_feature(self, face, &(ls_ray.origin + ls_ray.dir * toi));
+            }
+
+            res
+        })
+    }
+}
+
+// The feature of the face `face` of a polyhedron containing the point `pt`.
+//
+// This is a vertex or an edge of the face if `pt` lies on it, up to a small epsilon.
+fn polyhedron_feature<N: Real>(
+    poly: &ConvexPolyhedron<N>,
+    face: usize,
+    pt: &Point3<N>,
+) -> FeatureId {
+    let eps = N::default_epsilon().sqrt();
+    let f = &poly.faces()[face];
+
+    for v in f.vertices() {
+        if na::distance(&poly.points()[*v], pt) <= eps {
+            return FeatureId::Vertex(*v);
+        }
+    }
+
+    for e in f.edges() {
+        let vs = poly.edges()[*e].vertices();
+        let a = poly.points()[vs[0]];
+        let ab = poly.points()[vs[1]] - a;
+
+        if na::norm(&ab.cross(&(*pt - a))) <= eps * na::norm(&ab) {
+            return FeatureId::Edge(*e);
+        }
+    }
+
+    FeatureId::Face(face)
+}
+
+// The feature of the face `face` of a polygon containing the point `pt`.
+//
+// This is a vertex of the face if `pt` lies on it, up to a small epsilon.
+fn polygon_feature<N: Real>(poly: &ConvexPolygon<N>, face: usize, pt: &Point2<N>) -> FeatureId {
+    let eps = N::default_epsilon().sqrt();
+    let next = (face + 1) % poly.points().len();
+
+    if na::distance(&poly.points()[face], pt) <= eps {
+        FeatureId::Vertex(face)
+    } else if na::distance(&poly.points()[next], pt) <= eps {
+        FeatureId::Vertex(next)
+    } else {
+        FeatureId::Face(face)
+    }
+}
+
+// Clips the ray with the half-spaces bounded by each face of the polytope.
+//
+// Each face is given by one of its points and its outward normal. The index of the face hit is
+// returned as well, unless the ray starts inside of a solid polytope.
+fn polytope_toi_and_normal_with_ray<P, I>(
+    ray: &Ray<P>,
+    faces: I,
+    solid: bool,
+) -> Option<(P::Real, P::Vector, Option<usize>)>
+where
+    P: Point,
+    I: Iterator<Item = (P, P::Vector)>,
+{
+    let _0 = P::Real::zero();
+    let mut tmax = P::Real::max_value();
+    let mut tmin = -tmax;
+    let mut near_face = None;
+    let mut far_face = None;
+
+    for (i, (pt, normal)) in faces.enumerate() {
+        let denom = na::dot(&normal, &ray.dir);
+        let dist = na::dot(&normal, &(pt - ray.origin));
+
+        if denom.is_zero() {
+            // The ray is parallel to the face.
+            if dist < _0 {
+                return None;
+            }
+        } else {
+            let t = dist / denom;
+
+            if denom < _0 {
+                if t > tmin {
+                    tmin = t;
+                    near_face = Some((i, normal));
+                }
+            } else if t < tmax {
+                tmax = t;
+                far_face = Some((i, normal));
+            }
+
+            if tmin > tmax {
+                return None;
+            }
+        }
+    }
+
+    if tmin < _0 {
+        // The ray starts inside of the polytope.
+        if tmax < _0 {
+            None
+        } else if solid {
+            Some((_0, na::zero(), None))
+        } else {
+            far_face.map(|(i, n)| (tmax, -n, Some(i)))
+        }
+    } else {
+        near_face.map(|(i, n)| (tmin, n, Some(i)))
+    }
+}
@@ -1,5 +1,9 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+
 use bounding_volume::AABB;
-use shape::Cuboid;
+use shape::{Cuboid, FeatureId};
 use query::{Ray, RayCast, RayIntersection};
 use math::{Isometry, Point};
 
@@ -20,7 +24,13 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Cuboid<P::Vector> {
     ) -> Option<RayIntersection<P::Vector>> {
         let dl = P::from_coordinates(-*self.half_extents());
         let ur = P::from_coordinates(*self.half_extents());
-        AABB::new(dl, ur).toi_and_normal_with_ray(m, ray, solid)
+        AABB::new(dl, ur).toi_and_normal_with_ray(m, ray, solid).map(|mut res| {
+            let ls_ray = ray.inverse_transform_by(m);
+            let pt = ls_ray.origin + ls_ray.dir * res.toi;
+
+            res.feature = cuboid_feature(self.half_extents(), &pt);
+            res
+        })
     }
 
     #[inline]
@@ -32,6 +42,54 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Cuboid<P::Vector> {
     ) -> Option<RayIntersection<P::Vector>> {
         let dl = P::from_coordinates(-*self.half_extents());
         let ur = P::from_coordinates(*self.half_extents());
-        AABB::new(dl, ur).toi_and_normal_and_uv_with_ray(m, ray, solid)
+        AABB::new(dl, ur).toi_and_normal_and_uv_with_ray(m, ray, solid).map(|mut res| {
+            let ls_ray = ray.inverse_transform_by(m);
+            let pt = ls_ray.origin + ls_ray.dir * res.toi;
+
+            res.feature = cuboid_feature(self.half_extents(), &pt);
+            res
+        })
+    }
+}
+
+// The feature of a cuboid with the given half-extents containing the point `pt`.
+//
+// The point is on a face, an edge or a vertex if it lies on the planes of one, two or all the faces
+// adjacent to it, up to a small epsilon.
+fn cuboid_feature<P: Point>(half_extents: &P::Vector, pt: &P) -> FeatureId {
+    let dim = na::dimension::<P::Vector>();
+    let eps = P::Real::default_epsilon().sqrt();
+    let mut nfaces = 0;
+    let mut face = 0;
+    let mut free_axis = 0;
+    let mut signs = 0;
+
+    for i in 0..dim {
+        if pt[i] > na::zero() {
+            signs |= 1 << i;
+        }
+
+        if (pt[i].abs() - half_extents[i]).abs() <= eps {
+            nfaces += 1;
+            face = if pt[i] > na::zero() { i } else { i + dim };
+        } else {
+            free_axis = i;
+        }
+    }
+
+    if nfaces == 0 {
+        FeatureId::Unknown
+    } else if nfaces == 1 {
+        FeatureId::Face(face)
+    } else if nfaces == dim {
+        FeatureId::Vertex(signs)
+    } else if nfaces == 2 && dim == 3 {
+        // Remove the sign along the axis the edge is parallel to.
+        let low = signs & ((1 << free_axis) - 1);
+        let high = (signs >> (free_axis + 1)) << free_axis;
+
+        FeatureId::Edge(4 * free_axis + (low | high))
+    } else {
+        FeatureId::Unknown
     }
 }
@@ -0,0 +1,54 @@
+use num::Bounded;
+
+use shape::DynamicCompound;
+use query::{CullMode, Ray, RayCast, RayIntersection};
+use query::ray_internal::ray_composite_shape::{composite_shape_toi_and_normal_with_bounded_ray,
+                                               composite_shape_toi_and_normal_with_culled_ray,
+                                               composite_shape_toi_with_bounded_ray};
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for DynamicCompound<P, M> {
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        self.toi_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    fn toi_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<P::Real> {
+        composite_shape_toi_with_bounded_ray(m, self, ray, max_toi, solid)
+    }
+
+    fn toi_and_normal_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        composite_shape_toi_and_normal_with_bounded_ray(m, self, ray, max_toi, solid)
+    }
+
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        composite_shape_toi_and_normal_with_culled_ray(m, self, ray, cull, solid)
+    }
+
+}
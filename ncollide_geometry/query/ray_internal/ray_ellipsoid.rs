@@ -0,0 +1,55 @@
+use na;
+
+use query::{ray_internal, Ray, RayCast, RayIntersection};
+use shape::Ellipsoid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        let unit_ray = unscale_ray(self, &ray.inverse_transform_by(m));
+
+        ray_internal::ball_toi_with_ray(&P::origin(), na::one(), &unit_ray, solid).1
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let unit_ray = unscale_ray(self, &ls_ray);
+        let (inside, inter) =
+            ray_internal::ball_toi_with_ray(&P::origin(), na::one(), &unit_ray, solid);
+
+        inter.map(|toi| {
+            // The normal is the gradient of `sum((x_i / r_i)²)` at the hit point.
+            let mut normal = (ls_ray.origin + ls_ray.dir * toi).coordinates();
+
+            for i in 0..na::dimension::<P::Vector>() {
+                normal[i] /= self.radii()[i] * self.radii()[i];
+            }
+
+            let normal = m.rotate_vector(&na::normalize(&normal));
+
+            RayIntersection::new(toi, if inside { -normal } else { normal })
+        })
+    }
+}
+
+/// Maps a local-space ray to the space where the ellipsoid is the unit ball.
+///
+/// The ray parameters, hence the times of impact, are preserved by this mapping.
+fn unscale_ray<P: Point>(ellipsoid: &Ellipsoid<P::Vector>, ray: &Ray<P>) -> Ray<P> {
+    let mut origin = ray.origin;
+    let mut dir = ray.dir;
+
+    for i in 0..na::dimension::<P::Vector>() {
+        origin[i] /= ellipsoid.radii()[i];
+        dir[i] /= ellipsoid.radii()[i];
+    }
+
+    Ray::new(origin, dir)
+}
@@ -0,0 +1,236 @@
+use num::Bounded;
+
+use alga::general::{Id, Real};
+use na;
+
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal;
+use bounding_volume::AABB;
+use shape::HeightField;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for HeightField<P> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        heightfield_toi_and_normal_with_ray(self, &ls_ray, solid).map(|mut res| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+
+    #[inline]
+    fn toi_and_normal_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        heightfield_toi_and_normal_with_bounded_ray(self, &ls_ray, max_toi, solid).map(|mut res| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/// Computes the time of impact and normal of a ray on a heightfield expressed in its local frame.
+///
+/// The cells traversed by the projection of the ray on the heightfield domain are visited in
+/// order, so only the triangles (or segments) of those cells are tested.
+pub fn heightfield_toi_and_normal_with_ray<P: Point>(
+    heightfield: &HeightField<P>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    heightfield_toi_and_normal_with_bounded_ray(heightfield, ray, P::Real::max_value(), solid)
+}
+
+/// Computes the time of impact and normal of a ray on a heightfield expressed in its local frame,
+/// ignoring the intersections with a time of impact greater than `max_toi`.
+///
+/// The traversal of the cells stops as soon as they are farther than `max_toi` along the ray.
+pub fn heightfield_toi_and_normal_with_bounded_ray<P: Point>(
+    heightfield: &HeightField<P>,
+    ray: &Ray<P>,
+    max_toi: P::Real,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    let aabb = heightfield.local_aabb();
+    let (tmin, tmax) = match ray_internal::clip_ray_with_aabb(aabb, ray) {
+        Some(clip) => clip,
+        None => return None,
+    };
+
+    if tmin > max_toi {
+        return None;
+    }
+
+    let tmax = tmax.min(max_toi);
+
+    // Clamp the entry point to fight numerical errors near the AABB boundary.
+    let mut entry = ray.origin + ray.dir * tmin;
+
+    for i in 0..na::dimension::<P::Vector>() {
+        if entry[i] < aabb.mins()[i] {
+            entry[i] = aabb.mins()[i];
+        } else if entry[i] > aabb.maxs()[i] {
+            entry[i] = aabb.maxs()[i];
+        }
+    }
+
+    let (mut ix, mut iz) = match heightfield.cell_at_point(&entry) {
+        Some(cell) => cell,
+        None => return None,
+    };
+
+    let is_3d = na::dimension::<P::Vector>() != 2;
+    let mut walk_x = CellWalk::new(aabb, 0, heightfield.num_cells_x(), ix, &entry, ray, tmin);
+    let mut walk_z = if is_3d {
+        CellWalk::new(aabb, 2, heightfield.num_cells_z(), iz, &entry, ray, tmin)
+    } else {
+        CellWalk::stationary(2)
+    };
+
+    loop {
+        let mut best: Option<RayIntersection<P::Vector>> = None;
+
+        for part in heightfield.cell_parts(ix, iz) {
+            let inter = if is_3d {
+                heightfield
+                    .triangle_at(part)
+                    .toi_and_normal_with_ray(&Id::new(), ray, solid)
+            } else {
+                heightfield
+                    .segment_at(part)
+                    .toi_and_normal_with_ray(&Id::new(), ray, solid)
+            };
+
+            if let Some(mut inter) = inter {
+                if inter.toi > max_toi {
+                    continue;
+                }
+
+                inter.part = Some(part);
+
+                let is_closer = match best {
+                    Some(ref best) => inter.toi < best.toi,
+                    None => true,
+                };
+
+                if is_closer {
+                    best = Some(inter)
+                }
+            }
+        }
+
+        // Any hit on this cell is closer than the hits on the cells traversed next.
+        if best.is_some() {
+            return best;
+        }
+
+        // Move to the next cell.
+        let walk = if walk_z.tnext < walk_x.tnext {
+            &mut walk_z
+        } else {
+            &mut walk_x
+        };
+
+        if walk.tnext >= tmax {
+            return None;
+        }
+
+        let next = match walk.advance() {
+            Some(next) => next,
+            None => return None,
+        };
+
+        if walk.axis == 0 {
+            ix = next;
+        } else {
+            iz = next;
+        }
+    }
+}
+
+// Traversal of the cells of the heightfield along one axis.
+struct CellWalk<N> {
+    axis: usize,
+    curr: usize,
+    ncells: usize,
+    forward: bool,
+    tnext: N,
+    tdelta: N,
+}
+
+impl<N: Real> CellWalk<N> {
+    fn new<P: Point<Real = N>>(
+        aabb: &AABB<P>,
+        axis: usize,
+        ncells: usize,
+        curr: usize,
+        entry: &P,
+        ray: &Ray<P>,
+        tentry: N,
+    ) -> CellWalk<N> {
+        let min = aabb.mins()[axis];
+        let cell_size = (aabb.maxs()[axis] - min) / na::convert(ncells as f64);
+        let dir = ray.dir[axis];
+
+        if dir.is_zero() {
+            return CellWalk::stationary(axis);
+        }
+
+        let forward = dir > na::zero();
+        let boundary_id = if forward { curr + 1 } else { curr };
+        let boundary = min + cell_size * na::convert(boundary_id as f64);
+
+        CellWalk {
+            axis: axis,
+            curr: curr,
+            ncells: ncells,
+            forward: forward,
+            tnext: tentry + (boundary - entry[axis]) / dir,
+            tdelta: cell_size / dir.abs(),
+        }
+    }
+
+    fn stationary(axis: usize) -> CellWalk<N> {
+        CellWalk {
+            axis: axis,
+            curr: 0,
+            ncells: 1,
+            forward: true,
+            tnext: N::max_value(),
+            tdelta: na::zero(),
+        }
+    }
+
+    fn advance(&mut self) -> Option<usize> {
+        if self.forward {
+            if self.curr + 1 >= self.ncells {
+                return None;
+            }
+
+            self.curr += 1;
+        } else {
+            if self.curr == 0 {
+                return None;
+            }
+
+            self.curr -= 1;
+        }
+
+        self.tnext += self.tdelta;
+
+        Some(self.curr)
+    }
+}
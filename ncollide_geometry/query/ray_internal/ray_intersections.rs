@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+use num::Zero;
+
+use alga::general::Real;
+use na;
+
+use query::algorithms::gjk;
+use query::{ray_internal, Ray, RayCast, RayInterferencesCollector, RayIntersection};
+use shape::Shape;
+use math::{Isometry, Point};
+
+/// Computes all the intersections between a ray and a transformed shape, sorted by time of impact.
+///
+/// Composite shapes report the intersections with each of their parts, with `part` set to the
+/// index of the part hit. Other shapes are considered solid: the point where the ray enters them is
+/// reported (unless the ray origin is inside of the shape), followed by the point where the ray
+/// leaves them. Flat shapes like triangles and segments are reported once. The normals always
+/// point toward the outside of the shape hit.
+///
+/// Non-convex shapes that are not composite (e.g. `Torus`) only report their first entry point
+/// and their last exit point.
+pub fn ray_intersections_with_shape<P, M>(
+    m: &M,
+    shape: &Shape<P, M>,
+    ray: &Ray<P>,
+) -> Vec<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut res = Vec::new();
+
+    collect_ray_intersections(m, shape, ray, &mut res);
+    res.sort_by(|a, b| a.toi.partial_cmp(&b.toi).unwrap_or(Ordering::Equal));
+
+    res
+}
+
+fn collect_ray_intersections<P, M>(
+    m: &M,
+    shape: &Shape<P, M>,
+    ray: &Ray<P>,
+    out: &mut Vec<RayIntersection<P::Vector>>,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let Some(cshape) = shape.as_composite_shape() {
+        let ls_ray = ray.inverse_transform_by(m);
+        let mut parts = Vec::new();
+
+        {
+            let mut visitor = RayInterferencesCollector::new(&ls_ray, &mut parts);
+            cshape.visit_parts(&mut visitor);
+        }
+
+        for i in parts {
+            let start = out.len();
+
+            cshape.map_transformed_part_at(i, m, &mut |m, part| {
+                collect_ray_intersections(m, part, ray, out)
+            });
+
+            for inter in &mut out[start..] {
+                inter.part = Some(i);
+            }
+        }
+    } else {
+        convex_ray_intersections(m, shape, ray, out)
+    }
+}
+
+fn convex_ray_intersections<P, M>(
+    m: &M,
+    shape: &Shape<P, M>,
+    ray: &Ray<P>,
+    out: &mut Vec<RayIntersection<P::Vector>>,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    let entry = match shape.toi_and_normal_with_ray(m, ray, true) {
+        Some(entry) => entry,
+        None => return,
+    };
+
+    if entry.toi.is_zero() {
+        // The ray origin is inside of the shape: a non-solid ray cast reaches the exit point.
+        if let Some(exit) = shape.toi_and_normal_with_ray(m, ray, false) {
+            if !exit.toi.is_zero() {
+                out.push(exit)
+            }
+        }
+
+        return;
+    }
+
+    // Cast the ray backward from a point out of the AABB to find the exit point.
+    let far = match ray_internal::clip_ray_with_aabb(&shape.aabb(m), ray) {
+        Some((_, tmax)) => tmax + na::one(),
+        None => return,
+    };
+    let back_ray = Ray::new(ray.origin + ray.dir * far, -ray.dir);
+    let exit = shape.toi_and_normal_with_ray(m, &back_ray, true);
+    let entry_toi = entry.toi;
+
+    out.push(entry);
+
+    if let Some(mut exit) = exit {
+        exit.toi = far - exit.toi;
+
+        // The back ray starts inside of unbounded shapes, and hits flat shapes at the entry point.
+        if exit.toi < far && exit.toi > entry_toi + gjk::eps_tol::<P::Real>().sqrt() {
+            out.push(exit)
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use std::ops::Index;
-use num::Zero;
+use num::{Bounded, Zero};
 
 use alga::general::Id;
 use alga::linear::NormedSpace;
 use na::{self, Point2, Vector3};
 
-use query::{ray_internal, Ray, RayCast, RayIntersection};
+use query::{ray_internal, CullMode, PointQuery, Ray, RayCast, RayIntersection};
 use shape::{BaseMesh, BaseMeshElement, Polyline, TriMesh};
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
@@ -19,12 +19,44 @@ where
     E: BaseMeshElement<I, P> + RayCast<P, Id>,
 {
     #[inline]
-    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, _: bool) -> Option<P::Real> {
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        self.toi_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_and_uv_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    #[inline]
+    fn toi_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        _: bool,
+    ) -> Option<P::Real> {
         let ls_ray = ray.inverse_transform_by(m);
 
         let mut cost_fn = BaseMeshRayToiCostFn {
             mesh: self,
             ray: &ls_ray,
+            max_toi: max_toi,
         };
 
         self.bvt()
@@ -33,35 +65,25 @@ where
     }
 
     #[inline]
-    fn toi_and_normal_with_ray(
+    fn toi_and_normal_with_bounded_ray(
         &self,
         m: &M,
         ray: &Ray<P>,
+        max_toi: P::Real,
         _: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        let mut cost_fn = BaseMeshRayToiAndNormalCostFn {
-            mesh: self,
-            ray: &ls_ray,
-        };
-
-        self.bvt()
-            .best_first_search(&mut cost_fn)
-            .map(|(_, mut res)| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+        base_mesh_toi_and_normal_with_ray(self, m, ray, max_toi, CullMode::None)
     }
 
-    fn toi_and_normal_and_uv_with_ray(
+    fn toi_and_normal_and_uv_with_bounded_ray(
         &self,
         m: &M,
         ray: &Ray<P>,
+        max_toi: P::Real,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
         if self.uvs().is_none() || na::dimension::<P::Vector>() != 3 {
-            return self.toi_and_normal_with_ray(m, ray, solid);
+            return self.toi_and_normal_with_bounded_ray(m, ray, max_toi, solid);
         }
 
         let ls_ray = ray.inverse_transform_by(m);
@@ -69,6 +91,7 @@ where
         let mut cost_fn = BaseMeshRayToiAndNormalAndUVsCostFn {
             mesh: self,
             ray: &ls_ray,
+            max_toi: max_toi,
         };
         let cast = self.bvt().best_first_search(&mut cost_fn);
 
@@ -90,12 +113,8 @@ where
                 let uvy = uv1.y * uv.x + uv2.y * uv.y + uv3.y * uv.z;
 
                 // XXX: this interpolation should be done on the two other ray cast too!
-                match *self.normals() {
-                    None => Some(RayIntersection::new_with_uvs(
-                        toi,
-                        m.rotate_vector(&n),
-                        Some(Point2::new(uvx, uvy)),
-                    )),
+                let normal = match *self.normals() {
+                    None => n,
                     Some(ref ns) => {
                         let n1 = &ns[idx[0]];
                         let n2 = &ns[idx[1]];
@@ -104,31 +123,67 @@ where
                         let mut n123 = *n1 * uv.x + *n2 * uv.y + *n3 * uv.z;
 
                         if n123.normalize_mut().is_zero() {
-                            Some(RayIntersection::new_with_uvs(
-                                toi,
-                                m.rotate_vector(&n),
-                                Some(Point2::new(uvx, uvy)),
-                            ))
+                            n
+                        } else if na::dot(&n123, &ls_ray.dir) > na::zero() {
+                            -n123
                         } else {
-                            if na::dot(&n123, &ls_ray.dir) > na::zero() {
-                                Some(RayIntersection::new_with_uvs(
-                                    toi,
-                                    -m.rotate_vector(&n123),
-                                    Some(Point2::new(uvx, uvy)),
-                                ))
-                            } else {
-                                Some(RayIntersection::new_with_uvs(
-                                    toi,
-                                    m.rotate_vector(&n123),
-                                    Some(Point2::new(uvx, uvy)),
-                                ))
-                            }
+                            n123
                         }
                     }
-                }
+                };
+
+                let mut res = RayIntersection::new_with_uvs(
+                    toi,
+                    m.rotate_vector(&normal),
+                    Some(Point2::new(uvx, uvy)),
+                );
+                res.feature = inter.0.feature;
+                res.part = Some(*best);
+
+                Some(res)
             }
         }
     }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        _: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        base_mesh_toi_and_normal_with_ray(self, m, ray, P::Real::max_value(), cull)
+    }
+}
+
+fn base_mesh_toi_and_normal_with_ray<P, M, I, E>(
+    mesh: &BaseMesh<P, I, E>,
+    m: &M,
+    ray: &Ray<P>,
+    max_toi: P::Real,
+    cull: CullMode,
+) -> Option<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+    E: BaseMeshElement<I, P> + RayCast<P, Id>,
+{
+    let ls_ray = ray.inverse_transform_by(m);
+
+    let mut cost_fn = BaseMeshRayToiAndNormalCostFn {
+        mesh: mesh,
+        ray: &ls_ray,
+        max_toi: max_toi,
+        cull: cull,
+    };
+
+    mesh.bvt()
+        .best_first_search(&mut cost_fn)
+        .map(|(_, mut res)| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
 }
 
 /*
@@ -137,6 +192,7 @@ where
 struct BaseMeshRayToiCostFn<'a, P: 'a + Point, I: 'a, E: 'a> {
     mesh: &'a BaseMesh<P, I, E>,
     ray: &'a Ray<P>,
+    max_toi: P::Real,
 }
 
 impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>> for BaseMeshRayToiCostFn<'a, P, I, E>
@@ -148,14 +204,14 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
-        aabb.toi_with_ray(&Id::new(), self.ray, true)
+        aabb.toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
     }
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
         self.mesh
             .element_at(*b)
-            .toi_with_ray(&Id::new(), self.ray, true)
+            .toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
             .map(|toi| (toi, toi))
     }
 }
@@ -163,6 +219,8 @@ where
 struct BaseMeshRayToiAndNormalCostFn<'a, P: 'a + Point, I: 'a, E: 'a> {
     mesh: &'a BaseMesh<P, I, E>,
     ray: &'a Ray<P>,
+    max_toi: P::Real,
+    cull: CullMode,
 }
 
 impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>> for BaseMeshRayToiAndNormalCostFn<'a, P, I, E>
@@ -174,21 +232,29 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
-        aabb.toi_with_ray(&Id::new(), self.ray, true)
+        aabb.toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
     }
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
         self.mesh
             .element_at(*b)
-            .toi_and_normal_with_ray(&Id::new(), self.ray, true)
-            .map(|inter| (inter.toi, inter))
+            .toi_and_normal_with_culled_ray(&Id::new(), self.ray, self.cull, true)
+            .and_then(|mut inter| {
+                if inter.toi <= self.max_toi {
+                    inter.part = Some(*b);
+                    Some((inter.toi, inter))
+                } else {
+                    None
+                }
+            })
     }
 }
 
 struct BaseMeshRayToiAndNormalAndUVsCostFn<'a, P: 'a + Point, I: 'a, E: 'a> {
     mesh: &'a BaseMesh<P, I, E>,
     ray: &'a Ray<P>,
+    max_toi: P::Real,
 }
 
 impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>>
@@ -202,7 +268,7 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
-        aabb.toi_with_ray(&Id::new(), self.ray, true)
+        aabb.toi_with_bounded_ray(&Id::new(), self.ray, self.max_toi, true)
     }
 
     #[inline]
@@ -217,7 +283,14 @@ where
         let b = &vs[idx[1]];
         let c = &vs[idx[2]];
 
-        ray_internal::triangle_ray_intersection(a, b, c, self.ray).map(|inter| (inter.0.toi, inter))
+        ray_internal::triangle_ray_intersection(a, b, c, self.ray)
+            .and_then(|inter| {
+                if inter.0.toi <= self.max_toi {
+                    Some((inter.0.toi, inter))
+                } else {
+                    None
+                }
+            })
     }
 }
 
@@ -227,7 +300,7 @@ where
 impl<P: Point, M: Isometry<P>> RayCast<P, M> for TriMesh<P> {
     #[inline]
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
-        self.base_mesh().toi_with_ray(m, ray, solid)
+        self.toi_with_bounded_ray(m, ray, P::Real::max_value(), solid)
     }
 
     #[inline]
@@ -237,7 +310,7 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for TriMesh<P> {
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        self.base_mesh().toi_and_normal_with_ray(m, ray, solid)
+        self.toi_and_normal_with_bounded_ray(m, ray, P::Real::max_value(), solid)
     }
 
     #[inline]
@@ -247,15 +320,78 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for TriMesh<P> {
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_and_uv_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    #[inline]
+    fn toi_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<P::Real> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(na::zero());
+        }
+
+        self.base_mesh()
+            .toi_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(RayIntersection::new(na::zero(), na::zero()));
+        }
+
+        self.base_mesh()
+            .toi_and_normal_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(RayIntersection::new(na::zero(), na::zero()));
+        }
+
+        self.base_mesh()
+            .toi_and_normal_and_uv_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(RayIntersection::new(na::zero(), na::zero()));
+        }
+
         self.base_mesh()
-            .toi_and_normal_and_uv_with_ray(m, ray, solid)
+            .toi_and_normal_with_culled_ray(m, ray, cull, solid)
     }
 }
 
 impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
     #[inline]
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
-        self.base_mesh().toi_with_ray(m, ray, solid)
+        self.toi_with_bounded_ray(m, ray, P::Real::max_value(), solid)
     }
 
     #[inline]
@@ -265,7 +401,7 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        self.base_mesh().toi_and_normal_with_ray(m, ray, solid)
+        self.toi_and_normal_with_bounded_ray(m, ray, P::Real::max_value(), solid)
     }
 
     #[inline]
@@ -275,7 +411,54 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_and_uv_with_bounded_ray(m, ray, P::Real::max_value(), solid)
+    }
+
+    #[inline]
+    fn toi_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<P::Real> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(na::zero());
+        }
+
+        self.base_mesh()
+            .toi_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(RayIntersection::new(na::zero(), na::zero()));
+        }
+
+        self.base_mesh()
+            .toi_and_normal_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        if solid && self.is_closed() && self.contains_point(m, &ray.origin) {
+            return Some(RayIntersection::new(na::zero(), na::zero()));
+        }
+
         self.base_mesh()
-            .toi_and_normal_and_uv_with_ray(m, ray, solid)
+            .toi_and_normal_and_uv_with_bounded_ray(m, ray, max_toi, solid)
     }
 }
@@ -1,6 +1,6 @@
 use na;
 
-use query::{Ray, RayCast, RayIntersection};
+use query::{CullMode, Ray, RayCast, RayIntersection};
 use shape::Plane;
 use math::{Isometry, Point};
 
@@ -54,4 +54,23 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Plane<P::Vector> {
             None
         }
     }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let normal = self.normal().as_ref();
+        let inside = na::dot(normal, &ls_ray.origin.coordinates()) < na::zero();
+
+        if !(solid && inside) && cull.culls(normal, &ls_ray.dir) {
+            return None;
+        }
+
+        self.toi_and_normal_with_ray(m, ray, solid)
+    }
 }
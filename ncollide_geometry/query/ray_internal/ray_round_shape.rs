@@ -0,0 +1,49 @@
+use na;
+
+use query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal;
+use shape::{RoundShape, SupportMap};
+use math::{Isometry, Point};
+
+impl<P, M, S> RayCast<P, M> for RoundShape<P::Real, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
+{
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        // NOTE: the core shape only has a support mapping for the transformation type `M` so the
+        // ray is not expressed in the local space of the shape.
+        if na::dimension::<P::Vector>() == 2 {
+            ray_internal::implicit_toi_and_normal_with_ray(
+                m,
+                self,
+                &mut VoronoiSimplex2::<P>::new(),
+                ray,
+                solid,
+            )
+        } else if na::dimension::<P::Vector>() == 3 {
+            ray_internal::implicit_toi_and_normal_with_ray(
+                m,
+                self,
+                &mut VoronoiSimplex3::<P>::new(),
+                ray,
+                solid,
+            )
+        } else {
+            ray_internal::implicit_toi_and_normal_with_ray(
+                m,
+                self,
+                &mut JohnsonSimplex::<P>::new_w_tls(),
+                ray,
+                solid,
+            )
+        }
+    }
+}
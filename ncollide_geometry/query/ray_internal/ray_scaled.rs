@@ -0,0 +1,96 @@
+use na;
+
+use query::{CullMode, Ray, RayCast, RayIntersection};
+use shape::{Scaled, ScaledCompoundPart, Shape};
+use math::{Isometry, Point};
+
+impl<P, M, S> RayCast<P, M> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_culled_ray(m, ray, CullMode::None, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let one: M = na::one();
+
+        self.inner().as_ray_cast().and_then(|shape| {
+            scaled_toi_and_normal_with_ray(self, shape, &one, m, ray, cull, solid)
+        })
+    }
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for ScaledCompoundPart<P, M> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_culled_ray(m, ray, CullMode::None, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.shape().as_ray_cast().and_then(|shape| {
+            scaled_toi_and_normal_with_ray(self.scaled(), shape, self.m(), m, ray, cull, solid)
+        })
+    }
+}
+
+// Casts a ray on `shape` transformed by `inner_m`, scaled by `scaled`, and then transformed by `m`.
+//
+// The scaling factors are positive so the faces culled by `cull` are not affected by the scaling.
+fn scaled_toi_and_normal_with_ray<P, M, S>(
+    scaled: &Scaled<P::Vector, S>,
+    shape: &RayCast<P, M>,
+    inner_m: &M,
+    m: &M,
+    ray: &Ray<P>,
+    cull: CullMode,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    // The time of impact is invariant under the scaling of both the ray origin and
+    // direction. The normal is transformed by the inverse transpose of the scaling.
+    let ls_ray = ray.inverse_transform_by(m);
+    let unscaled_ray = Ray::new(
+        scaled.unscale_point(&ls_ray.origin),
+        scaled.unscale_vector(&ls_ray.dir),
+    );
+
+    shape
+        .toi_and_normal_with_culled_ray(inner_m, &unscaled_ray, cull, solid)
+        .map(|mut res| {
+            let normal = scaled.unscale_vector(&res.normal);
+            let normal = na::try_normalize(&normal, na::zero()).unwrap_or(normal);
+            res.normal = m.rotate_vector(&normal);
+            res
+        })
+}
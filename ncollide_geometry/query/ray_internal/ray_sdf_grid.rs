@@ -0,0 +1,122 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal;
+use shape::SdfGrid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for SdfGrid<P> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        sdf_grid_toi_and_normal_with_ray(self, &ls_ray, solid).map(|mut res| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/// Computes the time of impact and normal of a ray on a signed distance field grid expressed in
+/// its local frame.
+///
+/// The zero level set of the distance field is found by sphere tracing: the ray is advanced by
+/// the distance to the surface until this distance becomes negligible. If a step crosses the
+/// surface, the crossing is refined by bisection. The surface is assumed to lie inside of the grid
+/// domain. The ray is considered to miss the surface if it is not reached after 1000 steps, e.g.,
+/// if the ray grazes it.
+pub fn sdf_grid_toi_and_normal_with_ray<P: Point>(
+    sdf: &SdfGrid<P>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    let eps = P::Real::default_epsilon().sqrt();
+    let tolerance = sdf.cell_size() * eps;
+    let dir_norm = na::norm(&ray.dir);
+
+    let (origin_distance, origin_gradient) = sdf.distance_and_gradient_at(&ray.origin);
+    let inside = origin_distance <= na::zero();
+
+    if inside && solid {
+        let normal = na::try_normalize(&origin_gradient, eps).unwrap_or(na::zero());
+        return Some(RayIntersection::new(na::zero(), -normal));
+    }
+
+    if dir_norm == na::zero() {
+        return None;
+    }
+
+    let (tmin, tmax) = match ray_internal::clip_ray_with_aabb(sdf.local_aabb(), ray) {
+        Some(clip) => clip,
+        None => return None,
+    };
+
+    // The distance is negated when starting from inside so that the traced distance is always
+    // positive before the surface.
+    let sign: P::Real = if inside { -na::one::<P::Real>() } else { na::one() };
+    let mut prev_t = tmin;
+    let mut t = tmin;
+    let mut converged = false;
+
+    for _ in 0..1000 {
+        let distance = sdf.distance_at(&(ray.origin + ray.dir * t)) * sign;
+
+        if distance < -tolerance {
+            // We stepped over the surface.
+            t = bisect_surface(sdf, ray, sign, prev_t, t);
+            converged = true;
+            break;
+        }
+
+        if distance <= tolerance {
+            converged = true;
+            break;
+        }
+
+        prev_t = t;
+        t += distance / dir_norm;
+
+        if t > tmax {
+            return None;
+        }
+    }
+
+    if !converged {
+        return None;
+    }
+
+    let (_, gradient) = sdf.distance_and_gradient_at(&(ray.origin + ray.dir * t));
+    let normal = na::try_normalize(&gradient, eps).unwrap_or(na::zero());
+
+    Some(RayIntersection::new(t, if inside { -normal } else { normal }))
+}
+
+// Finds the surface crossing between `t1` (in front of the surface) and `t2` (behind it).
+fn bisect_surface<P: Point>(
+    sdf: &SdfGrid<P>,
+    ray: &Ray<P>,
+    sign: P::Real,
+    mut t1: P::Real,
+    mut t2: P::Real,
+) -> P::Real {
+    let _0_5: P::Real = na::convert(0.5f64);
+
+    for _ in 0..50 {
+        let mid = (t1 + t2) * _0_5;
+
+        if sdf.distance_at(&(ray.origin + ray.dir * mid)) * sign > na::zero() {
+            t1 = mid;
+        } else {
+            t2 = mid;
+        }
+    }
+
+    (t1 + t2) * _0_5
+}
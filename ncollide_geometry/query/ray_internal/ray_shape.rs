@@ -1,6 +1,6 @@
 use math::{Isometry, Point};
 use shape::Shape;
-use query::{Ray, RayCast, RayIntersection};
+use query::{CullMode, Ray, RayCast, RayIntersection};
 
 impl<P: Point, M: Isometry<P>> RayCast<P, M> for Shape<P, M> {
     #[inline]
@@ -40,4 +40,63 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Shape<P, M> {
             .expect("No RayCast implementation for the underlying shape.")
             .intersects_ray(m, ray)
     }
+
+    #[inline]
+    fn toi_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<P::Real> {
+        self.as_ray_cast()
+            .expect("No RayCast implementation for the underlying shape.")
+            .toi_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.as_ray_cast()
+            .expect("No RayCast implementation for the underlying shape.")
+            .toi_and_normal_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_bounded_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.as_ray_cast()
+            .expect("No RayCast implementation for the underlying shape.")
+            .toi_and_normal_and_uv_with_bounded_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn intersects_bounded_ray(&self, m: &M, ray: &Ray<P>, max_toi: P::Real) -> bool {
+        self.as_ray_cast()
+            .expect("No RayCast implementation for the underlying shape.")
+            .intersects_bounded_ray(m, ray, max_toi)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.as_ray_cast()
+            .expect("No RayCast implementation for the underlying shape.")
+            .toi_and_normal_with_culled_ray(m, ray, cull, solid)
+    }
 }
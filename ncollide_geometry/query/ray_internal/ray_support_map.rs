@@ -1,12 +1,12 @@
 use num::Zero;
 
-use alga::general::Id;
+use alga::general::{Id, Real};
 use na;
 
 use query::algorithms::gjk;
 use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use query::{Ray, RayCast, RayIntersection};
-use shape::{Capsule, Cone, ConvexHull, Cylinder, MinkowskiSum, Segment, SupportMap};
+use shape::{Capsule, Cone, ConvexHull, Cylinder, FeatureId, MinkowskiSum, Segment, SupportMap};
 use math::{Isometry, Point};
 
 /// Cast a ray on a shape using the GJK algorithm.
@@ -265,17 +265,14 @@ where
         // XXX: optimize if na::dimension::<P>() == 2
         let ls_ray = ray.inverse_transform_by(m);
 
-        if na::dimension::<P::Vector>() == 2 {
+        let res = if na::dimension::<P::Vector>() == 2 {
             implicit_toi_and_normal_with_ray(
                 &Id::new(),
                 self,
                 &mut VoronoiSimplex2::<P>::new(),
                 &ls_ray,
                 solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+            )
         } else if na::dimension::<P::Vector>() == 3 {
             implicit_toi_and_normal_with_ray(
                 &Id::new(),
@@ -283,10 +280,7 @@ where
                 &mut VoronoiSimplex3::<P>::new(),
                 &ls_ray,
                 solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+            )
         } else {
             implicit_toi_and_normal_with_ray(
                 &Id::new(),
@@ -294,11 +288,37 @@ where
                 &mut JohnsonSimplex::<P>::new_w_tls(),
                 &ls_ray,
                 solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+            )
+        };
+
+        res.map(|mut res| {
+            let pt = ls_ray.origin + ls_ray.dir * res.toi;
+
+            res.feature = segment_feature(self, &pt, &res.normal);
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+// The feature of a segment containing the point `pt`, hit on the side `normal` points to.
+fn segment_feature<P: Point>(segment: &Segment<P>, pt: &P, normal: &P::Vector) -> FeatureId {
+    let eps = gjk::eps_tol::<P::Real>().sqrt();
+
+    if na::distance(segment.a(), pt) <= eps {
+        FeatureId::Vertex(0)
+    } else if na::distance(segment.b(), pt) <= eps {
+        FeatureId::Vertex(1)
+    } else if na::dimension::<P::Vector>() == 2 {
+        let ab = *segment.b() - *segment.a();
+
+        if ab[1] * normal[0] - ab[0] * normal[1] >= na::zero() {
+            FeatureId::Face(0)
+        } else {
+            FeatureId::Face(1)
         }
+    } else {
+        FeatureId::Edge(0)
     }
 }
 
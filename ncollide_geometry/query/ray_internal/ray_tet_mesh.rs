@@ -0,0 +1,104 @@
+use std::cmp::Ordering;
+use num::Zero;
+
+use approx::ApproxEq;
+use alga::general::Real;
+
+use query::{ray_internal, Ray, RayCast, RayInterferencesCollector, RayIntersection};
+use shape::TetMesh;
+use math::{Isometry, Point, Vector};
+
+/// The intersection of a ray with the volume of a tetrahedral mesh.
+pub struct TetMeshRayIntersection<V: Vector> {
+    /// The point where the ray enters the mesh.
+    ///
+    /// Its time of impact is zero if the ray origin is inside of the mesh.
+    pub entry: RayIntersection<V>,
+    /// The index of the tetrahedron the ray enters the mesh through.
+    pub entry_tetrahedron: usize,
+    /// The point where the ray leaves the mesh.
+    pub exit: RayIntersection<V>,
+    /// The index of the tetrahedron the ray leaves the mesh through.
+    pub exit_tetrahedron: usize,
+}
+
+impl<P: Point> TetMesh<P> {
+    /// Computes the points where a ray enters and leaves the volume of this mesh.
+    ///
+    /// The exit point is the first point after the entry point where the ray leaves the set of
+    /// contiguous tetrahedra it traverses. The normals of both intersections are the outward
+    /// normals of the faces crossed by the ray. Returns `None` if the ray does not hit this mesh.
+    pub fn entry_and_exit_with_ray<M: Isometry<P>>(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+    ) -> Option<TetMeshRayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let mut candidates = Vec::new();
+
+        {
+            let mut visitor = RayInterferencesCollector::new(&ls_ray, &mut candidates);
+            self.bvt().visit(&mut visitor);
+        }
+
+        let mut intervals: Vec<_> = candidates
+            .into_iter()
+            .filter_map(|i| {
+                ray_internal::clip_ray_with_tetrahedron(&self.tetrahedron_at(i), &ls_ray)
+                    .map(|(entry, exit)| (i, entry, exit))
+            })
+            .collect();
+
+        intervals.sort_by(|a, b| {
+            a.1
+                .toi
+                .partial_cmp(&b.1.toi)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let mut intervals = intervals.into_iter();
+        let (entry_tetrahedron, entry, mut exit) = match intervals.next() {
+            Some(first) => first,
+            None => return None,
+        };
+        let mut exit_tetrahedron = entry_tetrahedron;
+        let eps = P::Real::default_epsilon().sqrt();
+
+        for (i, next_entry, next_exit) in intervals {
+            if next_entry.toi > exit.toi + eps {
+                break;
+            }
+
+            if next_exit.toi > exit.toi {
+                exit = next_exit;
+                exit_tetrahedron = i;
+            }
+        }
+
+        Some(TetMeshRayIntersection {
+            entry: RayIntersection::new(entry.toi, m.rotate_vector(&entry.normal)),
+            entry_tetrahedron: entry_tetrahedron,
+            exit: RayIntersection::new(exit.toi, m.rotate_vector(&exit.normal)),
+            exit_tetrahedron: exit_tetrahedron,
+        })
+    }
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for TetMesh<P> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.entry_and_exit_with_ray(m, ray).map(|inter| {
+            if solid || !inter.entry.toi.is_zero() {
+                inter.entry
+            } else {
+                // The ray origin is inside of the mesh.
+                RayIntersection::new(inter.exit.toi, -inter.exit.normal)
+            }
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use num::{Bounded, Zero};
+
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+
+use query::{Ray, RayCast, RayIntersection};
+use shape::Tetrahedron;
+use utils;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Tetrahedron<P> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        clip_ray_with_tetrahedron(self, &ls_ray).map(|(entry, exit)| {
+            if solid || !entry.toi.is_zero() {
+                RayIntersection::new(entry.toi, m.rotate_vector(&entry.normal))
+            } else {
+                // The ray origin is inside of the tetrahedron.
+                RayIntersection::new(exit.toi, -m.rotate_vector(&exit.normal))
+            }
+        })
+    }
+}
+
+/// Computes the entry and exit points of a ray on a tetrahedron.
+///
+/// Returns `None` if the ray does not intersect the tetrahedron or if the tetrahedron is
+/// degenerate. Otherwise, returns the entry and exit intersections with the outward normals of
+/// the faces crossed by the ray. The entry time of impact is zero if the ray origin is inside of
+/// the tetrahedron. This is only supported in 3D.
+pub fn clip_ray_with_tetrahedron<P: Point>(
+    tetrahedron: &Tetrahedron<P>,
+    ray: &Ray<P>,
+) -> Option<(RayIntersection<P::Vector>, RayIntersection<P::Vector>)> {
+    let vertices = [
+        tetrahedron.a(),
+        tetrahedron.b(),
+        tetrahedron.c(),
+        tetrahedron.d(),
+    ];
+    let _0: P::Real = na::zero();
+    let mut tmin = -P::Real::max_value();
+    let mut tmax = P::Real::max_value();
+    let mut nmin = na::zero::<P::Vector>();
+    let mut nmax = na::zero::<P::Vector>();
+
+    for i in 0..4 {
+        let face = tetrahedron.face(i);
+        // The face `i` is opposite to the vertex `3 - i`.
+        let opposite = *vertices[3 - i] - *face.a();
+        let mut normal = utils::cross3(&(*face.b() - *face.a()), &(*face.c() - *face.a()));
+
+        if na::dot(&normal, &opposite) > _0 {
+            normal = -normal;
+        }
+
+        let normal = match na::try_normalize(&normal, P::Real::default_epsilon()) {
+            Some(normal) => normal,
+            None => return None,
+        };
+        let dist = na::dot(&normal, &(*face.a() - ray.origin));
+        let dot = na::dot(&normal, &ray.dir);
+
+        if dot.is_zero() {
+            if dist < _0 {
+                return None;
+            }
+        } else {
+            let t = dist / dot;
+
+            if dot < _0 {
+                if t > tmin {
+                    tmin = t;
+                    nmin = normal;
+                }
+            } else if t < tmax {
+                tmax = t;
+                nmax = normal;
+            }
+        }
+    }
+
+    if tmax < _0 || tmin > tmax {
+        return None;
+    }
+
+    Some((
+        RayIntersection::new(tmin.max(_0), nmin),
+        RayIntersection::new(tmax, nmax),
+    ))
+}
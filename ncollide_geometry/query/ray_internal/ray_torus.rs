@@ -0,0 +1,106 @@
+use approx::ApproxEq;
+use na;
+
+use utils;
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal;
+use shape::Torus;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Torus<P::Real> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        torus_toi_and_normal_with_ray(self, &ls_ray, solid).map(|mut res| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/// Computes the time of impact and normal of a ray on a torus expressed in its local frame.
+///
+/// The intersection is the smallest positive root of the quartic equation obtained by
+/// substituting the ray parametrization into the implicit equation of the torus.
+pub fn torus_toi_and_normal_with_ray<P: Point>(
+    torus: &Torus<P::Real>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    let major = torus.major_radius();
+    let minor = torus.minor_radius();
+
+    let dir_norm = na::norm(&ray.dir);
+
+    if dir_norm <= P::Real::default_epsilon() {
+        return None;
+    }
+
+    let dir = ray.dir / dir_norm;
+
+    // Start from the bounding sphere boundary to keep the polynomial well-conditioned.
+    let (_, tmin) = ray_internal::ball_toi_with_ray(
+        &P::origin(),
+        major + minor,
+        &Ray::new(ray.origin, dir),
+        true,
+    );
+    let tmin = match tmin {
+        Some(tmin) => tmin,
+        None => return None,
+    };
+    let tmax = (major + minor) * na::convert(2.0f64);
+
+    let orig = (ray.origin + dir * tmin).coordinates();
+    let _2: P::Real = na::convert(2.0f64);
+    let _4: P::Real = na::convert(4.0f64);
+    let sq_major = major * major;
+    let oo = na::dot(&orig, &orig);
+    let od = na::dot(&orig, &dir);
+    let gamma = oo + sq_major - minor * minor;
+
+    let coeffs = [
+        gamma * gamma - _4 * sq_major * (oo - orig[1] * orig[1]),
+        _4 * od * gamma - _4 * sq_major * (_2 * od - _2 * orig[1] * dir[1]),
+        _4 * od * od + _2 * gamma - _4 * sq_major * (na::one::<P::Real>() - dir[1] * dir[1]),
+        _4 * od,
+        na::one(),
+    ];
+
+    // The implicit function is negative inside of the torus. The ray origin can only be inside
+    // if it is already inside of the bounding sphere.
+    let inside = tmin == na::zero() && coeffs[0] < na::zero();
+
+    let t = if inside && solid {
+        na::zero()
+    } else {
+        let mut roots = [na::zero(); 4];
+        let nroots = utils::real_polynomial_roots(&coeffs, na::zero(), tmax, &mut roots);
+
+        match roots[..nroots]
+            .iter()
+            .find(|t| !inside || **t > na::zero())
+        {
+            Some(t) => *t,
+            None => return None,
+        }
+    };
+
+    let pt = P::from_coordinates(orig + dir * t);
+    let core = torus.core_circle_point(&pt);
+    let normal = match na::try_normalize(&(pt - core), P::Real::default_epsilon()) {
+        Some(n) => n,
+        None => na::zero(),
+    };
+
+    Some(RayIntersection::new(
+        (tmin + t) / dir_norm,
+        if inside { -normal } else { normal },
+    ))
+}
@@ -1,12 +1,13 @@
 use num::Zero;
+use approx::ApproxEq;
 
 use alga::general::{Id, Real};
 use na::{self, Vector3};
 
 use query::algorithms::JohnsonSimplex;
-use query::{Ray, RayCast, RayIntersection};
+use query::{CullMode, Ray, RayCast, RayIntersection};
 use query::ray_internal;
-use shape::Triangle;
+use shape::{FeatureId, Triangle};
 use math::{Isometry, Point};
 
 use utils;
@@ -38,12 +39,37 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Triangle<P> {
             r
         })
     }
+
+    #[inline]
+    fn toi_and_normal_with_culled_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        cull: CullMode,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        if na::dimension::<P::Vector>() == 3 {
+            let normal = utils::cross3(&(*self.b() - *self.a()), &(*self.c() - *self.a()));
+
+            if cull.culls(&normal, &m.inverse_transform_vector(&ray.dir)) {
+                return None;
+            }
+        }
+
+        self.toi_and_normal_with_ray(m, ray, solid)
+    }
 }
 
 /// Computes the intersection between a triangle and a ray.
 ///
 /// If an intersection is found, the time of impact, the normal and the barycentric coordinates of
 /// the intersection point are returned.
+///
+/// The feature of the intersection is a vertex (indexed as `a`, `b`, `c`) or an edge (indexed as
+/// `ab`, `bc`, `ca`) if the ray passes through it, up to a small epsilon on the barycentric
+/// coordinates. Rays passing within this epsilon outside of the triangle hit the corresponding
+/// edge or vertex. Otherwise, it is the face `0` if the ray hits the side the normal `ab x ac`
+/// points to, and the face `1` if it hits the other side.
 pub fn triangle_ray_intersection<P: Point>(
     a: &P,
     b: &P,
@@ -72,6 +98,10 @@ pub fn triangle_ray_intersection<P: Point>(
 
     let d = d.abs();
 
+    // Rounding errors must not let rays passing through an edge slip through the triangle.
+    let eps = P::Real::default_epsilon().sqrt();
+    let tol = d * eps;
+
     //
     // intersection: compute barycentric coordinates
     //
@@ -81,47 +111,60 @@ pub fn triangle_ray_intersection<P: Point>(
     let mut w;
     let toi;
     let normal;
+    let face;
 
     if t < na::zero() {
         v = -na::dot(&ac, &e);
 
-        if v < na::zero() || v > d {
+        if v < -tol || v > d + tol {
             return None;
         }
 
         w = na::dot(&ab, &e);
 
-        if w < na::zero() || v + w > d {
+        if w < -tol || v + w > d + tol {
             return None;
         }
 
         let invd = na::one::<P::Real>() / d;
         toi = -t * invd;
         normal = -na::normalize(&n);
+        face = 1;
         v = v * invd;
         w = w * invd;
     } else {
         v = na::dot(&ac, &e);
 
-        if v < na::zero() || v > d {
+        if v < -tol || v > d + tol {
             return None;
         }
 
         w = -na::dot(&ab, &e);
 
-        if w < na::zero() || v + w > d {
+        if w < -tol || v + w > d + tol {
             return None;
         }
 
         let invd = na::one::<P::Real>() / d;
         toi = t * invd;
         normal = na::normalize(&n);
+        face = 0;
         v = v * invd;
         w = w * invd;
     }
 
-    Some((
-        RayIntersection::new(toi, normal),
-        Vector3::new(-v - w + na::one(), v, w),
-    ))
+    let u = -v - w + na::one();
+    let mut inter = RayIntersection::new(toi, normal);
+
+    inter.feature = match (u.abs() <= eps, v <= eps, w <= eps) {
+        (false, true, true) => FeatureId::Vertex(0),
+        (true, false, true) => FeatureId::Vertex(1),
+        (true, true, false) => FeatureId::Vertex(2),
+        (false, false, true) => FeatureId::Edge(0),
+        (true, false, false) => FeatureId::Edge(1),
+        (false, true, false) => FeatureId::Edge(2),
+        _ => FeatureId::Face(face),
+    };
+
+    Some((inter, Vector3::new(u, v, w)))
 }
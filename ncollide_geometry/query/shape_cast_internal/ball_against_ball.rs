@@ -0,0 +1,55 @@
+use num::Zero;
+
+use alga::linear::FiniteDimVectorSpace;
+use na::{self, Unit};
+use math::Point;
+use shape::Ball;
+use query::{ray_internal, Ray, TOIStatus, TOI};
+
+/// Time of impact of two balls under translational movement, with the witness points and normal at
+/// the impact.
+#[inline]
+pub fn ball_against_ball<P>(
+    center1: &P,
+    vel1: &P::Vector,
+    b1: &Ball<P::Real>,
+    center2: &P,
+    vel2: &P::Vector,
+    b2: &Ball<P::Real>,
+) -> Option<TOI<P>>
+where
+    P: Point,
+{
+    let vel = *vel1 - *vel2;
+    let radius = b1.radius() + b2.radius();
+    let center = *center1 + (-center2.coordinates());
+    let ray = Ray::new(P::origin(), -vel);
+    let toi = match ray_internal::ball_toi_with_ray(&center, radius, &ray, true).1 {
+        Some(toi) => toi,
+        None => return None,
+    };
+
+    let status = if na::norm_squared(&center.coordinates()) < radius * radius {
+        TOIStatus::Penetrating
+    } else {
+        TOIStatus::Converged
+    };
+
+    let center1 = *center1 + *vel1 * toi;
+    let center2 = *center2 + *vel2 * toi;
+    let delta_pos = center2 - center1;
+
+    let normal = if delta_pos.is_zero() {
+        Unit::new_unchecked(P::Vector::canonical_basis_element(0))
+    } else {
+        Unit::new_normalize(delta_pos)
+    };
+
+    Some(TOI::new(
+        toi,
+        center1 + *normal * b1.radius(),
+        center2 + (-*normal * b2.radius()),
+        normal,
+        status,
+    ))
+}
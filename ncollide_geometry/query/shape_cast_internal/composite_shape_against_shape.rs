@@ -0,0 +1,162 @@
+use alga::general::Id;
+use na;
+use math::{Isometry, Point};
+use bounding_volume::AABB;
+use partitioning::BVTCostFn;
+use shape::{composite_shape, CompositeShape, Shape};
+use query::{shape_cast_internal, QueryDispatcher, Ray, RayCast, Unsupported, TOI};
+
+/// Time of impact of a composite shape with any other shape under translational movement, with the
+/// witness points and normal at the impact.
+pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<TOI<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn =
+        CompositeShapeAgainstAnyShapeCastCostFn::new(dispatcher, m1, vel1, g1, m2, vel2, g2);
+
+    let res = composite_shape::best_first_search(g1, &mut cost_fn).map(|(_, res)| res);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res),
+    }
+}
+
+/// Time of impact of any shape with a composite shape under translational movement, with the
+/// witness points and normal at the impact.
+pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+) -> Result<Option<TOI<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    match composite_shape_against_shape(dispatcher, m2, vel2, g2, m1, vel1, g1) {
+        Ok(res) => Ok(res.map(|mut toi| {
+            toi.flip();
+            toi
+        })),
+        Err(mut err) => {
+            err.flip();
+            Err(err)
+        }
+    }
+}
+
+struct CompositeShapeAgainstAnyShapeCastCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
+    msum_shift: P::Vector,
+    msum_margin: P::Vector,
+    ray: Ray<P>,
+
+    dispatcher: &'a QueryDispatcher<P, M>,
+    m1: &'a M,
+    vel1: &'a P::Vector,
+    g1: &'a G1,
+    m2: &'a M,
+    vel2: &'a P::Vector,
+    g2: &'a Shape<P, M>,
+
+    // The first pair of parts not supported by the dispatcher.
+    error: Option<Unsupported>,
+}
+
+impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnyShapeCastCostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    pub fn new(
+        dispatcher: &'a QueryDispatcher<P, M>,
+        m1: &'a M,
+        vel1: &'a P::Vector,
+        g1: &'a G1,
+        m2: &'a M,
+        vel2: &'a P::Vector,
+        g2: &'a Shape<P, M>,
+    ) -> CompositeShapeAgainstAnyShapeCastCostFn<'a, P, M, G1> {
+        let ls_m2 = na::inverse(m1) * m2.clone();
+        let ls_aabb2 = g2.aabb(&ls_m2);
+
+        CompositeShapeAgainstAnyShapeCastCostFn {
+            msum_shift: -ls_aabb2.center().coordinates(),
+            msum_margin: ls_aabb2.half_extents(),
+            ray: Ray::new(P::origin(), m1.inverse_rotate_vector(&(*vel2 - *vel1))),
+            dispatcher: dispatcher,
+            m1: m1,
+            vel1: vel1,
+            g1: g1,
+            m2: m2,
+            vel2: vel2,
+            g2: g2,
+            error: None,
+        }
+    }
+}
+
+impl<'a, P, M, G1: ?Sized> BVTCostFn<P::Real, usize, AABB<P>>
+    for CompositeShapeAgainstAnyShapeCastCostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    type UserData = TOI<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // No need to continue if some parts are not supported.
+        if self.error.is_some() {
+            return None;
+        }
+
+        // Compute the minkowski sum of the two AABBs.
+        let msum = AABB::new(
+            *bv.mins() + self.msum_shift + (-self.msum_margin),
+            *bv.maxs() + self.msum_shift + self.msum_margin,
+        );
+
+        // Compute the TOI.
+        msum.toi_with_ray(&Id::new(), &self.ray, true)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, TOI<P>)> {
+        let mut res = None;
+
+        self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
+            match shape_cast_internal::shape_cast_with_dispatcher(
+                self.dispatcher,
+                m1,
+                self.vel1,
+                g1,
+                self.m2,
+                self.vel2,
+                self.g2,
+            ) {
+                Ok(toi) => res = toi.map(|toi| (toi.toi, toi)),
+                Err(err) => self.error = Some(err),
+            }
+        });
+
+        res
+    }
+}
@@ -0,0 +1,77 @@
+use alga::linear::Translation;
+use na::{self, Unit};
+use shape::{Plane, SupportMap};
+use query::{TOIStatus, TOI};
+use math::{Isometry, Point};
+
+/// Time of impact of a plane with a support-mapped shape under translational movement, with the
+/// witness points and normal at the impact.
+pub fn plane_against_support_map<P, M, G: ?Sized>(
+    mplane: &M,
+    vel_plane: &P::Vector,
+    plane: &Plane<P::Vector>,
+    mother: &M,
+    vel_other: &P::Vector,
+    other: &G,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let vel = *vel_other - *vel_plane;
+    let plane_normal = Unit::new_unchecked(mplane.rotate_vector(plane.normal()));
+    let closest_point = other.support_point(mother, &-*plane_normal);
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let dist = na::dot(&*plane_normal, &(closest_point - plane_center));
+
+    if dist <= na::zero() {
+        let witness1 = closest_point + *plane_normal * -dist;
+
+        return Some(TOI::new(
+            na::zero(),
+            witness1,
+            closest_point,
+            plane_normal,
+            TOIStatus::Penetrating,
+        ));
+    }
+
+    let approach_speed = -na::dot(&*plane_normal, &vel);
+
+    if approach_speed <= na::zero() {
+        return None;
+    }
+
+    let toi = dist / approach_speed;
+    let witness = closest_point + *vel_other * toi;
+
+    Some(TOI::new(
+        toi,
+        witness,
+        witness,
+        plane_normal,
+        TOIStatus::Converged,
+    ))
+}
+
+/// Time of impact of a support-mapped shape with a plane under translational movement, with the
+/// witness points and normal at the impact.
+pub fn support_map_against_plane<P, M, G: ?Sized>(
+    mother: &M,
+    vel_other: &P::Vector,
+    other: &G,
+    mplane: &M,
+    vel_plane: &P::Vector,
+    plane: &Plane<P::Vector>,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    plane_against_support_map(mplane, vel_plane, plane, mother, vel_other, other).map(|mut toi| {
+        toi.flip();
+        toi
+    })
+}
@@ -0,0 +1,133 @@
+use alga::linear::Translation;
+use math::{Isometry, Point};
+use shape::{Ball, Plane, Shape};
+use query::shape_cast_internal;
+use query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported, TOI};
+
+/// Computes the smallest time of impact of two shapes under translational movement, with the
+/// witness points and normal at the impact.
+///
+/// The returned time is `0.0` with the `TOIStatus::Penetrating` status if the objects are
+/// penetrating.
+///
+/// Panics if the pair of shapes is not supported.
+pub fn shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match try_shape_against_shape(m1, vel1, g1, m2, vel2, g2) {
+        Ok(res) => res,
+        Err(err) => panic!(
+            "No algorithm known to cast the given pair of shapes: {}.",
+            err
+        ),
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, with the
+/// witness points and normal at the impact, or returns an `Unsupported` error if the pair of shapes
+/// is not supported.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<TOI<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    shape_against_shape_with_dispatcher(&DefaultQueryDispatcher, m1, vel1, g1, m2, vel2, g2)
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, with the
+/// witness points and normal at the impact, using `dispatcher` to select the algorithm.
+///
+/// Composite shapes not handled by `dispatcher` are decomposed and each of their parts is
+/// dispatched through `dispatcher` as well. Returns an `Unsupported` error if the pair of shapes,
+/// or any pair made of their parts, is not supported.
+pub fn shape_against_shape_with_dispatcher<P, M>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<TOI<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = dispatcher.shape_cast(m1, vel1, g1, m2, vel2, g2);
+
+    if res.is_ok() {
+        res
+    } else if let Some(c1) = g1.as_composite_shape() {
+        shape_cast_internal::composite_shape_against_shape(
+            dispatcher,
+            m1,
+            vel1,
+            c1,
+            m2,
+            vel2,
+            g2,
+        )
+    } else if let Some(c2) = g2.as_composite_shape() {
+        shape_cast_internal::shape_against_composite_shape(
+            dispatcher,
+            m1,
+            vel1,
+            g1,
+            m2,
+            vel2,
+            c2,
+        )
+    } else {
+        res
+    }
+}
+
+// The shape cast of two non-composite shapes, as computed by the `DefaultQueryDispatcher`.
+pub(crate) fn default_shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<TOI<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = if let (Some(b1), Some(b2)) = (
+        g1.as_shape::<Ball<P::Real>>(),
+        g2.as_shape::<Ball<P::Real>>(),
+    ) {
+        let p1 = P::from_coordinates(m1.translation().to_vector());
+        let p2 = P::from_coordinates(m2.translation().to_vector());
+
+        shape_cast_internal::ball_against_ball(&p1, vel1, b1, &p2, vel2, b2)
+    } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
+        shape_cast_internal::plane_against_support_map(m1, vel1, p1, m2, vel2, s2)
+    } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
+        shape_cast_internal::support_map_against_plane(m1, vel1, s1, m2, vel2, p2)
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        shape_cast_internal::support_map_against_support_map(m1, vel1, s1, m2, vel2, s2)
+    } else {
+        return Err(Unsupported::new(g1, g2));
+    };
+
+    Ok(res)
+}
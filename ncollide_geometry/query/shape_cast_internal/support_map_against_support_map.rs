@@ -0,0 +1,164 @@
+use num::Bounded;
+
+use alga::general::Real;
+use alga::linear::Translation;
+use na::{self, Unit};
+use query::algorithms::gjk;
+use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::{closest_points_internal, contacts_internal, ClosestPoints, TOIStatus, TOI};
+use shape::{AnnotatedPoint, SupportMap};
+use math::{Isometry, Point};
+
+/// Time of impact of two support-mapped shapes under translational movement, with the witness
+/// points and normal at the impact.
+pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_against_support_map_with_params(
+            m1,
+            vel1,
+            g1,
+            m2,
+            vel2,
+            g2,
+            &mut VoronoiSimplex2::new(),
+        )
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_against_support_map_with_params(
+            m1,
+            vel1,
+            g1,
+            m2,
+            vel2,
+            g2,
+            &mut VoronoiSimplex3::new(),
+        )
+    } else {
+        support_map_against_support_map_with_params(
+            m1,
+            vel1,
+            g1,
+            m2,
+            vel2,
+            g2,
+            &mut JohnsonSimplex::new_w_tls(),
+        )
+    }
+}
+
+/// Time of impact of two support-mapped shapes under translational movement, with the witness
+/// points and normal at the impact.
+///
+/// The shapes are advanced conservatively: at each step, the closest points given by the GJK
+/// algorithm tell how far the shapes can move along their motion without colliding. If the
+/// algorithm does not converge after 100 steps, the result of the last step is returned with the
+/// `TOIStatus::Failed` status.
+pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+    simplex: &mut S,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    // The second shape is moved relatively to the first one.
+    let vel = *vel2 - *vel1;
+    let tolerance = gjk::eps_tol::<P::Real>().sqrt();
+    let mut toi: P::Real = na::zero();
+    let mut init_dir = None;
+    let mut last = None;
+
+    for _ in 0..100 {
+        let shift = M::Translation::from_vector(vel * toi).unwrap();
+        let curr_m2 = m2.append_translation(&shift);
+        let world_shift = *vel1 * toi;
+        let (res, sep) = closest_points_internal::support_map_against_support_map_with_params(
+            m1,
+            g1,
+            &curr_m2,
+            g2,
+            P::Real::max_value(),
+            simplex,
+            init_dir,
+        );
+
+        match res {
+            ClosestPoints::WithinMargin(p1, p2) => {
+                let (normal, dist) = Unit::new_and_get(p2 - p1);
+                let curr = TOI::new(
+                    toi,
+                    p1 + world_shift,
+                    p2 + world_shift,
+                    normal,
+                    TOIStatus::Converged,
+                );
+
+                if dist <= tolerance {
+                    return Some(curr);
+                }
+
+                let approach_speed = -na::dot(&*normal, &vel);
+
+                if approach_speed <= na::zero() {
+                    // The shapes are separated by a plane they are moving away from.
+                    return None;
+                }
+
+                toi += dist / approach_speed;
+                init_dir = Some(-sep);
+                last = Some(curr);
+            }
+            ClosestPoints::Intersecting => {
+                if let Some(last) = last {
+                    // The shapes touch after the last step: its witness points are moved to the
+                    // time of impact.
+                    let dt = toi - last.toi;
+
+                    return Some(TOI::new(
+                        toi,
+                        last.witness1 + *vel1 * dt,
+                        last.witness2 + *vel2 * dt,
+                        last.normal,
+                        TOIStatus::Converged,
+                    ));
+                }
+
+                return contacts_internal::support_map_against_support_map(
+                    m1,
+                    g1,
+                    m2,
+                    g2,
+                    tolerance,
+                ).map(|c| {
+                    TOI::new(toi, c.world1, c.world2, c.normal, TOIStatus::Penetrating)
+                });
+            }
+            ClosestPoints::Disjoint => return None,
+        }
+    }
+
+    last.map(|mut last| {
+        last.status = TOIStatus::Failed;
+        last
+    })
+}
@@ -0,0 +1,65 @@
+use std::mem;
+
+use na::Unit;
+use math::Point;
+
+/// The state of the algorithm that computed a time of impact.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TOIStatus {
+    /// The shapes touch at the time of impact.
+    Converged,
+    /// The shapes were already penetrating at the start of their motion. The time of impact is
+    /// zero and the witness points are those of the deepest contact.
+    Penetrating,
+    /// The iterative algorithm did not converge. The time of impact is conservative and the
+    /// witness points are those of the last iteration.
+    Failed,
+}
+
+/// The time of impact of two shapes, with the witness points and the normal at the impact.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TOI<P: Point> {
+    /// The time at which the shapes touch.
+    pub toi: P::Real,
+
+    /// Witness point on the first shape at the time of impact. The position is expressed in world
+    /// space.
+    pub witness1: P,
+
+    /// Witness point on the second shape at the time of impact. The position is expressed in world
+    /// space.
+    pub witness2: P,
+
+    /// Contact normal at the time of impact, pointing from the first shape toward the second one.
+    pub normal: Unit<P::Vector>,
+
+    /// The state of the algorithm that computed this time of impact.
+    pub status: TOIStatus,
+}
+
+impl<P: Point> TOI<P> {
+    /// Creates a new time of impact.
+    #[inline]
+    pub fn new(
+        toi: P::Real,
+        witness1: P,
+        witness2: P,
+        normal: Unit<P::Vector>,
+        status: TOIStatus,
+    ) -> TOI<P> {
+        TOI {
+            toi: toi,
+            witness1: witness1,
+            witness2: witness2,
+            normal: normal,
+            status: status,
+        }
+    }
+
+    /// Reverts the normal and swaps `witness1` and `witness2`.
+    #[inline]
+    pub fn flip(&mut self) {
+        mem::swap(&mut self.witness1, &mut self.witness2);
+        self.normal = -self.normal;
+    }
+}
@@ -0,0 +1,157 @@
+use std::marker::PhantomData;
+
+use alga::general::Id;
+use na;
+
+use bounding_volume::AABB;
+use partitioning::BVTCostFn;
+use shape::{composite_shape, CompositeShape, Shape};
+use query::{PointQuery, QueryDispatcher, SignedDistance, Unsupported};
+use query::signed_distance_internal;
+use math::{Isometry, Point};
+
+/// Signed distance between a composite shape (`Mesh`, `Compound`) and any other shape.
+///
+/// This is the smallest signed distance between the parts of the composite shape and the other
+/// shape, i.e., the deepest penetration if some parts are penetrating.
+pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Result<Option<SignedDistance<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn = CompositeShapeAgainstAnySignedDistCostFn::new(dispatcher, m1, g1, m2, g2);
+    let res = composite_shape::best_first_search(g1, &mut cost_fn).map(|(_, res)| res);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res),
+    }
+}
+
+/// Signed distance between a shape and a composite (`Mesh`, `Compound`) shape.
+pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &G2,
+) -> Result<Option<SignedDistance<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    match composite_shape_against_shape(dispatcher, m2, g2, m1, g1) {
+        Ok(res) => Ok(res.map(|mut res| {
+            res.flip();
+            res
+        })),
+        Err(mut err) => {
+            err.flip();
+            Err(err)
+        }
+    }
+}
+
+struct CompositeShapeAgainstAnySignedDistCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
+    msum_shift: P::Vector,
+    msum_margin: P::Vector,
+
+    dispatcher: &'a QueryDispatcher<P, M>,
+    m1: &'a M,
+    g1: &'a G1,
+    m2: &'a M,
+    g2: &'a Shape<P, M>,
+
+    // The first pair of parts not supported by the dispatcher.
+    error: Option<Unsupported>,
+
+    point_type: PhantomData<P>,
+}
+
+impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnySignedDistCostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    pub fn new(
+        dispatcher: &'a QueryDispatcher<P, M>,
+        m1: &'a M,
+        g1: &'a G1,
+        m2: &'a M,
+        g2: &'a Shape<P, M>,
+    ) -> CompositeShapeAgainstAnySignedDistCostFn<'a, P, M, G1> {
+        let ls_m2 = na::inverse(m1) * m2.clone();
+        let ls_aabb2 = g2.aabb(&ls_m2);
+
+        CompositeShapeAgainstAnySignedDistCostFn {
+            msum_shift: -ls_aabb2.center().coordinates(),
+            msum_margin: ls_aabb2.half_extents(),
+            dispatcher: dispatcher,
+            m1: m1,
+            g1: g1,
+            m2: m2,
+            g2: g2,
+            error: None,
+            point_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, P, M, G1: ?Sized> BVTCostFn<P::Real, usize, AABB<P>>
+    for CompositeShapeAgainstAnySignedDistCostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    type UserData = SignedDistance<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // No need to continue if some parts are not supported.
+        if self.error.is_some() {
+            return None;
+        }
+
+        // Compute the minkowski sum of the two AABBs.
+        let msum = AABB::new(
+            *bv.mins() + self.msum_shift + (-self.msum_margin),
+            *bv.maxs() + self.msum_shift + self.msum_margin,
+        );
+
+        // The signed distance from the origin is the opposite of the penetration depth of the two
+        // AABBs if they intersect. It is a lower bound of the signed distance of the parts they
+        // contain.
+        Some(msum.distance_to_point(&Id::new(), &P::origin(), false))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, SignedDistance<P>)> {
+        let mut res = None;
+
+        self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
+            match signed_distance_internal::signed_distance_with_dispatcher(
+                self.dispatcher,
+                m1,
+                g1,
+                self.m2,
+                self.g2,
+            ) {
+                Ok(Some(dist)) => res = Some((dist.distance, dist)),
+                Ok(None) => {}
+                Err(err) => self.error = Some(err),
+            }
+        });
+
+        res
+    }
+}
@@ -0,0 +1,12 @@
+//! Implementation details of the `signed_distance` function.
+
+pub use self::signed_distance::SignedDistance;
+pub use self::shape_against_shape::shape_against_shape as signed_distance;
+pub use self::shape_against_shape::shape_against_shape_with_dispatcher as signed_distance_with_dispatcher;
+pub use self::shape_against_shape::try_shape_against_shape as try_signed_distance;
+pub use self::composite_shape_against_shape::{composite_shape_against_shape,
+                                              shape_against_composite_shape};
+
+mod signed_distance;
+mod shape_against_shape;
+mod composite_shape_against_shape;
@@ -0,0 +1,78 @@
+use num::Bounded;
+
+use math::{Isometry, Point};
+use shape::Shape;
+use query::signed_distance_internal;
+use query::{DefaultQueryDispatcher, QueryDispatcher, SignedDistance, Unsupported};
+
+/// Computes the signed distance between two shapes.
+///
+/// The distance is negative if the shapes are penetrating. Returns `None` if no witness points
+/// can be found, e.g., if one of the shapes is an empty composite shape.
+///
+/// Panics if the pair of shapes is not supported.
+pub fn shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Option<SignedDistance<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match try_shape_against_shape(m1, g1, m2, g2) {
+        Ok(res) => res,
+        Err(err) => panic!(
+            "No algorithm known to compute the signed distance between the given pair of shapes: \
+             {}.",
+            err
+        ),
+    }
+}
+
+/// Computes the signed distance between two shapes, or returns an `Unsupported` error if the pair
+/// of shapes is not supported.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Result<Option<SignedDistance<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    shape_against_shape_with_dispatcher(&DefaultQueryDispatcher, m1, g1, m2, g2)
+}
+
+/// Computes the signed distance between two shapes, using `dispatcher` to select the algorithm.
+///
+/// The signed distance is derived from the contact computed by `dispatcher` with an unbounded
+/// prediction, so that its opposite is the contact depth. Composite shapes not handled by
+/// `dispatcher` are decomposed and each of their parts is dispatched through `dispatcher` as
+/// well. Returns an `Unsupported` error if the pair of shapes, or any pair made of their parts, is
+/// not supported.
+pub fn shape_against_shape_with_dispatcher<P, M>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Result<Option<SignedDistance<P>>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = dispatcher.contact(m1, g1, m2, g2, P::Real::max_value());
+
+    if let Ok(contact) = res {
+        Ok(contact.map(SignedDistance::from))
+    } else if let Some(c1) = g1.as_composite_shape() {
+        signed_distance_internal::composite_shape_against_shape(dispatcher, m1, c1, m2, g2)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        signed_distance_internal::shape_against_composite_shape(dispatcher, m1, g1, m2, c2)
+    } else {
+        res.map(|contact| contact.map(SignedDistance::from))
+    }
+}
@@ -0,0 +1,61 @@
+use std::mem;
+
+use na::Unit;
+use math::Point;
+use query::Contact;
+
+/// The signed distance between two shapes, with its witness points.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SignedDistance<P: Point> {
+    /// The distance separating the two shapes, or the opposite of their penetration depth if they
+    /// are penetrating.
+    pub distance: P::Real,
+
+    /// Witness point on the first shape. The position is expressed in world space.
+    pub world1: P,
+
+    /// Witness point on the second shape. The position is expressed in world space.
+    pub world2: P,
+
+    /// Direction pointing from the first shape toward the second one.
+    ///
+    /// Translating the second shape by `normal * -distance` makes both shapes touch.
+    pub normal: Unit<P::Vector>,
+}
+
+impl<P: Point> SignedDistance<P> {
+    /// Creates a new signed distance.
+    #[inline]
+    pub fn new(
+        distance: P::Real,
+        world1: P,
+        world2: P,
+        normal: Unit<P::Vector>,
+    ) -> SignedDistance<P> {
+        SignedDistance {
+            distance: distance,
+            world1: world1,
+            world2: world2,
+            normal: normal,
+        }
+    }
+
+    /// Reverts the normal and swaps `world1` and `world2`.
+    #[inline]
+    pub fn flip(&mut self) {
+        mem::swap(&mut self.world1, &mut self.world2);
+        self.normal = -self.normal;
+    }
+}
+
+impl<P: Point> From<Contact<P>> for SignedDistance<P> {
+    #[inline]
+    fn from(contact: Contact<P>) -> SignedDistance<P> {
+        SignedDistance::new(
+            -contact.depth,
+            contact.world1,
+            contact.world2,
+            contact.normal,
+        )
+    }
+}
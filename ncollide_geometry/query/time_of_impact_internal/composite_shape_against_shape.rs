@@ -3,43 +3,54 @@ use na;
 use math::{Isometry, Point};
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
-use shape::{CompositeShape, Shape};
-use query::{time_of_impact_internal, Ray, RayCast};
+use shape::{composite_shape, CompositeShape, Shape};
+use query::{time_of_impact_internal, QueryDispatcher, Ray, RayCast, Unsupported};
 
 /// Time Of Impact of a composite shape with any other shape, under translational movement.
 pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     vel1: &P::Vector,
     g1: &G1,
     m2: &M,
     vel2: &P::Vector,
     g2: &Shape<P, M>,
-) -> Option<P::Real>
+) -> Result<Option<P::Real>, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
     G1: CompositeShape<P, M>,
 {
-    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, g1, m2, vel2, g2);
+    let mut cost_fn =
+        CompositeShapeAgainstAnyTOICostFn::new(dispatcher, m1, vel1, g1, m2, vel2, g2);
 
-    g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
+    let res = composite_shape::best_first_search(g1, &mut cost_fn).map(|(_, res)| res);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res),
+    }
 }
 
 /// Time Of Impact of any shape with a composite shape, under translational movement.
 pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    dispatcher: &QueryDispatcher<P, M>,
     m1: &M,
     vel1: &P::Vector,
     g1: &Shape<P, M>,
     m2: &M,
     vel2: &P::Vector,
     g2: &G2,
-) -> Option<P::Real>
+) -> Result<Option<P::Real>, Unsupported>
 where
     P: Point,
     M: Isometry<P>,
     G2: CompositeShape<P, M>,
 {
-    composite_shape_against_shape(m2, vel2, g2, m1, vel1, g1)
+    composite_shape_against_shape(dispatcher, m2, vel2, g2, m1, vel1, g1).map_err(|mut err| {
+        err.flip();
+        err
+    })
 }
 
 struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
@@ -47,12 +58,16 @@ struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized +
     msum_margin: P::Vector,
     ray: Ray<P>,
 
+    dispatcher: &'a QueryDispatcher<P, M>,
     m1: &'a M,
     vel1: &'a P::Vector,
     g1: &'a G1,
     m2: &'a M,
     vel2: &'a P::Vector,
     g2: &'a Shape<P, M>,
+
+    // The first pair of parts not supported by the dispatcher.
+    error: Option<Unsupported>,
 }
 
 impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1>
@@ -62,6 +77,7 @@ where
     G1: CompositeShape<P, M>,
 {
     pub fn new(
+        dispatcher: &'a QueryDispatcher<P, M>,
         m1: &'a M,
         vel1: &'a P::Vector,
         g1: &'a G1,
@@ -76,12 +92,14 @@ where
             msum_shift: -ls_aabb2.center().coordinates(),
             msum_margin: ls_aabb2.half_extents(),
             ray: Ray::new(P::origin(), m1.inverse_rotate_vector(&(*vel2 - *vel1))),
+            dispatcher: dispatcher,
             m1: m1,
             vel1: vel1,
             g1: g1,
             m2: m2,
             vel2: vel2,
             g2: g2,
+            error: None,
         }
     }
 }
@@ -97,6 +115,11 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // No need to continue if some parts are not supported.
+        if self.error.is_some() {
+            return None;
+        }
+
         // Compute the minkowski sum of the two AABBs.
         let msum = AABB::new(
             *bv.mins() + self.msum_shift + (-self.msum_margin),
@@ -112,14 +135,18 @@ where
         let mut res = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
-            res = time_of_impact_internal::time_of_impact(
+            match time_of_impact_internal::time_of_impact_with_dispatcher(
+                self.dispatcher,
                 m1,
                 self.vel1,
                 g1,
                 self.m2,
                 self.vel2,
                 self.g2,
-            ).map(|toi| (toi, toi))
+            ) {
+                Ok(toi) => res = toi.map(|toi| (toi, toi)),
+                Err(err) => self.error = Some(err),
+            }
         });
 
         res
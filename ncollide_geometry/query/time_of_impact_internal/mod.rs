@@ -4,6 +4,14 @@ pub use self::ball_against_ball::ball_against_ball;
 pub use self::support_map_against_support_map::support_map_against_support_map;
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
 pub use self::shape_against_shape::shape_against_shape as time_of_impact;
+pub use self::shape_against_shape::shape_against_shape_with_dispatcher as time_of_impact_with_dispatcher;
+pub use self::shape_against_shape::try_shape_against_shape as try_time_of_impact;
+pub(crate) use self::shape_against_shape::default_shape_against_shape;
+pub use self::nonlinear_shape_against_shape::nonlinear_shape_against_shape as nonlinear_time_of_impact;
+pub use self::nonlinear_shape_against_shape::nonlinear_shape_against_shape_with_dispatcher as nonlinear_time_of_impact_with_dispatcher;
+pub use self::nonlinear_shape_against_shape::try_nonlinear_shape_against_shape as try_nonlinear_time_of_impact;
+pub use self::rigid_motion::{ConstantVelocityRigidMotion, ConstantVelocityRigidMotion2,
+                             ConstantVelocityRigidMotion3, RigidMotion};
 pub use self::composite_shape_against_shape::{composite_shape_against_shape,
                                               shape_against_composite_shape};
 
@@ -12,3 +20,5 @@ mod support_map_against_support_map;
 mod plane_against_support_map;
 mod shape_against_shape;
 mod composite_shape_against_shape;
+mod nonlinear_shape_against_shape;
+mod rigid_motion;
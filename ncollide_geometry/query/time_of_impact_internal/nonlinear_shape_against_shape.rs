@@ -0,0 +1,181 @@
+use num::{Bounded, Zero};
+
+use na;
+use math::{Isometry, Point};
+use shape::Shape;
+use query::closest_points_internal;
+use query::{ClosestPoints, DefaultQueryDispatcher, QueryDispatcher, RigidMotion, TOIStatus,
+            Unsupported};
+
+/// Computes the smallest time of impact of two shapes under rigid motions.
+///
+/// The time of impact is found by conservative advancement: the shapes are moved forward by the
+/// largest time step that cannot make them collide, given their distance and velocities, until
+/// they are closer than `tolerance`. Returns `None` if the shapes do not collide before `max_toi`.
+///
+/// The status of the returned time of impact is `TOIStatus::Converged` if the shapes are closer
+/// than `tolerance` at this time, and `TOIStatus::Penetrating` if they already intersect at the
+/// time `0`. If `max_iterations` is reached first, or if the points of a rotating shape can move
+/// arbitrarily fast (e.g. for a rotating plane), the status is `TOIStatus::Failed` and the time
+/// returned is the one reached so far: the shapes do not collide before it.
+///
+/// Panics if the pair of shapes is not supported.
+pub fn nonlinear_shape_against_shape<P, M>(
+    motion1: &RigidMotion<P, M>,
+    g1: &Shape<P, M>,
+    motion2: &RigidMotion<P, M>,
+    g2: &Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iterations: usize,
+) -> Option<(P::Real, TOIStatus)>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match try_nonlinear_shape_against_shape(
+        motion1,
+        g1,
+        motion2,
+        g2,
+        max_toi,
+        tolerance,
+        max_iterations,
+    ) {
+        Ok(res) => res,
+        Err(err) => panic!(
+            "No algorithm known to compute the nonlinear time of impact of the given pair of \
+             shapes: {}.",
+            err
+        ),
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under rigid motions, or returns an
+/// `Unsupported` error if the pair of shapes is not supported.
+pub fn try_nonlinear_shape_against_shape<P, M>(
+    motion1: &RigidMotion<P, M>,
+    g1: &Shape<P, M>,
+    motion2: &RigidMotion<P, M>,
+    g2: &Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iterations: usize,
+) -> Result<Option<(P::Real, TOIStatus)>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    nonlinear_shape_against_shape_with_dispatcher(
+        &DefaultQueryDispatcher,
+        motion1,
+        g1,
+        motion2,
+        g2,
+        max_toi,
+        tolerance,
+        max_iterations,
+    )
+}
+
+/// Computes the smallest time of impact of two shapes under rigid motions, using `dispatcher` to
+/// compute their closest points.
+///
+/// Any pair of shapes supported by `query::closest_points_with_dispatcher` is supported,
+/// including composite shapes.
+pub fn nonlinear_shape_against_shape_with_dispatcher<P, M>(
+    dispatcher: &QueryDispatcher<P, M>,
+    motion1: &RigidMotion<P, M>,
+    g1: &Shape<P, M>,
+    motion2: &RigidMotion<P, M>,
+    g2: &Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iterations: usize,
+) -> Result<Option<(P::Real, TOIStatus)>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    assert!(
+        tolerance > na::zero(),
+        "The time of impact tolerance must be strictly positive."
+    );
+
+    // Upper bound of the speed at which any point of `g1` can get closer to any point of `g2`.
+    let linear_speed = na::norm(&(motion1.linear_velocity() - motion2.linear_velocity()));
+    let max_speed = match (
+        angular_displacement_speed(motion1, g1),
+        angular_displacement_speed(motion2, g2),
+    ) {
+        (Some(speed1), Some(speed2)) => Some(linear_speed + speed1 + speed2),
+        _ => None,
+    };
+    let mut toi = na::zero();
+
+    for _ in 0..max_iterations {
+        let m1 = motion1.position_at_time(toi);
+        let m2 = motion2.position_at_time(toi);
+
+        let distance = match closest_points_internal::closest_points_with_dispatcher(
+            dispatcher,
+            &m1,
+            g1,
+            &m2,
+            g2,
+            P::Real::max_value(),
+        )? {
+            ClosestPoints::WithinMargin(p1, p2) => na::distance(&p1, &p2),
+            ClosestPoints::Intersecting if toi.is_zero() => {
+                return Ok(Some((toi, TOIStatus::Penetrating)))
+            }
+            ClosestPoints::Intersecting => na::zero(),
+            ClosestPoints::Disjoint => return Ok(None),
+        };
+
+        if distance <= tolerance {
+            return Ok(Some((toi, TOIStatus::Converged)));
+        }
+
+        let max_speed = match max_speed {
+            Some(max_speed) => max_speed,
+            None => return Ok(Some((toi, TOIStatus::Failed))),
+        };
+
+        if max_speed.is_zero() {
+            return Ok(None);
+        }
+
+        toi += distance / max_speed;
+
+        if toi > max_toi {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some((toi, TOIStatus::Failed)))
+}
+
+// Upper bound of the speed of the points of `g` due to the rotation of `motion`, or `None` if
+// this speed is not bounded, i.e., if `g` is an unbounded shape that rotates.
+fn angular_displacement_speed<P, M>(motion: &RigidMotion<P, M>, g: &Shape<P, M>) -> Option<P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let angular_speed = motion.angular_speed();
+
+    if angular_speed.is_zero() {
+        // Avoids infinite values for unbounded shapes, e.g., planes.
+        Some(na::zero())
+    } else {
+        let sphere = g.bounding_sphere(&M::identity());
+        let radius = na::norm(&sphere.center().coordinates()) + sphere.radius();
+
+        if radius < P::Real::max_value() {
+            Some(angular_speed * radius)
+        } else {
+            None
+        }
+    }
+}
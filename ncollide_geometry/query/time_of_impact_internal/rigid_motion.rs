@@ -0,0 +1,117 @@
+use alga::general::Real;
+use na::{Isometry2, Isometry3, Point2, Point3, Translation2, Translation3, UnitComplex,
+         UnitQuaternion, Vector2, Vector3};
+use math::Point;
+
+/// A continuous rigid motion with constant linear and angular velocities.
+///
+/// The object rotates around the origin of its local frame.
+pub trait RigidMotion<P: Point, M> {
+    /// The position of the object at the time `t`.
+    fn position_at_time(&self, t: P::Real) -> M;
+
+    /// The linear velocity of the origin of the local frame of the object.
+    fn linear_velocity(&self) -> P::Vector;
+
+    /// The norm of the angular velocity of the object.
+    fn angular_speed(&self) -> P::Real;
+}
+
+/// A rigid motion defined by a starting position and constant linear and angular velocities.
+#[derive(Clone, Debug)]
+pub struct ConstantVelocityRigidMotion<P: Point, M, A> {
+    /// The position of the object at the time `0`.
+    pub start: M,
+    /// The linear velocity of the origin of the local frame of the object.
+    pub linvel: P::Vector,
+    /// The angular velocity of the object: an angle in 2D, or a scaled axis in 3D.
+    pub angvel: A,
+}
+
+/// A 2D rigid motion with constant velocities.
+pub type ConstantVelocityRigidMotion2<N> =
+    ConstantVelocityRigidMotion<Point2<N>, Isometry2<N>, N>;
+
+/// A 3D rigid motion with constant velocities.
+pub type ConstantVelocityRigidMotion3<N> =
+    ConstantVelocityRigidMotion<Point3<N>, Isometry3<N>, Vector3<N>>;
+
+impl<P: Point, M, A> ConstantVelocityRigidMotion<P, M, A> {
+    /// Creates a motion starting at the position `start` with the given velocities.
+    #[inline]
+    pub fn new(start: M, linvel: P::Vector, angvel: A) -> ConstantVelocityRigidMotion<P, M, A> {
+        ConstantVelocityRigidMotion {
+            start: start,
+            linvel: linvel,
+            angvel: angvel,
+        }
+    }
+}
+
+impl<N: Real> ConstantVelocityRigidMotion2<N> {
+    /// Creates the motion going from the position `start` at the time `0` to the position `end`
+    /// at the time `1`.
+    ///
+    /// The rotation performed is the one with the smallest angle.
+    pub fn from_isometries(start: Isometry2<N>, end: &Isometry2<N>) -> Self {
+        let linvel: Vector2<N> = end.translation.vector - start.translation.vector;
+        let angvel = (end.rotation * start.rotation.inverse()).angle();
+
+        ConstantVelocityRigidMotion::new(start, linvel, angvel)
+    }
+}
+
+impl<N: Real> ConstantVelocityRigidMotion3<N> {
+    /// Creates the motion going from the position `start` at the time `0` to the position `end`
+    /// at the time `1`.
+    ///
+    /// The rotation performed is the one with the smallest angle.
+    pub fn from_isometries(start: Isometry3<N>, end: &Isometry3<N>) -> Self {
+        let linvel = end.translation.vector - start.translation.vector;
+        let angvel = (end.rotation * start.rotation.inverse()).scaled_axis();
+
+        ConstantVelocityRigidMotion::new(start, linvel, angvel)
+    }
+}
+
+impl<N: Real> RigidMotion<Point2<N>, Isometry2<N>> for ConstantVelocityRigidMotion2<N> {
+    #[inline]
+    fn position_at_time(&self, t: N) -> Isometry2<N> {
+        let translation =
+            Translation2::from_vector(self.start.translation.vector + self.linvel * t);
+        let rotation = UnitComplex::new(self.angvel * t) * self.start.rotation;
+
+        Isometry2::from_parts(translation, rotation)
+    }
+
+    #[inline]
+    fn linear_velocity(&self) -> Vector2<N> {
+        self.linvel
+    }
+
+    #[inline]
+    fn angular_speed(&self) -> N {
+        self.angvel.abs()
+    }
+}
+
+impl<N: Real> RigidMotion<Point3<N>, Isometry3<N>> for ConstantVelocityRigidMotion3<N> {
+    #[inline]
+    fn position_at_time(&self, t: N) -> Isometry3<N> {
+        let translation =
+            Translation3::from_vector(self.start.translation.vector + self.linvel * t);
+        let rotation = UnitQuaternion::new(self.angvel * t) * self.start.rotation;
+
+        Isometry3::from_parts(translation, rotation)
+    }
+
+    #[inline]
+    fn linear_velocity(&self) -> Vector3<N> {
+        self.linvel
+    }
+
+    #[inline]
+    fn angular_speed(&self) -> N {
+        self.angvel.norm()
+    }
+}
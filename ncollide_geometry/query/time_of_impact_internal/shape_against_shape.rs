@@ -2,10 +2,13 @@ use alga::linear::Translation;
 use math::{Isometry, Point};
 use shape::{Ball, Plane, Shape};
 use query::time_of_impact_internal;
+use query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
 
 /// Computes the smallest time of impact of two shapes under translational movement.
 ///
 /// Returns `0.0` if the objects are touching or penetrating.
+///
+/// Panics if the pair of shapes is not supported.
 pub fn shape_against_shape<P, M>(
     m1: &M,
     vel1: &P::Vector,
@@ -18,7 +21,94 @@ where
     P: Point,
     M: Isometry<P>,
 {
-    if let (Some(b1), Some(b2)) = (
+    match try_shape_against_shape(m1, vel1, g1, m2, vel2, g2) {
+        Ok(res) => res,
+        Err(err) => panic!(
+            "No algorithm known to compute the time of impact of the given pair of shapes: {}.",
+            err
+        ),
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, or returns an
+/// `Unsupported` error if the pair of shapes is not supported.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<P::Real>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    shape_against_shape_with_dispatcher(&DefaultQueryDispatcher, m1, vel1, g1, m2, vel2, g2)
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, using
+/// `dispatcher` to select the algorithm.
+///
+/// Composite shapes not handled by `dispatcher` are decomposed and each of their parts is
+/// dispatched through `dispatcher` as well. Returns an `Unsupported` error if the pair of shapes,
+/// or any pair made of their parts, is not supported.
+pub fn shape_against_shape_with_dispatcher<P, M>(
+    dispatcher: &QueryDispatcher<P, M>,
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<P::Real>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = dispatcher.time_of_impact(m1, vel1, g1, m2, vel2, g2);
+
+    if res.is_ok() {
+        res
+    } else if let Some(c1) = g1.as_composite_shape() {
+        time_of_impact_internal::composite_shape_against_shape(
+            dispatcher,
+            m1,
+            vel1,
+            c1,
+            m2,
+            vel2,
+            g2,
+        )
+    } else if let Some(c2) = g2.as_composite_shape() {
+        time_of_impact_internal::shape_against_composite_shape(
+            dispatcher,
+            m1,
+            vel1,
+            g1,
+            m2,
+            vel2,
+            c2,
+        )
+    } else {
+        res
+    }
+}
+
+// The time of impact of two non-composite shapes, as computed by the `DefaultQueryDispatcher`.
+pub(crate) fn default_shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<P::Real>, Unsupported>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let res = if let (Some(b1), Some(b2)) = (
         g1.as_shape::<Ball<P::Real>>(),
         g2.as_shape::<Ball<P::Real>>(),
     ) {
@@ -32,11 +122,9 @@ where
         time_of_impact_internal::support_map_against_plane(m1, vel1, s1, m2, vel2, p2)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         time_of_impact_internal::support_map_against_support_map(m1, vel1, s1, m2, vel2, s2)
-    } else if let Some(c1) = g1.as_composite_shape() {
-        time_of_impact_internal::composite_shape_against_shape(m1, vel1, c1, m2, vel2, g2)
-    } else if let Some(c2) = g2.as_composite_shape() {
-        time_of_impact_internal::shape_against_composite_shape(m1, vel1, g1, m2, vel2, c2)
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
-    }
+        return Err(Unsupported::new(g1, g2));
+    };
+
+    Ok(res)
 }
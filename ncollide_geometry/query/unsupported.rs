@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt;
+use std::mem;
+
+use shape::Shape;
+use math::Point;
+
+/// Error returned by a pairwise geometric query when no algorithm is known for a pair of shapes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Unsupported {
+    /// The type name of the first shape, as given by `Shape::type_name`.
+    pub shape1: &'static str,
+    /// The type name of the second shape, as given by `Shape::type_name`.
+    pub shape2: &'static str,
+}
+
+impl Unsupported {
+    /// Creates the error reporting that the pair of shapes `g1` and `g2` is not supported.
+    #[inline]
+    pub fn new<P: Point, M: 'static>(g1: &Shape<P, M>, g2: &Shape<P, M>) -> Unsupported {
+        Unsupported {
+            shape1: g1.type_name(),
+            shape2: g2.type_name(),
+        }
+    }
+
+    /// Swaps `shape1` and `shape2`.
+    #[inline]
+    pub fn flip(&mut self) {
+        mem::swap(&mut self.shape1, &mut self.shape2);
+    }
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "no algorithm known for the pair of shapes `{}` and `{}`",
+            self.shape1, self.shape2
+        )
+    }
+}
+
+impl Error for Unsupported {}
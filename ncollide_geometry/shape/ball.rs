@@ -6,6 +6,7 @@ use math::{Isometry, Point};
 
 /// A Ball shape.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Ball<N> {
     radius: N,
 }
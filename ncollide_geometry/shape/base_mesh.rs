@@ -4,8 +4,8 @@ use std::sync::Arc;
 use std::marker::PhantomData;
 
 use alga::general::Id;
-use na::Point2;
-use partitioning::BVT;
+use na::{self, Point2};
+use partitioning::{BVTVisitor, BVT};
 use bounding_volume::{self, HasBoundingVolume, AABB};
 use math::Point;
 
@@ -16,6 +16,14 @@ pub trait BaseMeshElement<I, P> {
 }
 
 /// A mesh generic wrt. the contained mesh elements characterized by vertices.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-serialize",
+           serde(bound(serialize = "P: ::serde::Serialize, P::Real: ::serde::Serialize, \
+                                    P::Vector: ::serde::Serialize, I: ::serde::Serialize",
+                       deserialize = "P: ::serde::Deserialize<'de>, \
+                                      P::Real: ::serde::Deserialize<'de>, \
+                                      P::Vector: ::serde::Deserialize<'de>, \
+                                      I: ::serde::Deserialize<'de>")))]
 pub struct BaseMesh<P: Point, I, E> {
     bvt: BVT<usize, AABB<P>>,
     bvs: Vec<AABB<P>>,
@@ -23,6 +31,9 @@ pub struct BaseMesh<P: Point, I, E> {
     indices: Arc<Vec<I>>,
     uvs: Option<Arc<Vec<Point2<P::Real>>>>,
     normals: Option<Arc<Vec<P::Vector>>>,
+    pseudo_normals: Option<Arc<MeshPseudoNormals<P::Vector>>>,
+    bvt_cost: P::Real,
+    bvt_rebuild_threshold: Option<P::Real>,
     elt: PhantomData<E>,
 }
 
@@ -38,6 +49,9 @@ where
             indices: self.indices.clone(),
             uvs: self.uvs.clone(),
             normals: self.normals.clone(),
+            pseudo_normals: self.pseudo_normals.clone(),
+            bvt_cost: self.bvt_cost,
+            bvt_rebuild_threshold: self.bvt_rebuild_threshold,
             elt: PhantomData,
         }
     }
@@ -59,24 +73,10 @@ where
             assert!(uvs.len() == vertices.len());
         }
 
-        let mut leaves = Vec::new();
-        let mut bvs = Vec::new();
-
-        {
-            let vs = &*vertices;
-            let is = &*indices;
-
-            for (i, is) in is[..].iter().enumerate() {
-                let vs: &[P] = &vs[..];
-                let element: E = BaseMeshElement::new_with_vertices_and_indices(vs, is);
-                // loosen for better persistancy
-                let bv = bounding_volume::aabb(&element, &Id::new());
-                leaves.push((i, bv.clone()));
-                bvs.push(bv);
-            }
-        }
-
+        let bvs = Self::elements_bounding_volumes(&vertices[..], &indices[..]);
+        let leaves = bvs.iter().cloned().enumerate().collect();
         let bvt = BVT::new_balanced(leaves);
+        let bvt_cost = bvt_cost(&bvt);
 
         BaseMesh {
             bvt: bvt,
@@ -85,9 +85,96 @@ where
             indices: indices,
             uvs: uvs,
             normals: normals,
+            pseudo_normals: None,
+            bvt_cost: bvt_cost,
+            bvt_rebuild_threshold: None,
             elt: PhantomData,
         }
     }
+
+    /// Replaces the vertices of this mesh without modifying its topology.
+    ///
+    /// The new vertex buffer must have the same length as the current one. The bounding volume
+    /// tree is refitted, and the normals and pseudo-normals are discarded since they may no longer
+    /// be valid. Use `set_normals` to provide the normals of the deformed mesh.
+    pub fn set_vertices(&mut self, vertices: Arc<Vec<P>>) {
+        assert!(
+            vertices.len() == self.vertices.len(),
+            "The number of vertices of a mesh cannot be modified."
+        );
+
+        self.vertices = vertices;
+        self.refit();
+    }
+
+    /// Sets the per-vertex normals of this mesh.
+    ///
+    /// If not `None`, there must be exactly one normal per vertex.
+    pub fn set_normals(&mut self, normals: Option<Arc<Vec<P::Vector>>>) {
+        for normals in normals.iter() {
+            assert!(
+                normals.len() == self.vertices.len(),
+                "A mesh must have exactly one normal per vertex."
+            );
+        }
+
+        self.normals = normals;
+    }
+
+    /// Modifies the vertices of this mesh in-place without modifying its topology.
+    ///
+    /// The vertex buffer is cloned first if it is shared with other meshes. The bounding volume
+    /// tree is refitted, and the normals and pseudo-normals are discarded since they may no longer
+    /// be valid. Use `set_normals` to provide the normals of the deformed mesh.
+    pub fn update_vertices<F: FnOnce(&mut [P])>(&mut self, f: F) {
+        f(&mut Arc::make_mut(&mut self.vertices)[..]);
+        self.refit();
+    }
+
+    /// Sets the degradation of the bounding volume tree that triggers its rebuild after a vertex
+    /// update.
+    ///
+    /// The tree is rebuilt when its cost, i.e., the sum of the extents of its internal nodes,
+    /// exceeds `threshold` times its cost right after its last construction. If `None`, the tree
+    /// is only refitted. Defaults to `None`.
+    #[inline]
+    pub fn set_bvt_rebuild_threshold(&mut self, threshold: Option<P::Real>) {
+        self.bvt_rebuild_threshold = threshold
+    }
+
+    /// Rebuilds the bounding volume tree of this mesh from scratch.
+    pub fn rebuild_bvt(&mut self) {
+        let leaves = self.bvs.iter().cloned().enumerate().collect();
+        self.bvt = BVT::new_balanced(leaves);
+        self.bvt_cost = bvt_cost(&self.bvt);
+    }
+
+    fn refit(&mut self) {
+        self.bvs = Self::elements_bounding_volumes(&self.vertices[..], &self.indices[..]);
+        self.normals = None;
+        self.pseudo_normals = None;
+
+        {
+            let bvs = &self.bvs;
+            self.bvt.refit(&mut |i: &usize| bvs[*i].clone());
+        }
+
+        if let Some(threshold) = self.bvt_rebuild_threshold {
+            if bvt_cost(&self.bvt) > self.bvt_cost * threshold {
+                self.rebuild_bvt()
+            }
+        }
+    }
+
+    fn elements_bounding_volumes(vertices: &[P], indices: &[I]) -> Vec<AABB<P>> {
+        indices
+            .iter()
+            .map(|is| {
+                let element: E = BaseMeshElement::new_with_vertices_and_indices(vertices, is);
+                bounding_volume::aabb(&element, &Id::new())
+            })
+            .collect()
+    }
 }
 
 impl<P, I, E> BaseMesh<P, I, E>
@@ -135,6 +222,23 @@ where
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
         &self.bvt
     }
+
+    /// The pseudo-normals of this mesh, if it is closed.
+    #[inline]
+    pub fn pseudo_normals(&self) -> Option<&MeshPseudoNormals<P::Vector>> {
+        self.pseudo_normals.as_ref().map(|normals| &**normals)
+    }
+
+    /// Whether this mesh has been marked as the closed boundary of a solid.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.pseudo_normals.is_some()
+    }
+
+    #[inline]
+    pub(crate) fn set_pseudo_normals(&mut self, pseudo_normals: MeshPseudoNormals<P::Vector>) {
+        self.pseudo_normals = Some(Arc::new(pseudo_normals))
+    }
 }
 
 impl<P, I, E> BaseMesh<P, I, E>
@@ -150,3 +254,75 @@ where
         BaseMeshElement::new_with_vertices_and_indices(vs, &self.indices[i])
     }
 }
+
+// The sum of the extents of the internal nodes of a bounding volume tree.
+fn bvt_cost<P: Point>(bvt: &BVT<usize, AABB<P>>) -> P::Real {
+    let mut visitor = BVTCostVisitor { cost: na::zero() };
+    bvt.visit(&mut visitor);
+    visitor.cost
+}
+
+struct BVTCostVisitor<N> {
+    cost: N,
+}
+
+impl<P: Point> BVTVisitor<usize, AABB<P>> for BVTCostVisitor<P::Real> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        let extents = bv.half_extents();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            self.cost += extents[i];
+        }
+
+        true
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, _: &usize, _: &AABB<P>) {}
+}
+
+/// The pseudo-normals of a closed mesh.
+///
+/// A point is inside of the solid bounded by the mesh if it lies behind the pseudo-normal of the
+/// mesh feature closest to it. The pseudo-normal of a face is its normal, the pseudo-normal of an
+/// edge is the sum of the normals of its two adjacent faces, and the pseudo-normal of a vertex is
+/// the sum of the normals of its adjacent faces weighted by their angle at this vertex.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct MeshPseudoNormals<V> {
+    faces: Vec<V>,
+    edges: Vec<V>,
+    vertices: Vec<V>,
+}
+
+impl<V> MeshPseudoNormals<V> {
+    pub(crate) fn new(faces: Vec<V>, edges: Vec<V>, vertices: Vec<V>) -> MeshPseudoNormals<V> {
+        MeshPseudoNormals {
+            faces: faces,
+            edges: edges,
+            vertices: vertices,
+        }
+    }
+
+    /// The normal of each mesh element.
+    #[inline]
+    pub fn faces(&self) -> &[V] {
+        &self.faces[..]
+    }
+
+    /// The pseudo-normals of the edges of each triangle.
+    ///
+    /// The pseudo-normal of the `k`-th edge of the `i`-th triangle is at index `3 * i + k`. This
+    /// is empty for polylines since their edges are the mesh elements themselves.
+    #[inline]
+    pub fn edges(&self) -> &[V] {
+        &self.edges[..]
+    }
+
+    /// The pseudo-normal of each mesh vertex.
+    #[inline]
+    pub fn vertices(&self) -> &[V] {
+        &self.vertices[..]
+    }
+}
@@ -10,6 +10,7 @@ use math::{Isometry, Point};
 
 /// SupportMap description of a capsule shape with its principal axis aligned with the `y` axis.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Capsule<N> {
     half_height: N,
     radius: N,
@@ -1,5 +1,6 @@
+use num::Bounded;
 use math::Point;
-use partitioning::BVT;
+use partitioning::{BVTCostFn, BVTVisitor};
 use bounding_volume::AABB;
 use shape::Shape;
 
@@ -14,9 +15,94 @@ pub trait CompositeShape<P: Point, M> {
     /// shape.
     fn map_transformed_part_at(&self, usize, m: &M, &mut FnMut(&M, &Shape<P, M>));
 
-    // FIXME: the following two methods really are not generic enough.
+    // FIXME: the following method really is not generic enough.
     /// Gets the AABB of the shape identified by the index `i`.
     fn aabb_at(&self, i: usize) -> AABB<P>;
-    /// Gets the acceleration structure of the concave shape.
-    fn bvt(&self) -> &BVT<usize, AABB<P>>;
+
+    /// Traverses the bounding volume hierarchy of this shape using a visitor.
+    ///
+    /// The leaves of this hierarchy are the indices of the parts of this shape, with their AABB
+    /// expressed in the local space of this shape.
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>);
+
+    /// Performs a best-first search on the bounding volume hierarchy of this shape.
+    ///
+    /// Returns the index of the part with the smallest cost. Use
+    /// `composite_shape::best_first_search(...)` to retrieve the user-defined data computed by
+    /// the cost function as well.
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize>;
+
+    /// Whether this shape has a part identified by the index `i`.
+    ///
+    /// This is always `true` for valid indices, unless parts can be removed from this shape after
+    /// its construction (e.g. `DynamicCompound`). This lets the users of this shape, like the
+    /// contact generators of the collision pipeline, detect part removals.
+    #[inline]
+    fn contains_part(&self, _: usize) -> bool {
+        true
+    }
+}
+
+/// Performs a best-first search on the bounding volume hierarchy of a composite shape.
+///
+/// Returns the index of the part with the smallest associated cost, and the result computed by
+/// `cost_fn` for this part.
+pub fn best_first_search<P, M, G: ?Sized, BFS>(
+    g: &G,
+    cost_fn: &mut BFS,
+) -> Option<(usize, BFS::UserData)>
+where
+    P: Point,
+    G: CompositeShape<P, M>,
+    BFS: BVTCostFn<P::Real, usize, AABB<P>>,
+{
+    let mut adapter = BestResultCostFn {
+        cost_fn: cost_fn,
+        best_cost: P::Real::max_value(),
+        best_result: None,
+    };
+
+    match g.best_first_search_part(&mut adapter) {
+        Some(part) => adapter.best_result.map(|res| (part, res)),
+        None => None,
+    }
+}
+
+// Records the user data of the part with the smallest cost so that the best-first search itself
+// does not have to handle it.
+struct BestResultCostFn<'a, P: Point, BFS: 'a + BVTCostFn<P::Real, usize, AABB<P>>> {
+    cost_fn: &'a mut BFS,
+    best_cost: P::Real,
+    best_result: Option<BFS::UserData>,
+}
+
+impl<'a, P, BFS> BVTCostFn<P::Real, usize, AABB<P>> for BestResultCostFn<'a, P, BFS>
+where
+    P: Point,
+    BFS: BVTCostFn<P::Real, usize, AABB<P>>,
+{
+    type UserData = ();
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        self.cost_fn.compute_bv_cost(bv)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, ())> {
+        match self.cost_fn.compute_b_cost(b) {
+            Some((cost, result)) => {
+                if cost < self.best_cost {
+                    self.best_cost = cost;
+                    self.best_result = Some(result);
+                }
+
+                Some((cost, ()))
+            }
+            None => None,
+        }
+    }
 }
@@ -7,7 +7,7 @@ use std::ops::Mul;
 use na;
 
 use bounding_volume::{BoundingVolume, AABB};
-use partitioning::BVT;
+use partitioning::{BVTCostFn, BVTVisitor, BVT};
 use shape::{CompositeShape, Shape, ShapeHandle};
 use math::{Isometry, Point};
 
@@ -16,7 +16,13 @@ use math::{Isometry, Point};
 /// A compound shape is a shape composed of the union of several simpler shape. This is
 /// the main way of creating a concave shape from convex parts. Each parts can have its own
 /// delta transformation to shift or rotate it with regard to the other shapes.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Compound<P: Point, M> {
+    #[cfg_attr(feature = "serde-serialize",
+               serde(bound(serialize = "M: ::serde::Serialize, \
+                                        ShapeHandle<P, M>: ::serde::Serialize",
+                           deserialize = "M: ::serde::Deserialize<'de>, \
+                                          ShapeHandle<P, M>: ::serde::Deserialize<'de>")))]
     shapes: Vec<(M, ShapeHandle<P, M>)>,
     bvt: BVT<usize, AABB<P>>,
     bvs: Vec<AABB<P>>,
@@ -107,7 +113,15 @@ where
     }
 
     #[inline]
-    fn bvt(&self) -> &BVT<usize, AABB<P>> {
-        self.bvt()
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.bvt().visit(visitor)
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        self.bvt().best_first_search(cost_fn).map(|(part, _)| *part)
     }
 }
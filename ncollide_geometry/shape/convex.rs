@@ -5,6 +5,7 @@ use math::{Isometry, Point};
 
 #[derive(PartialEq, Debug, Clone)]
 /// The implicit convex hull of a set of points.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct ConvexHull<P> {
     points: Vec<P>,
 }
@@ -0,0 +1,175 @@
+//! Convex polygon with explicit edge topology.
+
+use alga::general::Real;
+use na::{self, Point2, Unit, Vector2};
+
+use shape::{FeatureId, SupportMap};
+use math::Isometry;
+
+/// A 2D convex polygon.
+///
+/// The vertices are stored counterclockwise. The `i`-th face of the polygon is the segment joining
+/// the `i`-th vertex and the next one. The support function hill-climbs along the polygon boundary
+/// instead of scanning every point.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct ConvexPolygon<N: Real> {
+    points: Vec<Point2<N>>,
+    normals: Vec<Unit<Vector2<N>>>,
+}
+
+impl<N: Real> ConvexPolygon<N> {
+    /// Attempts to build a convex polygon from the ordered vertices of its boundary.
+    ///
+    /// The vertices may be given either clockwise or counterclockwise, e.g., as output by
+    /// `ncollide_transformation::convex_hull2`. Duplicate and collinear vertices are removed.
+    /// Returns `None` if the result is not a convex polygon with at least three vertices.
+    pub fn try_new(mut points: Vec<Point2<N>>) -> Option<ConvexPolygon<N>> {
+        let eps = N::default_epsilon().sqrt();
+
+        // Remove the duplicate and collinear vertices.
+        let mut i = 0;
+
+        while i < points.len() && points.len() > 2 {
+            let n = points.len();
+            let ab = points[i] - points[(i + n - 1) % n];
+            let bc = points[(i + 1) % n] - points[i];
+
+            if perp(&ab, &bc).abs() <= eps * na::norm(&ab) * na::norm(&bc) {
+                let _ = points.remove(i);
+
+                if i > 0 {
+                    i -= 1;
+                }
+            } else {
+                i += 1;
+            }
+        }
+
+        if points.len() < 3 {
+            return None;
+        }
+
+        // Ensure the vertices are counterclockwise.
+        let mut area = N::zero();
+
+        for i in 0..points.len() {
+            let j = (i + 1) % points.len();
+            area += perp(&points[i].coords, &points[j].coords);
+        }
+
+        if area < N::zero() {
+            points.reverse();
+        }
+
+        let n = points.len();
+        let mut normals = Vec::with_capacity(n);
+
+        for i in 0..n {
+            let ab = points[i] - points[(i + n - 1) % n];
+            let bc = points[(i + 1) % n] - points[i];
+
+            if perp(&ab, &bc) <= N::zero() {
+                return None;
+            }
+
+            normals.push(Unit::new_normalize(Vector2::new(bc.y, -bc.x)));
+        }
+
+        Some(ConvexPolygon {
+            points: points,
+            normals: normals,
+        })
+    }
+
+    /// The vertices of this polygon, in counterclockwise order.
+    #[inline]
+    pub fn points(&self) -> &[Point2<N>] {
+        &self.points[..]
+    }
+
+    /// The outward normals of the faces of this polygon.
+    #[inline]
+    pub fn normals(&self) -> &[Unit<Vector2<N>>] {
+        &self.normals[..]
+    }
+
+    /// The index of a vertex of this polygon furthest toward the direction `local_dir`.
+    pub fn support_point_id(&self, local_dir: &Vector2<N>) -> usize {
+        let n = self.points.len();
+        let mut best = 0;
+        let mut best_dot = na::dot(&self.points[0].coords, local_dir);
+
+        loop {
+            let next = (best + 1) % n;
+            let prev = (best + n - 1) % n;
+            let next_dot = na::dot(&self.points[next].coords, local_dir);
+            let prev_dot = na::dot(&self.points[prev].coords, local_dir);
+
+            if next_dot > best_dot {
+                best = next;
+                best_dot = next_dot;
+            } else if prev_dot > best_dot {
+                best = prev;
+                best_dot = prev_dot;
+            } else {
+                return best;
+            }
+        }
+    }
+
+    /// The feature of this polygon furthest toward the direction `local_dir`.
+    ///
+    /// A face is returned if its normal is within `eps_angle` of `local_dir`. Otherwise a vertex
+    /// is returned.
+    pub fn support_feature_id_toward(
+        &self,
+        local_dir: &Unit<Vector2<N>>,
+        eps_angle: N,
+    ) -> FeatureId {
+        let n = self.points.len();
+        let v = self.support_point_id(local_dir.as_ref());
+        let prev = (v + n - 1) % n;
+        let cos_eps = eps_angle.cos();
+        let dot_prev = na::dot(self.normals[prev].as_ref(), local_dir.as_ref());
+        let dot_curr = na::dot(self.normals[v].as_ref(), local_dir.as_ref());
+
+        if dot_prev >= cos_eps && dot_prev > dot_curr {
+            FeatureId::Face(prev)
+        } else if dot_curr >= cos_eps {
+            FeatureId::Face(v)
+        } else {
+            FeatureId::Vertex(v)
+        }
+    }
+
+    /// The normal of the given feature of this polygon.
+    ///
+    /// The normal of a vertex is the normalized sum of the normals of its adjacent faces.
+    pub fn feature_normal(&self, feature: FeatureId) -> Unit<Vector2<N>> {
+        match feature {
+            FeatureId::Face(f) => self.normals[f],
+            FeatureId::Vertex(v) => {
+                let n = self.points.len();
+                let prev = (v + n - 1) % n;
+
+                Unit::new_normalize(*self.normals[prev] + *self.normals[v])
+            }
+            _ => panic!("Invalid feature for a convex polygon."),
+        }
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> SupportMap<Point2<N>, M> for ConvexPolygon<N> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &Vector2<N>) -> Point2<N> {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        m.transform_point(&self.points[self.support_point_id(&local_dir)])
+    }
+}
+
+#[inline]
+fn perp<N: Real>(a: &Vector2<N>, b: &Vector2<N>) -> N {
+    a.x * b.y - a.y * b.x
+}
@@ -0,0 +1,549 @@
+//! Convex polyhedron with explicit vertex, edge, and face topology.
+
+use std::collections::HashMap;
+
+use alga::general::Real;
+use na::{self, Point3, Unit, Vector3};
+
+use shape::{FeatureId, SupportMap};
+use math::Isometry;
+
+/// A vertex of a convex polyhedron.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct PolyhedronVertex {
+    edges: Vec<usize>,
+    faces: Vec<usize>,
+}
+
+impl PolyhedronVertex {
+    /// The indices of the edges adjacent to this vertex.
+    #[inline]
+    pub fn edges(&self) -> &[usize] {
+        &self.edges[..]
+    }
+
+    /// The indices of the faces adjacent to this vertex.
+    #[inline]
+    pub fn faces(&self) -> &[usize] {
+        &self.faces[..]
+    }
+}
+
+/// An edge of a convex polyhedron.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct PolyhedronEdge {
+    vertices: [usize; 2],
+    faces: [usize; 2],
+}
+
+impl PolyhedronEdge {
+    /// The indices of the two endpoints of this edge.
+    #[inline]
+    pub fn vertices(&self) -> &[usize; 2] {
+        &self.vertices
+    }
+
+    /// The indices of the two faces adjacent to this edge.
+    #[inline]
+    pub fn faces(&self) -> &[usize; 2] {
+        &self.faces
+    }
+
+    /// The endpoint of this edge that is not `vertex`.
+    #[inline]
+    pub fn other_vertex(&self, vertex: usize) -> usize {
+        if self.vertices[0] == vertex {
+            self.vertices[1]
+        } else {
+            self.vertices[0]
+        }
+    }
+}
+
+/// A face of a convex polyhedron.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct PolyhedronFace<N: Real> {
+    vertices: Vec<usize>,
+    edges: Vec<usize>,
+    normal: Unit<Vector3<N>>,
+}
+
+impl<N: Real> PolyhedronFace<N> {
+    /// The indices of the vertices of this face, counterclockwise when seen from outside of the
+    /// polyhedron.
+    #[inline]
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices[..]
+    }
+
+    /// The indices of the edges of this face.
+    ///
+    /// The `i`-th edge joins the `i`-th vertex and the next one.
+    #[inline]
+    pub fn edges(&self) -> &[usize] {
+        &self.edges[..]
+    }
+
+    /// The outward normal of this face.
+    #[inline]
+    pub fn normal(&self) -> &Unit<Vector3<N>> {
+        &self.normal
+    }
+}
+
+/// A 3D convex polyhedron with explicit topology.
+///
+/// Unlike `ConvexHull`, this stores the faces, edges and adjacency information of the polyhedron.
+/// Adjacent coplanar triangles are merged into a single polygonal face. The support function
+/// hill-climbs over the vertex adjacency graph instead of scanning every point.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct ConvexPolyhedron<N: Real> {
+    points: Vec<Point3<N>>,
+    vertices: Vec<PolyhedronVertex>,
+    edges: Vec<PolyhedronEdge>,
+    faces: Vec<PolyhedronFace<N>>,
+}
+
+impl<N: Real> ConvexPolyhedron<N> {
+    /// Attempts to build a convex polyhedron from the closed triangle mesh of its boundary.
+    ///
+    /// The triangles must be oriented counterclockwise when seen from outside of the polyhedron,
+    /// as output by `ncollide_transformation::convex_hull3`. Points not referenced by any
+    /// triangle, or lying strictly inside of a face once the coplanar triangles are merged, are
+    /// discarded so that every vertex has adjacent edges and faces. Returns `None` if the mesh is
+    /// not closed, contains degenerate triangles, or is not convex.
+    pub fn try_new(
+        points: Vec<Point3<N>>,
+        indices: &[Point3<usize>],
+    ) -> Option<ConvexPolyhedron<N>> {
+        let eps = N::default_epsilon().sqrt();
+
+        if indices.is_empty() {
+            return None;
+        }
+
+        /*
+         * Discard the unused points.
+         */
+        let mut remap = vec![usize::max_value(); points.len()];
+        let mut used_points = Vec::new();
+        let mut tris = Vec::with_capacity(indices.len());
+
+        for idx in indices.iter() {
+            let mut tri = [0; 3];
+
+            for k in 0..3 {
+                let i = idx[k];
+
+                if i >= points.len() {
+                    return None;
+                }
+
+                if remap[i] == usize::max_value() {
+                    remap[i] = used_points.len();
+                    used_points.push(points[i]);
+                }
+
+                tri[k] = remap[i];
+            }
+
+            tris.push(tri);
+        }
+
+        let points = used_points;
+
+        /*
+         * Triangle normals and adjacency.
+         */
+        let mut tri_normals = Vec::with_capacity(tris.len());
+        let mut directed_edges = HashMap::new();
+
+        for (t, tri) in tris.iter().enumerate() {
+            let ab = points[tri[1]] - points[tri[0]];
+            let ac = points[tri[2]] - points[tri[0]];
+            let normal = ab.cross(&ac);
+
+            if na::try_normalize(&normal, N::default_epsilon()).is_none() {
+                return None;
+            }
+
+            tri_normals.push(normal);
+
+            for k in 0..3 {
+                if directed_edges.insert((tri[k], tri[(k + 1) % 3]), t).is_some() {
+                    return None;
+                }
+            }
+        }
+
+        // Merge the adjacent coplanar triangles.
+        let mut parents: Vec<usize> = (0..tris.len()).collect();
+
+        for (t, tri) in tris.iter().enumerate() {
+            for k in 0..3 {
+                let u = match directed_edges.get(&(tri[(k + 1) % 3], tri[k])) {
+                    Some(u) => *u,
+                    None => return None,
+                };
+
+                let nt = na::normalize(&tri_normals[t]);
+                let nu = na::normalize(&tri_normals[u]);
+
+                if na::dot(&nt, &nu) >= N::one() - eps {
+                    union(&mut parents, t, u);
+                }
+            }
+        }
+
+        /*
+         * Faces.
+         */
+        let mut face_of_group = vec![usize::max_value(); tris.len()];
+        let mut face_tris: Vec<Vec<usize>> = Vec::new();
+
+        for t in 0..tris.len() {
+            let group = find(&mut parents, t);
+
+            if face_of_group[group] == usize::max_value() {
+                face_of_group[group] = face_tris.len();
+                face_tris.push(Vec::new());
+            }
+
+            face_tris[face_of_group[group]].push(t);
+        }
+
+        let mut faces = Vec::with_capacity(face_tris.len());
+
+        for (f, ftris) in face_tris.iter().enumerate() {
+            let mut next = HashMap::new();
+            let mut first = None;
+            let mut normal = na::zero::<Vector3<N>>();
+
+            for t in ftris.iter() {
+                let tri = &tris[*t];
+                normal += tri_normals[*t];
+
+                for k in 0..3 {
+                    let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                    let u = directed_edges[&(b, a)];
+
+                    if face_of_group[find(&mut parents, u)] != f {
+                        if next.insert(a, b).is_some() {
+                            return None;
+                        }
+
+                        if first.is_none() {
+                            first = Some(a);
+                        }
+                    }
+                }
+            }
+
+            // The boundary of the face must be a single loop.
+            let first = match first {
+                Some(first) => first,
+                None => return None,
+            };
+            let mut vertices = vec![first];
+            let mut curr = next[&first];
+
+            while curr != first {
+                if vertices.len() == next.len() {
+                    return None;
+                }
+
+                vertices.push(curr);
+                curr = match next.get(&curr) {
+                    Some(v) => *v,
+                    None => return None,
+                };
+            }
+
+            if vertices.len() != next.len() {
+                return None;
+            }
+
+            let normal = match Unit::try_new(normal, N::default_epsilon()) {
+                Some(normal) => normal,
+                None => return None,
+            };
+
+            faces.push(PolyhedronFace {
+                vertices: vertices,
+                edges: Vec::new(),
+                normal: normal,
+            });
+        }
+
+        /*
+         * Discard the points strictly inside of the merged faces.
+         */
+        let mut remap = vec![usize::max_value(); points.len()];
+        let mut face_points = Vec::new();
+
+        for face in faces.iter_mut() {
+            for v in face.vertices.iter_mut() {
+                if remap[*v] == usize::max_value() {
+                    remap[*v] = face_points.len();
+                    face_points.push(points[*v]);
+                }
+
+                *v = remap[*v];
+            }
+        }
+
+        let points = face_points;
+
+        /*
+         * Edges and vertices.
+         */
+        let mut edge_ids = HashMap::new();
+        let mut edges: Vec<PolyhedronEdge> = Vec::new();
+
+        for f in 0..faces.len() {
+            let nvtx = faces[f].vertices.len();
+
+            for i in 0..nvtx {
+                let a = faces[f].vertices[i];
+                let b = faces[f].vertices[(i + 1) % nvtx];
+                let key = if a < b { (a, b) } else { (b, a) };
+
+                match edge_ids.get(&key).cloned() {
+                    Some(e) => {
+                        let edge: &mut PolyhedronEdge = &mut edges[e];
+
+                        if edge.faces[1] != usize::max_value() {
+                            return None;
+                        }
+
+                        edge.faces[1] = f;
+                        faces[f].edges.push(e);
+                    }
+                    None => {
+                        let _ = edge_ids.insert(key, edges.len());
+                        faces[f].edges.push(edges.len());
+                        edges.push(PolyhedronEdge {
+                            vertices: [a, b],
+                            faces: [f, usize::max_value()],
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut vertices = vec![
+            PolyhedronVertex {
+                edges: Vec::new(),
+                faces: Vec::new(),
+            };
+            points.len()
+        ];
+
+        for (e, edge) in edges.iter().enumerate() {
+            if edge.faces[1] == usize::max_value() {
+                return None;
+            }
+
+            vertices[edge.vertices[0]].edges.push(e);
+            vertices[edge.vertices[1]].edges.push(e);
+        }
+
+        for (f, face) in faces.iter().enumerate() {
+            for v in face.vertices.iter() {
+                vertices[*v].faces.push(f);
+            }
+        }
+
+        /*
+         * Convexity check.
+         */
+        let mut scale = N::one();
+
+        for pt in points.iter() {
+            for i in 0..3 {
+                scale = scale.max(pt[i].abs());
+            }
+        }
+
+        for face in faces.iter() {
+            let origin = points[face.vertices[0]];
+
+            for pt in points.iter() {
+                if na::dot(face.normal.as_ref(), &(*pt - origin)) > eps * scale {
+                    return None;
+                }
+            }
+        }
+
+        Some(ConvexPolyhedron {
+            points: points,
+            vertices: vertices,
+            edges: edges,
+            faces: faces,
+        })
+    }
+
+    /// The vertex positions of this polyhedron.
+    #[inline]
+    pub fn points(&self) -> &[Point3<N>] {
+        &self.points[..]
+    }
+
+    /// The topology of the vertices of this polyhedron.
+    ///
+    /// The `i`-th vertex is located at the `i`-th point.
+    #[inline]
+    pub fn vertices(&self) -> &[PolyhedronVertex] {
+        &self.vertices[..]
+    }
+
+    /// The edges of this polyhedron.
+    #[inline]
+    pub fn edges(&self) -> &[PolyhedronEdge] {
+        &self.edges[..]
+    }
+
+    /// The faces of this polyhedron.
+    #[inline]
+    pub fn faces(&self) -> &[PolyhedronFace<N>] {
+        &self.faces[..]
+    }
+
+    /// The index of a vertex of this polyhedron furthest toward the direction `local_dir`.
+    ///
+    /// This hill-climbs over the edges of the polyhedron which always reaches the global maximum
+    /// since the polyhedron is convex and each of its vertices has adjacent edges.
+    pub fn support_vertex_id(&self, local_dir: &Vector3<N>) -> usize {
+        let mut best = 0;
+        let mut best_dot = na::dot(&self.points[0].coords, local_dir);
+
+        loop {
+            let curr = best;
+
+            for e in self.vertices[curr].edges.iter() {
+                let other = self.edges[*e].other_vertex(curr);
+                let dot = na::dot(&self.points[other].coords, local_dir);
+
+                if dot > best_dot {
+                    best = other;
+                    best_dot = dot;
+                }
+            }
+
+            if best == curr {
+                return best;
+            }
+        }
+    }
+
+    /// The feature of this polyhedron furthest toward the direction `local_dir`.
+    ///
+    /// A face (resp. an edge) is returned if its normal (resp. its direction) is within
+    /// `eps_angle` of (resp. orthogonal to) `local_dir`. Otherwise a vertex is returned.
+    pub fn support_feature_id_toward(
+        &self,
+        local_dir: &Unit<Vector3<N>>,
+        eps_angle: N,
+    ) -> FeatureId {
+        let v = self.support_vertex_id(local_dir.as_ref());
+        let mut best_face = None;
+        let mut best_dot = eps_angle.cos();
+
+        for f in self.vertices[v].faces.iter() {
+            let dot = na::dot(self.faces[*f].normal.as_ref(), local_dir.as_ref());
+
+            if dot >= best_dot {
+                best_face = Some(*f);
+                best_dot = dot;
+            }
+        }
+
+        if let Some(f) = best_face {
+            return FeatureId::Face(f);
+        }
+
+        let mut best_edge = None;
+        let mut best_dot = eps_angle.sin();
+
+        for e in self.vertices[v].edges.iter() {
+            let edge = &self.edges[*e];
+            let dir = self.points[edge.vertices[1]] - self.points[edge.vertices[0]];
+            let dot = na::dot(&na::normalize(&dir), local_dir.as_ref()).abs();
+
+            if dot <= best_dot {
+                best_edge = Some(*e);
+                best_dot = dot;
+            }
+        }
+
+        match best_edge {
+            Some(e) => FeatureId::Edge(e),
+            None => FeatureId::Vertex(v),
+        }
+    }
+
+    /// The normal of the given feature of this polyhedron.
+    ///
+    /// The normal of an edge or a vertex is the normalized sum of the normals of its adjacent
+    /// faces.
+    pub fn feature_normal(&self, feature: FeatureId) -> Unit<Vector3<N>> {
+        match feature {
+            FeatureId::Face(f) => self.faces[f].normal,
+            FeatureId::Edge(e) => {
+                let faces = &self.edges[e].faces;
+                let normal = *self.faces[faces[0]].normal + *self.faces[faces[1]].normal;
+
+                Unit::new_normalize(normal)
+            }
+            FeatureId::Vertex(v) => {
+                let mut normal = na::zero::<Vector3<N>>();
+
+                for f in self.vertices[v].faces.iter() {
+                    normal += *self.faces[*f].normal;
+                }
+
+                Unit::new_normalize(normal)
+            }
+            FeatureId::Unknown => panic!("Cannot compute the normal of an unknown feature."),
+        }
+    }
+}
+
+impl<N: Real, M: Isometry<Point3<N>>> SupportMap<Point3<N>, M> for ConvexPolyhedron<N> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &Vector3<N>) -> Point3<N> {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        m.transform_point(&self.points[self.support_vertex_id(&local_dir)])
+    }
+}
+
+fn find(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    // Path compression.
+    let mut curr = i;
+
+    while parents[curr] != root {
+        let next = parents[curr];
+        parents[curr] = root;
+        curr = next;
+    }
+
+    root
+}
+
+fn union(parents: &mut [usize], i: usize, j: usize) {
+    let root_i = find(parents, i);
+    let root_j = find(parents, j);
+
+    parents[root_i] = root_j;
+}
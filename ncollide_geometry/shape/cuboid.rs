@@ -7,7 +7,17 @@ use shape::SupportMap;
 use math::{Isometry, Point, Vector};
 
 /// Shape of a box.
+///
+/// The features of a box with `d` dimensions are identified as follows:
+///
+/// * `Face(i)` and `Face(i + d)` are the faces orthogonal to the `i`-th axis, on its positive and
+///   negative side respectively.
+/// * `Vertex(k)` is the vertex whose `i`-th coordinate is positive iff the `i`-th bit of `k` is
+///   set.
+/// * In 3D, `Edge(4 * i + k)` is an edge parallel to the `i`-th axis. The two bits of `k` give the
+///   signs of its coordinates along the two other axes, in increasing axis order.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Cuboid<V> {
     half_extents: V,
 }
@@ -0,0 +1,249 @@
+//!
+//! Shape composed from the union of primitives that can be modified after construction.
+//!
+
+use std::collections::HashMap;
+
+use na;
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde-serialize")]
+use serde::ser::SerializeStruct;
+
+use bounding_volume::{BoundingVolume, AABB};
+use partitioning::{BVTCostFn, BVTVisitor, DBVTLeaf, DBVTLeafId, DBVT};
+use shape::{CompositeShape, Shape, ShapeHandle};
+use math::{Isometry, Point};
+
+/// A compound shape which parts can be inserted, removed, or moved after its construction.
+///
+/// Unlike `Compound`, the parts are stored on a dynamic bounding volume tree which is updated
+/// incrementally on each modification. Each part is identified by an index which is never reused
+/// by this compound, even after the part removal. Thus, the part indices have gaps that can be
+/// detected with `CompositeShape::contains_part`.
+pub struct DynamicCompound<P: Point, M> {
+    parts: HashMap<usize, DynamicCompoundPart<P, M>>,
+    dbvt: DBVT<P, usize, AABB<P>>,
+    next_id: usize,
+}
+
+struct DynamicCompoundPart<P: Point, M> {
+    part: (M, ShapeHandle<P, M>),
+    leaf: DBVTLeafId,
+}
+
+impl<P: Point, M: Clone> Clone for DynamicCompound<P, M> {
+    fn clone(&self) -> DynamicCompound<P, M> {
+        DynamicCompound {
+            parts: self.parts.clone(),
+            dbvt: self.dbvt.clone(),
+            next_id: self.next_id,
+        }
+    }
+}
+
+impl<P: Point, M: Clone> Clone for DynamicCompoundPart<P, M> {
+    fn clone(&self) -> DynamicCompoundPart<P, M> {
+        DynamicCompoundPart {
+            part: self.part.clone(),
+            leaf: self.leaf,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> DynamicCompound<P, M> {
+    /// Creates a new empty dynamic compound shape.
+    pub fn new() -> DynamicCompound<P, M> {
+        DynamicCompound {
+            parts: HashMap::new(),
+            dbvt: DBVT::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a part to this compound shape and returns its index.
+    pub fn insert(&mut self, delta: M, shape: ShapeHandle<P, M>) -> usize {
+        let id = self.next_id;
+        let leaf = self.dbvt.insert(DBVTLeaf::new(part_aabb(&delta, &shape), id));
+        let part = DynamicCompoundPart {
+            part: (delta, shape),
+            leaf: leaf,
+        };
+
+        self.next_id += 1;
+        let _ = self.parts.insert(id, part);
+
+        id
+    }
+
+    /// Removes the `i`-th part of this compound shape.
+    ///
+    /// Returns `None` if this compound does not contain this part.
+    pub fn remove(&mut self, i: usize) -> Option<(M, ShapeHandle<P, M>)> {
+        self.parts.remove(&i).map(|part| {
+            let _ = self.dbvt.remove(part.leaf);
+            part.part
+        })
+    }
+
+    /// Sets the delta transformation of the `i`-th part of this compound shape.
+    ///
+    /// The part bounding volume is only updated if the part moved outside of it. Panics if this
+    /// compound does not contain this part.
+    pub fn set_part_position(&mut self, i: usize, delta: M) {
+        let part = self.parts
+            .get_mut(&i)
+            .expect("Set part position: part not found.");
+        let aabb = part.part.1.aabb(&delta);
+
+        if !self.dbvt[part.leaf].bounding_volume.contains(&aabb) {
+            let _ = self.dbvt.remove(part.leaf);
+            part.leaf = self.dbvt.insert(DBVTLeaf::new(part_aabb(&delta, &part.part.1), i));
+        }
+
+        part.part.0 = delta;
+    }
+}
+
+impl<P: Point, M> DynamicCompound<P, M> {
+    /// The number of parts of this compound shape.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Whether this compound shape has no part.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// The delta transformation and shape of the `i`-th part of this compound shape, if it exists.
+    #[inline]
+    pub fn part(&self, i: usize) -> Option<&(M, ShapeHandle<P, M>)> {
+        self.parts.get(&i).map(|part| &part.part)
+    }
+
+    /// The optimization structure used by this compound shape.
+    #[inline]
+    pub fn dbvt(&self) -> &DBVT<P, usize, AABB<P>> {
+        &self.dbvt
+    }
+
+    /// The AABB of the `i`-th part of this compound shape.
+    ///
+    /// Panics if this compound does not contain this part.
+    #[inline]
+    pub fn aabb_at(&self, i: usize) -> &AABB<P> {
+        &self.dbvt[self.parts[&i].leaf].bounding_volume
+    }
+
+    #[inline]
+    fn part_at(&self, i: usize) -> &(M, ShapeHandle<P, M>) {
+        &self.parts
+            .get(&i)
+            .expect("The dynamic compound does not contain this part.")
+            .part
+    }
+}
+
+// The dynamic bounding volume tree is not serialized: it is rebuilt from the parts on
+// deserialization. The part indices are preserved.
+#[cfg(feature = "serde-serialize")]
+impl<P: Point, M: Serialize> Serialize for DynamicCompound<P, M>
+where
+    ShapeHandle<P, M>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut parts: Vec<_> = self.parts
+            .iter()
+            .map(|(i, part)| (*i, &part.part.0, &part.part.1))
+            .collect();
+        parts.sort_by_key(|part| part.0);
+
+        let mut state = serializer.serialize_struct("DynamicCompound", 2)?;
+        state.serialize_field("parts", &parts)?;
+        state.serialize_field("next_id", &self.next_id)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+#[derive(Deserialize)]
+#[serde(rename = "DynamicCompound")]
+#[serde(bound(deserialize = "M: Deserialize<'de>, ShapeHandle<P, M>: Deserialize<'de>"))]
+struct DynamicCompoundParts<P: Point, M> {
+    parts: Vec<(usize, M, ShapeHandle<P, M>)>,
+    next_id: usize,
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, P: Point, M: Isometry<P> + Deserialize<'de>> Deserialize<'de> for DynamicCompound<P, M>
+where
+    ShapeHandle<P, M>: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = DynamicCompoundParts::deserialize(deserializer)?;
+        let mut compound = DynamicCompound::new();
+
+        for (i, delta, shape) in repr.parts {
+            let leaf = compound
+                .dbvt
+                .insert(DBVTLeaf::new(part_aabb(&delta, &shape), i));
+            let part = DynamicCompoundPart {
+                part: (delta, shape),
+                leaf: leaf,
+            };
+
+            let _ = compound.parts.insert(i, part);
+        }
+
+        compound.next_id = repr.next_id;
+
+        Ok(compound)
+    }
+}
+
+// The AABB of a part, loosened for better persistancy.
+fn part_aabb<P: Point, M: Isometry<P>>(delta: &M, shape: &ShapeHandle<P, M>) -> AABB<P> {
+    shape.aabb(delta).loosened(na::convert(0.04f64))
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for DynamicCompound<P, M> {
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let &(ref m, ref g) = self.part_at(i);
+
+        f(m, g.as_ref())
+    }
+
+    #[inline(always)]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let elt = self.part_at(i);
+
+        f(&(m.clone() * elt.0.clone()), elt.1.as_ref())
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        DynamicCompound::aabb_at(self, i).clone()
+    }
+
+    #[inline]
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.dbvt.visit(visitor)
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        self.dbvt.best_first_search(cost_fn).map(|(part, _)| *part)
+    }
+
+    #[inline]
+    fn contains_part(&self, i: usize) -> bool {
+        self.parts.contains_key(&i)
+    }
+}
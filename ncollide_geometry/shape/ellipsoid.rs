@@ -0,0 +1,63 @@
+//! Support mapping based Ellipsoid shape.
+
+use num::Zero;
+
+use alga::general::Real;
+use na;
+use shape::SupportMap;
+use math::{Isometry, Point, Vector};
+
+/// An ellipsoid (or an ellipse in 2D) centered at the origin and aligned with the local axes.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct Ellipsoid<V> {
+    radii: V,
+}
+
+impl<V: Vector> Ellipsoid<V> {
+    /// Creates a new ellipsoid from its radii along each local axis.
+    ///
+    /// Each radius must be strictly positive.
+    #[inline]
+    pub fn new(radii: V) -> Ellipsoid<V> {
+        for i in 0..na::dimension::<V>() {
+            assert!(
+                radii[i] > V::Real::zero(),
+                "An ellipsoid radius must be strictly positive."
+            );
+        }
+
+        Ellipsoid { radii: radii }
+    }
+}
+
+impl<V> Ellipsoid<V> {
+    /// The radii of this ellipsoid along each local axis.
+    #[inline]
+    pub fn radii(&self) -> &V {
+        &self.radii
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        // The support point is `R² * dir / |R * dir|` where `R` is the diagonal matrix of radii.
+        let mut res = local_dir;
+        let mut norm_squared = P::Real::zero();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            let r_dir = self.radii[i] * local_dir[i];
+            norm_squared += r_dir * r_dir;
+            res[i] = self.radii[i] * r_dir;
+        }
+
+        if norm_squared.is_zero() {
+            return m.translate_point(&P::origin());
+        }
+
+        m.transform_point(&P::from_coordinates(res / norm_squared.sqrt()))
+    }
+}
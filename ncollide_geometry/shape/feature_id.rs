@@ -0,0 +1,17 @@
+//! Identifiers of the geometric features of a shape.
+
+/// An identifier of a geometric feature (vertex, edge, or face) of a shape.
+///
+/// The index is relative to the shape the feature belongs to. For 2D polygons, faces are the
+/// segments of the polygon boundary and there is no edge.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FeatureId {
+    /// Identifier of a vertex.
+    Vertex(usize),
+    /// Identifier of an edge.
+    Edge(usize),
+    /// Identifier of a face.
+    Face(usize),
+    /// Unknown identifier.
+    Unknown,
+}
@@ -0,0 +1,450 @@
+//! A regular grid of heights.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+use num::Bounded;
+use alga::general::Id;
+use na::{self, DMatrix};
+use bounding_volume::{self, AABB};
+use partitioning::{BVTCostFn, BVTVisitor};
+use shape::{CompositeShape, Segment, Shape, Triangle};
+use math::{Isometry, Point};
+
+/// A heightfield, i.e., a regular grid of heights along the `y` axis.
+///
+/// In 3D, the element at the row `i` and column `j` of the heights matrix is the height of the
+/// `i`-th sample along the `z` axis and of the `j`-th sample along the `x` axis. Each cell of the
+/// grid is split into two triangles along its diagonal. In 2D, the heights matrix must have a
+/// single column, its `i`-th element being the height of the `i`-th sample along the `x` axis, and
+/// each cell is a segment.
+///
+/// The heightfield is centered at the origin of the `xz` plane and each sample height is
+/// multiplied by `scale[1]`. Its triangles (or segments) are generated on demand so that large
+/// terrains do not need to store any vertex, index, or bounding volume tree.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct HeightField<P: Point> {
+    #[cfg_attr(feature = "serde-serialize",
+               serde(bound(serialize = "P::Real: ::serde::Serialize",
+                           deserialize = "P::Real: ::serde::Deserialize<'de>")))]
+    heights: DMatrix<P::Real>,
+    scale: P::Vector,
+    aabb: AABB<P>,
+}
+
+impl<P: Point> HeightField<P> {
+    /// Creates a new heightfield with the given heights and scale factor.
+    ///
+    /// The heightfield covers the region `[-scale[0] / 2, scale[0] / 2]` of the `x` axis (and
+    /// `[-scale[2] / 2, scale[2] / 2]` of the `z` axis in 3D).
+    pub fn new(heights: DMatrix<P::Real>, scale: P::Vector) -> HeightField<P> {
+        if na::dimension::<P::Vector>() == 2 {
+            assert!(
+                heights.ncols() == 1,
+                "A 2D heightfield must have a single column of heights."
+            );
+            assert!(
+                heights.nrows() > 1,
+                "A heightfield must have at least two samples."
+            );
+        } else {
+            assert!(
+                heights.nrows() > 1 && heights.ncols() > 1,
+                "A heightfield must have at least two samples along each axis."
+            );
+        }
+
+        let mut min_height = heights[(0, 0)];
+        let mut max_height = heights[(0, 0)];
+
+        for h in heights.iter() {
+            if *h < min_height {
+                min_height = *h;
+            }
+            if *h > max_height {
+                max_height = *h;
+            }
+        }
+
+        let _0_5: P::Real = na::convert(0.5f64);
+        let mut mins = P::from_coordinates(-scale * _0_5);
+        let mut maxs = P::from_coordinates(scale * _0_5);
+        mins[1] = min_height * scale[1];
+        maxs[1] = max_height * scale[1];
+
+        HeightField {
+            heights: heights,
+            scale: scale,
+            aabb: AABB::new(mins, maxs),
+        }
+    }
+
+    /// The heights of this heightfield.
+    #[inline]
+    pub fn heights(&self) -> &DMatrix<P::Real> {
+        &self.heights
+    }
+
+    /// The scale factor applied to this heightfield.
+    #[inline]
+    pub fn scale(&self) -> &P::Vector {
+        &self.scale
+    }
+
+    /// The AABB of this heightfield, in its local space.
+    #[inline]
+    pub fn local_aabb(&self) -> &AABB<P> {
+        &self.aabb
+    }
+
+    /// The number of cells of this heightfield along the `x` axis.
+    #[inline]
+    pub fn num_cells_x(&self) -> usize {
+        if na::dimension::<P::Vector>() == 2 {
+            self.heights.nrows() - 1
+        } else {
+            self.heights.ncols() - 1
+        }
+    }
+
+    /// The number of cells of this heightfield along the `z` axis.
+    ///
+    /// This is always 1 in 2D.
+    #[inline]
+    pub fn num_cells_z(&self) -> usize {
+        if na::dimension::<P::Vector>() == 2 {
+            1
+        } else {
+            self.heights.nrows() - 1
+        }
+    }
+
+    /// The number of triangles (in 3D) or segments (in 2D) of this heightfield.
+    #[inline]
+    pub fn num_parts(&self) -> usize {
+        self.num_cells_x() * self.num_cells_z() * self.num_parts_per_cell()
+    }
+
+    /// The indices of the parts of the cell at the `ix`-th position along `x` and the `iz`-th
+    /// position along `z`.
+    #[inline]
+    pub fn cell_parts(&self, ix: usize, iz: usize) -> Range<usize> {
+        let nparts = self.num_parts_per_cell();
+        let first = (iz * self.num_cells_x() + ix) * nparts;
+
+        first..first + nparts
+    }
+
+    /// The cell containing the orthogonal projection of `pt` on the `xz` plane (or the `x` axis
+    /// in 2D), if it lies inside of the heightfield domain.
+    ///
+    /// The point is expressed in the local space of the heightfield.
+    pub fn cell_at_point(&self, pt: &P) -> Option<(usize, usize)> {
+        let ix = self.cell_coordinate(pt, 0, self.num_cells_x());
+        let iz = if na::dimension::<P::Vector>() == 2 {
+            Some(0)
+        } else {
+            self.cell_coordinate(pt, 2, self.num_cells_z())
+        };
+
+        match (ix, iz) {
+            (Some(ix), Some(iz)) => Some((ix, iz)),
+            _ => None,
+        }
+    }
+
+    /// The `i`-th triangle of this 3D heightfield.
+    pub fn triangle_at(&self, i: usize) -> Triangle<P> {
+        assert!(
+            na::dimension::<P::Vector>() != 2,
+            "Triangles are only available for heightfields with at least 3 dimensions."
+        );
+
+        let cell = i / 2;
+        let ix = cell % self.num_cells_x();
+        let iz = cell / self.num_cells_x();
+
+        let p00 = self.sample_at(ix, iz);
+        let p01 = self.sample_at(ix, iz + 1);
+        let p11 = self.sample_at(ix + 1, iz + 1);
+
+        // Both triangles share the diagonal (p00, p11) and have their normals toward `+y`.
+        if i % 2 == 0 {
+            Triangle::new(p00, p01, p11)
+        } else {
+            Triangle::new(p00, p11, self.sample_at(ix + 1, iz))
+        }
+    }
+
+    /// The `i`-th segment of this 2D heightfield.
+    pub fn segment_at(&self, i: usize) -> Segment<P> {
+        assert!(
+            na::dimension::<P::Vector>() == 2,
+            "Segments are only available for 2D heightfields."
+        );
+
+        Segment::new(self.sample_at(i, 0), self.sample_at(i + 1, 0))
+    }
+
+    /// The AABB of the `i`-th part of this heightfield, in its local space.
+    pub fn part_aabb(&self, i: usize) -> AABB<P> {
+        let (mins, maxs) = if na::dimension::<P::Vector>() == 2 {
+            let seg = self.segment_at(i);
+            bounding_volume::point_cloud_aabb(&Id::new(), &[*seg.a(), *seg.b()])
+        } else {
+            let tri = self.triangle_at(i);
+            bounding_volume::point_cloud_aabb(&Id::new(), &[*tri.a(), *tri.b(), *tri.c()])
+        };
+
+        AABB::new(mins, maxs)
+    }
+
+    #[inline]
+    fn num_parts_per_cell(&self) -> usize {
+        if na::dimension::<P::Vector>() == 2 {
+            1
+        } else {
+            2
+        }
+    }
+
+    // The vertex of the grid at the `ix`-th position along `x` and `iz`-th position along `z`.
+    fn sample_at(&self, ix: usize, iz: usize) -> P {
+        let mut res = *self.aabb.mins();
+        let dx = self.scale[0] / na::convert(self.num_cells_x() as f64);
+        res[0] += dx * na::convert(ix as f64);
+
+        if na::dimension::<P::Vector>() == 2 {
+            res[1] = self.heights[ix] * self.scale[1];
+        } else {
+            let dz = self.scale[2] / na::convert(self.num_cells_z() as f64);
+            res[2] += dz * na::convert(iz as f64);
+            res[1] = self.heights[(iz, ix)] * self.scale[1];
+        }
+
+        res
+    }
+
+    fn cell_coordinate(&self, pt: &P, axis: usize, ncells: usize) -> Option<usize> {
+        let mins = self.aabb.mins()[axis];
+        let maxs = self.aabb.maxs()[axis];
+
+        if pt[axis] < mins || pt[axis] > maxs {
+            return None;
+        }
+
+        let cell: f64 = na::try_convert(
+            (pt[axis] - mins) / (maxs - mins) * na::convert(ncells as f64),
+        ).unwrap_or(0.0);
+
+        if cell >= ncells as f64 {
+            Some(ncells - 1)
+        } else {
+            Some(cell as usize)
+        }
+    }
+
+    // The AABB of a block of cells. Its extent along `y` is the one of the whole heightfield.
+    fn block_aabb(&self, block: &CellBlock) -> AABB<P> {
+        let mut mins = self.sample_at(block.xs.start, block.zs.start);
+        let mut maxs = self.sample_at(block.xs.end, block.zs.end);
+        mins[1] = self.aabb.mins()[1];
+        maxs[1] = self.aabb.maxs()[1];
+
+        AABB::new(mins, maxs)
+    }
+
+    fn root_block(&self) -> CellBlock {
+        CellBlock {
+            xs: 0..self.num_cells_x(),
+            zs: 0..self.num_cells_z(),
+        }
+    }
+
+    fn visit_block(&self, block: CellBlock, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        if !visitor.visit_internal(&self.block_aabb(&block)) {
+            return;
+        }
+
+        if block.is_cell() {
+            for part in self.cell_parts(block.xs.start, block.zs.start) {
+                visitor.visit_leaf(&part, &self.part_aabb(part));
+            }
+        } else {
+            let (left, right) = block.split();
+            self.visit_block(left, visitor);
+            self.visit_block(right, visitor);
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for HeightField<P> {
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline(always)]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        if na::dimension::<P::Vector>() == 2 {
+            f(m, &self.segment_at(i))
+        } else {
+            f(m, &self.triangle_at(i))
+        }
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        self.part_aabb(i)
+    }
+
+    #[inline]
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.visit_block(self.root_block(), visitor)
+    }
+
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut queue: BinaryHeap<BlockWithCost<P::Real>> = BinaryHeap::new();
+        let mut best_cost = P::Real::max_value();
+        let mut result = None;
+
+        let root = self.root_block();
+
+        match cost_fn.compute_bv_cost(&self.block_aabb(&root)) {
+            Some(cost) => queue.push(BlockWithCost::new(root, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            if node.block.is_cell() {
+                for part in self.cell_parts(node.block.xs.start, node.block.zs.start) {
+                    match cost_fn.compute_bv_cost(&self.part_aabb(part)) {
+                        Some(bv_cost) if bv_cost < best_cost => {}
+                        _ => continue,
+                    }
+
+                    if let Some((cost, _)) = cost_fn.compute_b_cost(&part) {
+                        if cost < best_cost {
+                            best_cost = cost;
+                            result = Some(part);
+                        }
+                    }
+                }
+            } else {
+                let (left, right) = node.block.split();
+
+                for child in [left, right].iter() {
+                    if let Some(cost) = cost_fn.compute_bv_cost(&self.block_aabb(child)) {
+                        if cost < best_cost {
+                            queue.push(BlockWithCost::new(child.clone(), -cost))
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/*
+ * Implicit hierarchy.
+ */
+// A rectangular block of cells, used as a node of the implicit bounding volume hierarchy of the
+// heightfield.
+#[derive(Clone)]
+struct CellBlock {
+    xs: Range<usize>,
+    zs: Range<usize>,
+}
+
+impl CellBlock {
+    fn is_cell(&self) -> bool {
+        self.xs.len() == 1 && self.zs.len() == 1
+    }
+
+    // Splits this block in two along its longest side.
+    fn split(self) -> (CellBlock, CellBlock) {
+        if self.xs.len() >= self.zs.len() {
+            let mid = self.xs.start + self.xs.len() / 2;
+
+            (
+                CellBlock {
+                    xs: self.xs.start..mid,
+                    zs: self.zs.clone(),
+                },
+                CellBlock {
+                    xs: mid..self.xs.end,
+                    zs: self.zs,
+                },
+            )
+        } else {
+            let mid = self.zs.start + self.zs.len() / 2;
+
+            (
+                CellBlock {
+                    xs: self.xs.clone(),
+                    zs: self.zs.start..mid,
+                },
+                CellBlock {
+                    xs: self.xs,
+                    zs: mid..self.zs.end,
+                },
+            )
+        }
+    }
+}
+
+struct BlockWithCost<N> {
+    block: CellBlock,
+    cost: N,
+}
+
+impl<N> BlockWithCost<N> {
+    fn new(block: CellBlock, cost: N) -> BlockWithCost<N> {
+        BlockWithCost {
+            block: block,
+            cost: cost,
+        }
+    }
+}
+
+impl<N: PartialEq> PartialEq for BlockWithCost<N> {
+    #[inline]
+    fn eq(&self, other: &BlockWithCost<N>) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<N: PartialEq> Eq for BlockWithCost<N> {}
+
+impl<N: PartialOrd> PartialOrd for BlockWithCost<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &BlockWithCost<N>) -> Option<Ordering> {
+        self.cost.partial_cmp(&other.cost)
+    }
+}
+
+impl<N: PartialOrd> Ord for BlockWithCost<N> {
+    #[inline]
+    fn cmp(&self, other: &BlockWithCost<N>) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Less
+        } else if self.cost > other.cost {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
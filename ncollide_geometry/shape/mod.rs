@@ -6,24 +6,39 @@ pub use self::cuboid::Cuboid;
 pub use self::capsule::Capsule;
 pub use self::cone::Cone;
 pub use self::cylinder::Cylinder;
+pub use self::ellipsoid::Ellipsoid;
 pub use self::convex::ConvexHull;
+pub use self::convex_polyhedron::{ConvexPolyhedron, PolyhedronEdge, PolyhedronFace,
+                                  PolyhedronVertex};
+pub use self::convex_polygon::ConvexPolygon;
+pub use self::feature_id::FeatureId;
 pub use self::minkowski_sum::{cso_support_point, AnnotatedCSO, AnnotatedMinkowskiSum,
                               AnnotatedPoint, MinkowskiSum, CSO};
 pub use self::reflection::Reflection;
 pub use self::compound::Compound;
-pub use self::base_mesh::{BaseMesh, BaseMeshElement};
+pub use self::dynamic_compound::DynamicCompound;
+pub use self::base_mesh::{BaseMesh, BaseMeshElement, MeshPseudoNormals};
 pub use self::trimesh::TriMesh;
 pub use self::polyline::Polyline;
+pub use self::tet_mesh::TetMesh;
+pub use self::heightfield::HeightField;
+pub use self::sdf_grid::SdfGrid;
 pub use self::segment::Segment;
 pub use self::triangle::Triangle;
 pub use self::tetrahedron::Tetrahedron;
 pub use self::torus::Torus;
+pub use self::round_shape::RoundShape;
+pub use self::scaled::Scaled;
+pub(crate) use self::scaled::ScaledCompoundPart;
 #[doc(inline)]
 pub use self::composite_shape::CompositeShape;
 #[doc(inline)]
 pub use self::support_map::SupportMap;
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
+#[cfg(feature = "serde-serialize")]
+pub use self::shape_handle_serde::{register_serializable_shape, with_shared_shapes,
+                                   BUILTIN_SHAPE_NAMES};
 
 use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
 
@@ -42,15 +57,27 @@ mod tetrahedron;
 mod base_mesh;
 mod trimesh;
 mod polyline;
+mod tet_mesh;
+mod heightfield;
+mod sdf_grid;
 mod ball;
 mod capsule;
 mod cone;
 mod cylinder;
+mod ellipsoid;
 mod reflection;
 mod torus;
+mod round_shape;
+mod scaled;
 mod compound;
+mod dynamic_compound;
 mod convex;
+mod convex_polyhedron;
+mod convex_polygon;
+mod feature_id;
 mod shape_impl;
+#[cfg(feature = "serde-serialize")]
+mod shape_handle_serde;
 
 /*
  *
@@ -69,6 +96,10 @@ pub type Capsule2<N> = Capsule<N>;
 pub type Cone2<N> = Cone<N>;
 #[doc = "A 2D cylinder."]
 pub type Cylinder2<N> = Cylinder<N>;
+#[doc = "A 2D ellipse."]
+pub type Ellipsoid2<N> = Ellipsoid<Vector2<N>>;
+#[doc = "A 2D torus."]
+pub type Torus2<N> = Torus<N>;
 #[doc = "A 2D convex polytope."]
 pub type ConvexHull2<N> = ConvexHull<Point2<N>>;
 #[doc = "A 2D segment."]
@@ -77,8 +108,14 @@ pub type Segment2<N> = Segment<Point2<N>>;
 pub type Triangle2<N> = Triangle<Point2<N>>;
 #[doc = "A 2D polyline."]
 pub type Polyline2<N> = Polyline<Point2<N>>;
+#[doc = "A 2D heightfield."]
+pub type HeightField2<N> = HeightField<Point2<N>>;
+#[doc = "A 2D signed distance field grid."]
+pub type SdfGrid2<N> = SdfGrid<Point2<N>>;
 #[doc = "A 2D compound shape."]
 pub type Compound2<N> = Compound<Point2<N>, Isometry2<N>>;
+#[doc = "A 2D dynamic compound shape."]
+pub type DynamicCompound2<N> = DynamicCompound<Point2<N>, Isometry2<N>>;
 #[doc = "A 2D abstract composite shape."]
 pub type CompositeShape2<N> = CompositeShape<Point2<N>, Isometry2<N>>;
 #[doc = "A 2D abstract support mapping."]
@@ -100,6 +137,10 @@ pub type Capsule3<N> = Capsule<N>;
 pub type Cone3<N> = Cone<N>;
 #[doc = "A 3D cylinder."]
 pub type Cylinder3<N> = Cylinder<N>;
+#[doc = "A 3D ellipsoid."]
+pub type Ellipsoid3<N> = Ellipsoid<Vector3<N>>;
+#[doc = "A 3D torus."]
+pub type Torus3<N> = Torus<N>;
 #[doc = "A 3D convex polytope."]
 pub type ConvexHull3<N> = ConvexHull<Point3<N>>;
 #[doc = "A 3D segment."]
@@ -112,8 +153,16 @@ pub type Tetrahedron3<N> = Tetrahedron<Point3<N>>;
 pub type Polyline3<N> = Polyline<Point3<N>>;
 #[doc = "A 3D triangle mesh."]
 pub type TriMesh3<N> = TriMesh<Point3<N>>;
+#[doc = "A 3D tetrahedral mesh."]
+pub type TetMesh3<N> = TetMesh<Point3<N>>;
+#[doc = "A 3D heightfield."]
+pub type HeightField3<N> = HeightField<Point3<N>>;
+#[doc = "A 3D signed distance field grid."]
+pub type SdfGrid3<N> = SdfGrid<Point3<N>>;
 #[doc = "A 3D compound shape."]
 pub type Compound3<N> = Compound<Point3<N>, Isometry3<N>>;
+#[doc = "A 3D dynamic compound shape."]
+pub type DynamicCompound3<N> = DynamicCompound<Point3<N>, Isometry3<N>>;
 #[doc = "A 3D abstract composite shape."]
 pub type CompositeShape3<N> = CompositeShape<Point3<N>, Isometry3<N>>;
 #[doc = "A 3D abstract support mapping."]
@@ -4,6 +4,7 @@ use na::{self, Unit};
 
 /// SupportMap description of a plane.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Plane<V> {
     /// The plane normal.
     normal: Unit<V>,
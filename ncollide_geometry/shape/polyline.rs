@@ -3,14 +3,20 @@
 use std::mem;
 use std::sync::Arc;
 
+use approx::ApproxEq;
+
 use na::{self, Point2};
-use partitioning::BVT;
+use partitioning::{BVTCostFn, BVTVisitor, BVT};
 use bounding_volume::AABB;
-use shape::{BaseMesh, CompositeShape, Segment, Shape};
+use shape::{BaseMesh, CompositeShape, MeshPseudoNormals, Segment, Shape};
 use math::{Isometry, Point};
 
 /// Shape commonly known as a 2d line strip or a 3d segment mesh.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Polyline<P: Point> {
+    #[cfg_attr(feature = "serde-serialize",
+               serde(bound(serialize = "BaseMesh<P, Point2<usize>, Segment<P>>: ::serde::Serialize",
+                           deserialize = "BaseMesh<P, Point2<usize>, Segment<P>>: ::serde::Deserialize<'de>")))]
     mesh: BaseMesh<P, Point2<usize>, Segment<P>>,
 }
 
@@ -78,6 +84,126 @@ impl<P: Point> Polyline<P> {
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
         self.mesh.bvt()
     }
+
+    /// Replaces the vertices of this polyline without modifying its topology.
+    ///
+    /// The new vertex buffer must have the same length as the current one. The bounding volume
+    /// tree is refitted, the normals are discarded, and the pseudo-normals are recomputed if this
+    /// polyline is closed.
+    pub fn set_vertices(&mut self, vertices: Arc<Vec<P>>) {
+        let closed = self.is_closed();
+        self.mesh.set_vertices(vertices);
+
+        if closed {
+            let _ = self.mark_as_closed();
+        }
+    }
+
+    /// Sets the per-vertex normals of this polyline.
+    ///
+    /// If not `None`, there must be exactly one normal per vertex.
+    pub fn set_normals(&mut self, normals: Option<Arc<Vec<P::Vector>>>) {
+        self.mesh.set_normals(normals)
+    }
+
+    /// Modifies the vertices of this polyline in-place without modifying its topology.
+    ///
+    /// The vertex buffer is cloned first if it is shared. The bounding volume tree is refitted,
+    /// the normals are discarded, and the pseudo-normals are recomputed if this polyline is closed.
+    pub fn update_vertices<F: FnOnce(&mut [P])>(&mut self, f: F) {
+        let closed = self.is_closed();
+        self.mesh.update_vertices(f);
+
+        if closed {
+            let _ = self.mark_as_closed();
+        }
+    }
+
+    /// Sets the degradation of the bounding volume tree that triggers its rebuild after a vertex
+    /// update.
+    ///
+    /// See `BaseMesh::set_bvt_rebuild_threshold` for details.
+    #[inline]
+    pub fn set_bvt_rebuild_threshold(&mut self, threshold: Option<P::Real>) {
+        self.mesh.set_bvt_rebuild_threshold(threshold)
+    }
+
+    /// Rebuilds the bounding volume tree of this polyline from scratch.
+    #[inline]
+    pub fn rebuild_bvt(&mut self) {
+        self.mesh.rebuild_bvt()
+    }
+
+    /// Whether this polyline has been marked as the closed boundary of a solid.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.mesh.is_closed()
+    }
+
+    /// Marks this polyline as the closed boundary of a 2D solid.
+    ///
+    /// This computes the pseudo-normals that allow point queries and solid ray casts to detect
+    /// points inside of the polyline. Returns `false` and leaves this polyline unchanged if it is
+    /// not a 2D polyline, or if its segments do not form consistently oriented closed loops.
+    pub fn mark_as_closed(&mut self) -> bool {
+        if na::dimension::<P::Vector>() != 2 {
+            return false;
+        }
+
+        let pseudo_normals = {
+            let vs = &self.mesh.vertices()[..];
+            let is = &self.mesh.indices()[..];
+            let mut outgoing = vec![usize::max_value(); vs.len()];
+            let mut incoming = vec![usize::max_value(); vs.len()];
+            let mut faces = Vec::with_capacity(is.len());
+            let mut area = na::zero::<P::Real>();
+
+            for (i, idx) in is.iter().enumerate() {
+                if outgoing[idx.x] != usize::max_value() || incoming[idx.y] != usize::max_value() {
+                    return false;
+                }
+
+                outgoing[idx.x] = i;
+                incoming[idx.y] = i;
+
+                let (a, b) = (vs[idx.x], vs[idx.y]);
+                let dir = b - a;
+                let mut normal = na::zero::<P::Vector>();
+                normal[0] = dir[1];
+                normal[1] = -dir[0];
+
+                let normal = na::try_normalize(&normal, P::Real::default_epsilon()).unwrap_or(normal);
+                faces.push(normal);
+                area += a[0] * b[1] - a[1] * b[0];
+            }
+
+            let mut vertices = Vec::with_capacity(vs.len());
+
+            for (o, i) in outgoing.iter().zip(incoming.iter()) {
+                if *o == usize::max_value() && *i == usize::max_value() {
+                    // Unused vertex.
+                    vertices.push(na::zero());
+                } else if *o == usize::max_value() || *i == usize::max_value() {
+                    return false;
+                } else {
+                    vertices.push(faces[*o] + faces[*i]);
+                }
+            }
+
+            // Make the normals point outward if the segments are clockwise.
+            if area < na::zero() {
+                for n in faces.iter_mut().chain(vertices.iter_mut()) {
+                    *n = -*n;
+                }
+            }
+
+            MeshPseudoNormals::new(faces, Vec::new(), vertices)
+        };
+
+        self.mesh.set_pseudo_normals(pseudo_normals);
+
+        true
+    }
 }
 
 impl<P: Point> Polyline<P> {
@@ -109,7 +235,15 @@ impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for Polyline<P> {
     }
 
     #[inline]
-    fn bvt(&self) -> &BVT<usize, AABB<P>> {
-        self.bvt()
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.bvt().visit(visitor)
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        self.bvt().best_first_search(cost_fn).map(|(part, _)| *part)
     }
 }
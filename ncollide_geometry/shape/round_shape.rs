@@ -0,0 +1,68 @@
+//! Support mapping based rounded shape.
+
+use approx::ApproxEq;
+
+use alga::general::Real;
+use na::{self, Unit};
+
+use shape::SupportMap;
+use math::{Isometry, Point};
+
+/// A shape dilated by a ball, i.e., a shape with rounded edges and vertices.
+///
+/// The rounded shape is the set of points at a distance smaller than `radius` from the
+/// support-mapped shape it wraps, called its core. Geometric queries work on the core shape and
+/// offset the result by the radius whenever possible.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct RoundShape<N, S> {
+    shape: S,
+    radius: N,
+}
+
+impl<N: Real, S> RoundShape<N, S> {
+    /// Creates a new shape by dilating `shape` with a ball of radius `radius`.
+    pub fn new(shape: S, radius: N) -> RoundShape<N, S> {
+        assert!(
+            !radius.is_negative(),
+            "The radius of a rounded shape must not be negative."
+        );
+
+        RoundShape {
+            shape: shape,
+            radius: radius,
+        }
+    }
+
+    /// The shape dilated by this rounded shape.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.shape
+    }
+
+    /// The radius of the ball dilating the inner shape.
+    #[inline]
+    pub fn radius(&self) -> N {
+        self.radius
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for RoundShape<P::Real, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
+{
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        match na::try_normalize(dir, P::Real::default_epsilon()) {
+            Some(dir) => self.shape.support_point(m, &dir) + dir * self.radius,
+            None => self.shape.support_point(m, dir),
+        }
+    }
+
+    #[inline]
+    fn support_point_toward(&self, m: &M, dir: &Unit<P::Vector>) -> P {
+        self.shape.support_point_toward(m, dir) + **dir * self.radius
+    }
+}
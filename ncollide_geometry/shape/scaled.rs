@@ -0,0 +1,492 @@
+//! Shape scaled non-uniformly along its local axes.
+
+use num::Zero;
+
+use na;
+use partitioning::{BVTCostFn, BVTVisitor};
+use bounding_volume::AABB;
+use shape::{CompositeShape, Compound, DynamicCompound, Segment, Shape, ShapeHandle, SupportMap,
+            Tetrahedron, Triangle};
+use math::{Isometry, Point, Vector};
+
+/// A shape scaled by a different factor along each axis of its local space.
+///
+/// The scaling of support-mapped shapes is exact since it is applied to their support function.
+/// The parts of a scaled `TriMesh`, `Polyline`, `Compound` or `DynamicCompound` are scaled only
+/// when they are accessed through the `CompositeShape` trait so the wrapped shape is never
+/// duplicated. Other composite shapes can be scaled as long as their parts are triangles,
+/// segments, tetrahedra, or parts of scaled shapes: accessing any other part panics.
+///
+/// A `Scaled` shape is a support map or a composite shape only if the shape it wraps is. If it is
+/// not a support map, the support function of the `Scaled` shape is the one of its AABB, and if it
+/// is not a composite shape, the `Scaled` shape has no parts.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct Scaled<V, S> {
+    shape: S,
+    scale: V,
+}
+
+impl<V: Vector, S> Scaled<V, S> {
+    /// Creates a new shape by scaling `shape` by `scale[i]` along its `i`-th local axis.
+    ///
+    /// Each scaling factor must be strictly positive.
+    #[inline]
+    pub fn new(shape: S, scale: V) -> Scaled<V, S> {
+        for i in 0..na::dimension::<V>() {
+            assert!(
+                scale[i] > V::Real::zero(),
+                "The scaling factors must be strictly positive."
+            );
+        }
+
+        Scaled {
+            shape: shape,
+            scale: scale,
+        }
+    }
+
+    /// The shape being scaled.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.shape
+    }
+
+    /// The scaling factor along each local axis.
+    #[inline]
+    pub fn scale(&self) -> &V {
+        &self.scale
+    }
+
+    /// Multiplies each component of `v` by the corresponding scaling factor.
+    #[inline]
+    pub fn scale_vector(&self, v: &V) -> V {
+        scale_vector(v, &self.scale)
+    }
+
+    /// Divides each component of `v` by the corresponding scaling factor.
+    #[inline]
+    pub fn unscale_vector(&self, v: &V) -> V {
+        unscale_vector(v, &self.scale)
+    }
+
+    /// Maps a point from the local space of the inner shape to the local space of `self`.
+    #[inline]
+    pub fn scale_point<P: Point<Vector = V>>(&self, pt: &P) -> P {
+        P::from_coordinates(self.scale_vector(&pt.coordinates()))
+    }
+
+    /// Maps a point from the local space of `self` to the local space of the inner shape.
+    #[inline]
+    pub fn unscale_point<P: Point<Vector = V>>(&self, pt: &P) -> P {
+        P::from_coordinates(self.unscale_vector(&pt.coordinates()))
+    }
+
+    /// Maps an AABB from the local space of the inner shape to the local space of `self`.
+    #[inline]
+    pub fn scale_aabb<P: Point<Vector = V>>(&self, aabb: &AABB<P>) -> AABB<P> {
+        AABB::new(self.scale_point(aabb.mins()), self.scale_point(aabb.maxs()))
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let one: M = na::one();
+
+        scaled_support_point(&self.shape, &one, &self.scale, m, dir)
+            .unwrap_or_else(|| aabb_support_point(&self.aabb(m), dir))
+    }
+}
+
+impl<P, M, S> CompositeShape<P, M> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        map_scaled_part(&self.shape, &one, &self.scale, i, m, f)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        let one: M = na::one();
+
+        match self.shape.as_composite_shape() {
+            Some(composite) => self.scale_aabb(&composite.aabb_at(i)),
+            None => self.scale_aabb(&self.shape.aabb(&one)),
+        }
+    }
+
+    #[inline]
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        let mut adapter = ScaledBVTVisitor::<P, M> {
+            visitor: visitor,
+            m: None,
+            scale: &self.scale,
+        };
+
+        if let Some(composite) = self.shape.as_composite_shape() {
+            composite.visit_parts(&mut adapter)
+        }
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut adapter = ScaledBVTCostFn::<P, M> {
+            cost_fn: cost_fn,
+            m: None,
+            scale: &self.scale,
+        };
+
+        self.shape
+            .as_composite_shape()
+            .and_then(|composite| composite.best_first_search_part(&mut adapter))
+    }
+
+    #[inline]
+    fn contains_part(&self, i: usize) -> bool {
+        self.shape
+            .as_composite_shape()
+            .map_or(false, |composite| composite.contains_part(i))
+    }
+}
+
+/// A part of a scaled compound shape.
+///
+/// This is the shape of the part transformed by `m`, and then scaled.
+#[derive(Clone)]
+pub(crate) struct ScaledCompoundPart<P: Point, M> {
+    m: M,
+    scaled: Scaled<P::Vector, ShapeHandle<P, M>>,
+}
+
+impl<P: Point, M: Isometry<P>> ScaledCompoundPart<P, M> {
+    /// The transformation of the part before it is scaled.
+    #[inline]
+    pub fn m(&self) -> &M {
+        &self.m
+    }
+
+    /// The shape of the part.
+    #[inline]
+    pub fn shape(&self) -> &Shape<P, M> {
+        &**self.scaled.inner()
+    }
+
+    /// The scaling applied to the transformed part.
+    #[inline]
+    pub fn scaled(&self) -> &Scaled<P::Vector, ShapeHandle<P, M>> {
+        &self.scaled
+    }
+
+    /// The AABB of this part, expressed in the local space of the scaled compound.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB<P> {
+        self.scaled.scale_aabb(&self.shape().aabb(&self.m))
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for ScaledCompoundPart<P, M> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        scaled_support_point(self.shape(), &self.m, self.scaled.scale(), m, dir)
+            .unwrap_or_else(|| aabb_support_point(&self.aabb(m), dir))
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for ScaledCompoundPart<P, M> {
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        map_scaled_part(self.shape(), &self.m, self.scaled.scale(), i, m, f)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        match self.shape().as_composite_shape() {
+            Some(composite) => {
+                transformed_scaled_aabb(&composite.aabb_at(i), Some(&self.m), self.scaled.scale())
+            }
+            None => self.local_aabb(),
+        }
+    }
+
+    #[inline]
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        let mut adapter = ScaledBVTVisitor::<P, M> {
+            visitor: visitor,
+            m: Some(&self.m),
+            scale: self.scaled.scale(),
+        };
+
+        if let Some(composite) = self.shape().as_composite_shape() {
+            composite.visit_parts(&mut adapter)
+        }
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut adapter = ScaledBVTCostFn::<P, M> {
+            cost_fn: cost_fn,
+            m: Some(&self.m),
+            scale: self.scaled.scale(),
+        };
+
+        self.shape()
+            .as_composite_shape()
+            .and_then(|composite| composite.best_first_search_part(&mut adapter))
+    }
+
+    #[inline]
+    fn contains_part(&self, i: usize) -> bool {
+        self.shape()
+            .as_composite_shape()
+            .map_or(false, |composite| composite.contains_part(i))
+    }
+}
+
+// The support point of `shape` transformed by `inner_m`, scaled, and then transformed by `m`.
+//
+// This is the scaled support point of the inner shape toward `scale * dir`, or `None` if the
+// inner shape is not a support map.
+fn scaled_support_point<P, M>(
+    shape: &Shape<P, M>,
+    inner_m: &M,
+    scale: &P::Vector,
+    m: &M,
+    dir: &P::Vector,
+) -> Option<P>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let local_dir = m.inverse_rotate_vector(dir);
+
+    shape.as_support_map().map(|support_map| {
+        let pt = support_map.support_point(inner_m, &scale_vector(&local_dir, scale));
+
+        m.transform_point(&P::from_coordinates(scale_vector(&pt.coordinates(), scale)))
+    })
+}
+
+// The support point of an AABB.
+fn aabb_support_point<P: Point>(aabb: &AABB<P>, dir: &P::Vector) -> P {
+    let mut res = *aabb.maxs();
+
+    for i in 0..na::dimension::<P::Vector>() {
+        if dir[i] < na::zero() {
+            res[i] = aabb.mins()[i];
+        }
+    }
+
+    res
+}
+
+// Applies `f` to the `i`-th part of `shape` transformed by `inner_m` and then scaled.
+//
+// The parts of compound shapes are scaled lazily by a `ScaledCompoundPart`, while triangles,
+// segments and tetrahedra are scaled explicitly. The parts removed from a `DynamicCompound` and
+// the parts of shapes that are not composite are skipped.
+fn map_scaled_part<P, M>(
+    shape: &Shape<P, M>,
+    inner_m: &M,
+    scale: &P::Vector,
+    i: usize,
+    m: &M,
+    f: &mut FnMut(&M, &Shape<P, M>),
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    let compound_part = if let Some(compound) = shape.as_shape::<Compound<P, M>>() {
+        compound.shapes().get(i)
+    } else if let Some(compound) = shape.as_shape::<DynamicCompound<P, M>>() {
+        compound.part(i)
+    } else if let Some(composite) = shape.as_composite_shape() {
+        return map_scaled_element(composite, inner_m, scale, i, m, f);
+    } else {
+        return;
+    };
+
+    if let Some(&(ref part_m, ref part)) = compound_part {
+        // The part transform may contain a rotation which does not commute with the scaling
+        // so it has to be kept by the scaled part itself.
+        let part = ScaledCompoundPart {
+            m: inner_m.clone() * part_m.clone(),
+            scaled: Scaled {
+                shape: part.clone(),
+                scale: *scale,
+            },
+        };
+
+        f(m, &part)
+    }
+}
+
+// Applies `f` to the `i`-th element of a mesh-like composite shape transformed by `inner_m` and
+// then scaled.
+//
+// The parts of a nested scaled shape are scaled lazily by wrapping them into another
+// `ScaledCompoundPart`. Other parts cannot be scaled without being copied so this panics.
+fn map_scaled_element<P, M>(
+    shape: &CompositeShape<P, M>,
+    inner_m: &M,
+    scale: &P::Vector,
+    i: usize,
+    m: &M,
+    f: &mut FnMut(&M, &Shape<P, M>),
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    shape.map_transformed_part_at(i, inner_m, &mut |part_m, part| {
+        let scale_pt = |pt: &P| {
+            let pt = part_m.transform_point(pt);
+            P::from_coordinates(scale_vector(&pt.coordinates(), scale))
+        };
+
+        if let Some(t) = part.as_shape::<Triangle<P>>() {
+            f(m, &Triangle::new(scale_pt(t.a()), scale_pt(t.b()), scale_pt(t.c())))
+        } else if let Some(s) = part.as_shape::<Segment<P>>() {
+            f(m, &Segment::new(scale_pt(s.a()), scale_pt(s.b())))
+        } else if let Some(t) = part.as_shape::<Tetrahedron<P>>() {
+            let (a, b, c, d) = (scale_pt(t.a()), scale_pt(t.b()), scale_pt(t.c()), scale_pt(t.d()));
+            f(m, &Tetrahedron::new(a, b, c, d))
+        } else if let Some(nested) = part.as_shape::<ScaledCompoundPart<P, M>>() {
+            let part = ScaledCompoundPart {
+                m: part_m.clone(),
+                scaled: Scaled {
+                    shape: ShapeHandle::new(nested.clone()),
+                    scale: *scale,
+                },
+            };
+
+            f(m, &part)
+        } else {
+            panic!(
+                "The parts of type {} of a composite shape cannot be scaled.",
+                part.type_name()
+            )
+        }
+    })
+}
+
+#[inline]
+fn scale_vector<V: Vector>(v: &V, scale: &V) -> V {
+    let mut res = *v;
+
+    for i in 0..na::dimension::<V>() {
+        res[i] = res[i] * scale[i];
+    }
+
+    res
+}
+
+#[inline]
+fn unscale_vector<V: Vector>(v: &V, scale: &V) -> V {
+    let mut res = *v;
+
+    for i in 0..na::dimension::<V>() {
+        res[i] = res[i] / scale[i];
+    }
+
+    res
+}
+
+// Transforms an AABB by `m` (if any) and then scales it.
+fn transformed_scaled_aabb<P, M>(aabb: &AABB<P>, m: Option<&M>, scale: &P::Vector) -> AABB<P>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let (mins, maxs) = match m {
+        Some(m) => {
+            let center = m.transform_point(&aabb.center());
+            let half_extents = (*aabb.maxs() - *aabb.mins()) * na::convert::<f64, P::Real>(0.5);
+            let ws_half_extents = m.absolute_rotate_vector(&half_extents);
+
+            (center + (-ws_half_extents), center + ws_half_extents)
+        }
+        None => (*aabb.mins(), *aabb.maxs()),
+    };
+
+    AABB::new(
+        P::from_coordinates(scale_vector(&mins.coordinates(), scale)),
+        P::from_coordinates(scale_vector(&maxs.coordinates(), scale)),
+    )
+}
+
+// Maps the bounding volumes of a composite shape before they are given to a visitor.
+struct ScaledBVTVisitor<'a, P: 'a + Point, M: 'a> {
+    visitor: &'a mut BVTVisitor<usize, AABB<P>>,
+    m: Option<&'a M>,
+    scale: &'a P::Vector,
+}
+
+impl<'a, P: Point, M: Isometry<P>> BVTVisitor<usize, AABB<P>> for ScaledBVTVisitor<'a, P, M> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        self.visitor
+            .visit_internal(&transformed_scaled_aabb(bv, self.m, self.scale))
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        self.visitor
+            .visit_leaf(b, &transformed_scaled_aabb(bv, self.m, self.scale))
+    }
+}
+
+// Maps the bounding volumes of a composite shape before they are given to a cost function.
+struct ScaledBVTCostFn<'a, P: 'a + Point, M: 'a> {
+    cost_fn: &'a mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    m: Option<&'a M>,
+    scale: &'a P::Vector,
+}
+
+impl<'a, P, M> BVTCostFn<P::Real, usize, AABB<P>> for ScaledBVTCostFn<'a, P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    type UserData = ();
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        self.cost_fn
+            .compute_bv_cost(&transformed_scaled_aabb(bv, self.m, self.scale))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, ())> {
+        self.cost_fn.compute_b_cost(b)
+    }
+}
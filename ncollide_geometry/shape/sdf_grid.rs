@@ -0,0 +1,263 @@
+//! Signed distance field sampled on a regular grid.
+
+use alga::general::{Id, Real};
+use na;
+use bounding_volume::{self, AABB};
+use query::PointQuery;
+use shape::TriMesh;
+use math::Point;
+
+/// A signed distance field sampled on a regular grid of a 2D or 3D space.
+///
+/// Negative distances are inside of the shape. The distance field is interpolated bilinearly (in
+/// 2D) or trilinearly (in 3D) between the samples. Outside of the grid domain, the distance is
+/// extrapolated by adding the distance to the domain to the interpolated distance on its
+/// boundary.
+///
+/// The sample with the multi-index `(i, j, k)` is located at `origin + (i, j, k) * cell_size` and
+/// is stored at the index `i + j * ni + k * ni * nj` of the values array, where `ni` and `nj` are
+/// the number of samples along the `x` and `y` axes.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct SdfGrid<P: Point> {
+    origin: P,
+    cell_size: P::Real,
+    num_samples: Vec<usize>,
+    values: Vec<P::Real>,
+    aabb: AABB<P>,
+}
+
+impl<P: Point> SdfGrid<P> {
+    /// Creates a new signed distance field grid.
+    ///
+    /// The grid has `num_samples[i]` samples along the `i`-th axis and its first sample is located
+    /// at `origin`. There must be at least two samples along each axis.
+    pub fn new(
+        origin: P,
+        cell_size: P::Real,
+        num_samples: Vec<usize>,
+        values: Vec<P::Real>,
+    ) -> SdfGrid<P> {
+        let dim = na::dimension::<P::Vector>();
+
+        assert!(
+            dim == 2 || dim == 3,
+            "Signed distance field grids are only supported in 2D and 3D."
+        );
+        assert!(
+            num_samples.len() == dim,
+            "The number of samples must be given for each axis."
+        );
+        assert!(
+            cell_size > na::zero(),
+            "The grid cell size must be strictly positive."
+        );
+        assert!(
+            num_samples.iter().all(|n| *n > 1),
+            "A signed distance field grid must have at least two samples along each axis."
+        );
+        assert!(
+            num_samples.iter().product::<usize>() == values.len(),
+            "The number of values does not match the number of samples."
+        );
+
+        let mut maxs = origin;
+
+        for i in 0..dim {
+            maxs[i] += cell_size * na::convert((num_samples[i] - 1) as f64);
+        }
+
+        SdfGrid {
+            origin: origin,
+            cell_size: cell_size,
+            num_samples: num_samples,
+            values: values,
+            aabb: AABB::new(origin, maxs),
+        }
+    }
+
+    /// Samples the signed distance to a closed triangle mesh on a regular grid.
+    ///
+    /// The grid covers the AABB of the mesh enlarged by `margin`. Returns `None` if the mesh
+    /// cannot be marked as closed (see `TriMesh::mark_as_closed`) since the distance sign would
+    /// be undefined.
+    pub fn from_trimesh(
+        mesh: &TriMesh<P>,
+        cell_size: P::Real,
+        margin: P::Real,
+    ) -> Option<SdfGrid<P>> {
+        let mut mesh = mesh.clone();
+
+        if !mesh.is_closed() && !mesh.mark_as_closed() {
+            return None;
+        }
+
+        let dim = na::dimension::<P::Vector>();
+        let (mins, maxs) = bounding_volume::point_cloud_aabb(&Id::new(), &mesh.vertices()[..]);
+        let mut origin = mins;
+        let mut num_samples = Vec::with_capacity(dim);
+
+        for i in 0..dim {
+            origin[i] -= margin;
+
+            let extent = maxs[i] - mins[i] + margin + margin;
+            let ncells: f64 = na::try_convert((extent / cell_size).ceil()).unwrap_or(1.0);
+            num_samples.push(ncells.max(1.0) as usize + 1);
+        }
+
+        let total = num_samples.iter().product();
+        let mut values = Vec::with_capacity(total);
+
+        for id in 0..total {
+            let mut pt = origin;
+            let mut rem = id;
+
+            for i in 0..dim {
+                pt[i] += cell_size * na::convert((rem % num_samples[i]) as f64);
+                rem /= num_samples[i];
+            }
+
+            values.push(mesh.distance_to_point(&Id::new(), &pt, false));
+        }
+
+        Some(SdfGrid::new(origin, cell_size, num_samples, values))
+    }
+
+    /// The position of the first sample of this grid, in its local space.
+    #[inline]
+    pub fn origin(&self) -> &P {
+        &self.origin
+    }
+
+    /// The distance between two consecutive samples along each axis.
+    #[inline]
+    pub fn cell_size(&self) -> P::Real {
+        self.cell_size
+    }
+
+    /// The number of samples along each axis.
+    #[inline]
+    pub fn num_samples(&self) -> &[usize] {
+        &self.num_samples[..]
+    }
+
+    /// The sampled signed distances.
+    #[inline]
+    pub fn values(&self) -> &[P::Real] {
+        &self.values[..]
+    }
+
+    /// The domain covered by the samples of this grid, in its local space.
+    #[inline]
+    pub fn local_aabb(&self) -> &AABB<P> {
+        &self.aabb
+    }
+
+    /// The interpolated signed distance at a point expressed in the local space of this grid.
+    #[inline]
+    pub fn distance_at(&self, pt: &P) -> P::Real {
+        let clamped = self.clamp_to_domain(pt);
+
+        self.interpolate(&clamped) + na::distance(pt, &clamped)
+    }
+
+    /// The interpolated signed distance and its gradient at a point expressed in the local space
+    /// of this grid.
+    ///
+    /// Inside of the grid domain, the gradient is estimated by central differences of the
+    /// interpolated distance with a step of half a cell. It is not normalized.
+    pub fn distance_and_gradient_at(&self, pt: &P) -> (P::Real, P::Vector) {
+        let clamped = self.clamp_to_domain(pt);
+        let mut distance = self.interpolate(&clamped);
+        let mut gradient = na::zero::<P::Vector>();
+        let half_cell = self.cell_size * na::convert(0.5f64);
+
+        for i in 0..na::dimension::<P::Vector>() {
+            let mut a = clamped;
+            let mut b = clamped;
+            a[i] -= half_cell;
+            b[i] += half_cell;
+            let a = self.clamp_to_domain(&a);
+            let b = self.clamp_to_domain(&b);
+
+            gradient[i] = (self.interpolate(&b) - self.interpolate(&a)) / (b[i] - a[i]);
+        }
+
+        // Extrapolation outside of the grid domain.
+        let shift = *pt - clamped;
+        let shift_norm = na::norm(&shift);
+
+        if shift_norm > na::zero() {
+            distance += shift_norm;
+
+            for i in 0..na::dimension::<P::Vector>() {
+                if shift[i] != na::zero() {
+                    gradient[i] = shift[i] / shift_norm;
+                }
+            }
+        }
+
+        (distance, gradient)
+    }
+
+    fn clamp_to_domain(&self, pt: &P) -> P {
+        let mut clamped = *pt;
+
+        for i in 0..na::dimension::<P::Vector>() {
+            if clamped[i] < self.aabb.mins()[i] {
+                clamped[i] = self.aabb.mins()[i];
+            } else if clamped[i] > self.aabb.maxs()[i] {
+                clamped[i] = self.aabb.maxs()[i];
+            }
+        }
+
+        clamped
+    }
+
+    // Multilinear interpolation of the samples at a point inside of the grid domain.
+    fn interpolate(&self, pt: &P) -> P::Real {
+        let dim = na::dimension::<P::Vector>();
+        let _1: P::Real = na::one();
+
+        // Find the cell containing the point and the local coordinates inside of it.
+        let mut first = 0;
+        let mut stride = 1;
+        let mut strides = [0; 3];
+        let mut fracts = [na::zero::<P::Real>(); 3];
+
+        for i in 0..dim {
+            let u = (pt[i] - self.origin[i]) / self.cell_size;
+            let cell: f64 = na::try_convert(u.floor()).unwrap_or(0.0);
+            let cell = if cell < 0.0 {
+                0
+            } else {
+                (cell as usize).min(self.num_samples[i] - 2)
+            };
+
+            fracts[i] = u - na::convert(cell as f64);
+            strides[i] = stride;
+            first += cell * stride;
+            stride *= self.num_samples[i];
+        }
+
+        let mut distance = na::zero::<P::Real>();
+
+        for corner in 0..(1 << dim) {
+            let mut id = first;
+            let mut weight = _1;
+
+            for i in 0..dim {
+                if corner & (1 << i) != 0 {
+                    id += strides[i];
+                    weight *= fracts[i];
+                } else {
+                    weight *= _1 - fracts[i];
+                }
+            }
+
+            distance += self.values[id] * weight;
+        }
+
+        distance
+    }
+}
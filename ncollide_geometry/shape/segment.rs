@@ -6,7 +6,12 @@ use shape::{BaseMeshElement, SupportMap};
 use math::{Isometry, Point};
 
 /// A segment shape.
+///
+/// The vertices `a` and `b` of a segment are identified by `Vertex(0)` and `Vertex(1)`. In 2D, its
+/// two sides are the faces `Face(0)`, on the right of the direction `b - a`, and `Face(1)`. In 3D,
+/// the segment itself is the edge `Edge(0)`.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Segment<P> {
     a: P,
     b: P,
@@ -10,6 +10,7 @@ use shape::{CompositeShape, SupportMap};
 // Queries.
 use bounding_volume::{BoundingSphere, AABB};
 use query::{PointQuery, RayCast};
+use mass_properties::MassProperties;
 use math::Point;
 
 /// Trait implemented by all shapes supported by ncollide.
@@ -20,6 +21,14 @@ pub trait Shape<P: Point, M>: Send + Sync + Any + GetTypeId {
     #[inline]
     fn aabb(&self, m: &M) -> AABB<P>;
 
+    /// A human-readable name of the type of `self`, used by error messages.
+    ///
+    /// Defaults to `"<unnamed shape>"`. Custom shapes should override this.
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        "<unnamed shape>"
+    }
+
     /// The bounding sphere of `self`.
     #[inline]
     fn bounding_sphere(&self, m: &M) -> BoundingSphere<P> {
@@ -52,6 +61,22 @@ pub trait Shape<P: Point, M>: Send + Sync + Any + GetTypeId {
         None
     }
 
+    /// The support mapping of the core of `self` and its dilation radius, if `self` is a rounded
+    /// shape.
+    ///
+    /// Queries use the core shape instead of the whole rounded shape whenever possible as it
+    /// yields more accurate results.
+    #[inline]
+    fn as_round_shape(&self) -> Option<(&SupportMap<P, M>, P::Real)> {
+        None
+    }
+
+    /// The mass properties of `self` if it is a solid with a well-defined volume.
+    #[inline]
+    fn as_mass_properties(&self) -> Option<&MassProperties<P>> {
+        None
+    }
+
     /// Whether `self` uses a supportmapping-based representation.
     #[inline]
     fn is_support_map(&self) -> bool {
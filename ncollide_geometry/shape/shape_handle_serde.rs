@@ -0,0 +1,656 @@
+//! Serialization of shape handles.
+//!
+//! A `ShapeHandle` is serialized as a pair `(id, shape)`. The `id` identifies the shared shape
+//! during a serialization session and `shape` is a pair `(type name, shape data)` tagging the
+//! concrete type of the shape. If the same shared shape has already been serialized during the
+//! current session, `shape` is omitted (serialized as `None`) and only its `id` is written. This
+//! allows the deserializer to rebuild handles sharing the same shape.
+//!
+//! A `RoundShape` or a `Scaled` shape wrapping a builtin shape is tagged with the name of the
+//! wrapper, and its data is itself a pair `(type name, shape data)` where the type name is the
+//! one of the wrapped shape.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use alga::general::Real;
+use na::{Point2, Point3, Vector2, Vector3};
+use erased_serde;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, SeqAccess, Visitor};
+use serde::ser::{self, SerializeTuple};
+
+use shape::{Ball, Capsule, Compound, Cone, ConvexHull, ConvexPolygon, ConvexPolyhedron, Cuboid,
+            Cylinder, DynamicCompound, Ellipsoid, HeightField, Plane, Polyline, RoundShape,
+            Scaled, SdfGrid, Segment, Shape, ShapeHandle, TetMesh, Tetrahedron, Torus, TriMesh,
+            Triangle};
+use math::{Isometry, Point};
+
+type SerializeFn<P, M> = for<'a> fn(&'a Shape<P, M>) -> Option<&'a erased_serde::Serialize>;
+type BuiltinSerializeFn<P, M> =
+    for<'a> fn(&'a Shape<P, M>) -> Option<(&'static str, Box<erased_serde::Serialize + 'a>)>;
+type DeserializeFn<P, M> =
+    for<'a, 'de> fn(&'a mut erased_serde::Deserializer<'de>)
+        -> Result<ShapeHandle<P, M>, erased_serde::Error>;
+
+/// The names used to tag the shapes supported by ncollide when serializing a `ShapeHandle`.
+///
+/// They cannot be used to register user-defined shapes.
+pub const BUILTIN_SHAPE_NAMES: &[&str] = &[
+    "Ball",
+    "Cuboid",
+    "Cylinder",
+    "Cone",
+    "Capsule",
+    "ConvexHull",
+    "ConvexPolygon",
+    "ConvexPolyhedron",
+    "Segment",
+    "Triangle",
+    "Tetrahedron",
+    "Plane",
+    "Ellipsoid",
+    "Torus",
+    "Compound",
+    "DynamicCompound",
+    "TriMesh",
+    "Polyline",
+    "TetMesh",
+    "HeightField",
+    "SdfGrid",
+    "RoundShape",
+    "Scaled",
+];
+
+/*
+ * Registration of user-defined shapes.
+ */
+struct UserShape<P: Point, M> {
+    name: &'static str,
+    serialize: SerializeFn<P, M>,
+    deserialize: DeserializeFn<P, M>,
+}
+
+impl<P: Point, M> Clone for UserShape<P, M> {
+    fn clone(&self) -> UserShape<P, M> {
+        UserShape {
+            name: self.name,
+            serialize: self.serialize,
+            deserialize: self.deserialize,
+        }
+    }
+}
+
+lazy_static! {
+    // The user-defined shapes registered for each `(P, M)` pair of type parameters.
+    static ref USER_SHAPES: Mutex<HashMap<TypeId, Box<Any + Send>>> = Mutex::new(HashMap::new());
+}
+
+/// Registers a user-defined shape type so that `ShapeHandle`s containing it can be serialized.
+///
+/// The shape is tagged with `name` when serialized. Registering the same name twice replaces the
+/// previous registration. Panics if `name` is one of the `BUILTIN_SHAPE_NAMES`.
+pub fn register_serializable_shape<P, M, S>(name: &'static str)
+where
+    P: Point,
+    M: 'static,
+    S: Shape<P, M> + Serialize + DeserializeOwned,
+{
+    assert!(
+        !BUILTIN_SHAPE_NAMES.contains(&name),
+        "The shape name `{}` is reserved.",
+        name
+    );
+
+    let shape = UserShape {
+        name: name,
+        serialize: serialize_user_shape::<P, M, S>,
+        deserialize: deserialize_user_shape::<P, M, S>,
+    };
+
+    let mut registries = USER_SHAPES.lock().unwrap();
+    let registry = registries
+        .entry(TypeId::of::<(P, M)>())
+        .or_insert_with(|| Box::new(Vec::<UserShape<P, M>>::new()));
+    let registry = registry.downcast_mut::<Vec<UserShape<P, M>>>().unwrap();
+
+    registry.retain(|shape| shape.name != name);
+    registry.push(shape);
+}
+
+// The registry is copied so that it is not locked while serializing nested shape handles.
+fn user_shapes<P: Point, M: 'static>() -> Vec<UserShape<P, M>> {
+    let registries = USER_SHAPES.lock().unwrap();
+
+    match registries.get(&TypeId::of::<(P, M)>()) {
+        Some(registry) => registry
+            .downcast_ref::<Vec<UserShape<P, M>>>()
+            .unwrap()
+            .clone(),
+        None => Vec::new(),
+    }
+}
+
+fn serialize_user_shape<'a, P, M, S>(
+    shape: &'a Shape<P, M>,
+) -> Option<&'a erased_serde::Serialize>
+where
+    P: Point,
+    M: 'static,
+    S: Shape<P, M> + Serialize,
+{
+    shape
+        .as_shape::<S>()
+        .map(|shape| shape as &erased_serde::Serialize)
+}
+
+fn deserialize_user_shape<'a, 'de, P, M, S>(
+    deserializer: &'a mut erased_serde::Deserializer<'de>,
+) -> Result<ShapeHandle<P, M>, erased_serde::Error>
+where
+    P: Point,
+    S: Shape<P, M> + DeserializeOwned,
+{
+    erased_serde::deserialize::<S>(deserializer).map(ShapeHandle::new)
+}
+
+/*
+ * Sharing of the shapes between handles.
+ */
+struct SharedShapes {
+    next_id: u64,
+    serialized: HashMap<usize, u64>,
+    deserialized: HashMap<u64, Box<Any>>,
+}
+
+thread_local! {
+    static SHARED_SHAPES: RefCell<Option<SharedShapes>> = RefCell::new(None);
+}
+
+struct SharedShapesGuard;
+
+impl Drop for SharedShapesGuard {
+    fn drop(&mut self) {
+        SHARED_SHAPES.with(|shared| *shared.borrow_mut() = None);
+    }
+}
+
+/// Executes `f` within a session where shapes shared by several `ShapeHandle`s are serialized
+/// only once, and deserialized as shapes shared by several `ShapeHandle`s.
+///
+/// Each serialized `ShapeHandle` outside of such a session opens its own session, so the sharing
+/// is already preserved within a single shape handle, e.g., between the parts of a `Compound`.
+/// This must be used to preserve the sharing between several shape handles serialized
+/// independently, e.g., the elements of a `Vec<ShapeHandle<P, M>>`. The same data must then be
+/// deserialized within such a session as well. Sessions do not nest: an inner call to
+/// `with_shared_shapes` simply executes `f` within the current session.
+pub fn with_shared_shapes<R, F: FnOnce() -> R>(f: F) -> R {
+    let is_new_session = SHARED_SHAPES.with(|shared| {
+        let mut shared = shared.borrow_mut();
+
+        if shared.is_none() {
+            *shared = Some(SharedShapes {
+                next_id: 0,
+                serialized: HashMap::new(),
+                deserialized: HashMap::new(),
+            });
+            true
+        } else {
+            false
+        }
+    });
+
+    let _guard = if is_new_session {
+        Some(SharedShapesGuard)
+    } else {
+        None
+    };
+
+    f()
+}
+
+// Returns the session identifier of a shape and whether it is serialized for the first time.
+fn shared_shape_id<P: Point, M>(handle: &ShapeHandle<P, M>) -> (u64, bool) {
+    let address = &**handle as *const Shape<P, M> as *const () as usize;
+
+    SHARED_SHAPES.with(|shared| {
+        let mut shared = shared.borrow_mut();
+        let shared = shared
+            .as_mut()
+            .expect("Shape handles must be serialized within a session.");
+
+        if let Some(id) = shared.serialized.get(&address) {
+            return (*id, false);
+        }
+
+        let id = shared.next_id;
+        shared.next_id += 1;
+        let _ = shared.serialized.insert(address, id);
+
+        (id, true)
+    })
+}
+
+fn deserialized_shape<P: Point, M: Clone + 'static>(id: u64) -> Option<ShapeHandle<P, M>> {
+    SHARED_SHAPES.with(|shared| {
+        let shared = shared.borrow();
+        let shared = shared
+            .as_ref()
+            .expect("Shape handles must be deserialized within a session.");
+
+        shared
+            .deserialized
+            .get(&id)
+            .and_then(|shape| shape.downcast_ref::<ShapeHandle<P, M>>())
+            .cloned()
+    })
+}
+
+fn register_deserialized_shape<P: Point, M: Clone + 'static>(id: u64, handle: &ShapeHandle<P, M>) {
+    SHARED_SHAPES.with(|shared| {
+        let mut shared = shared.borrow_mut();
+        let shared = shared
+            .as_mut()
+            .expect("Shape handles must be deserialized within a session.");
+
+        let _ = shared.deserialized.insert(id, Box::new(handle.clone()));
+    })
+}
+
+/*
+ * Serialization.
+ */
+struct TaggedShape<'a>(&'static str, &'a erased_serde::Serialize);
+
+impl<'a> Serialize for TaggedShape<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(self.0)?;
+        tuple.serialize_element(self.1)?;
+        tuple.end()
+    }
+}
+
+fn serialize_shape_handle<P, M, S>(
+    handle: &ShapeHandle<P, M>,
+    serializer: S,
+    builtin: BuiltinSerializeFn<P, M>,
+) -> Result<S::Ok, S::Error>
+where
+    P: Point,
+    M: 'static,
+    S: Serializer,
+{
+    with_shared_shapes(|| {
+        let (id, is_new) = shared_shape_id(handle);
+        let mut shape = None;
+
+        if is_new {
+            shape = builtin(&**handle);
+
+            if shape.is_none() {
+                for user_shape in user_shapes::<P, M>() {
+                    if let Some(data) = (user_shape.serialize)(&**handle) {
+                        shape = Some((user_shape.name, Box::new(data) as Box<_>));
+                        break;
+                    }
+                }
+            }
+
+            if shape.is_none() {
+                return Err(ser::Error::custom(
+                    "unregistered shape type: see `shape::register_serializable_shape`",
+                ));
+            }
+        }
+
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&id)?;
+        let tagged = shape.as_ref().map(|&(name, ref data)| TaggedShape(name, &**data));
+
+        tuple.serialize_element(&tagged)?;
+        tuple.end()
+    })
+}
+
+/*
+ * Deserialization.
+ */
+struct ShapeHandleVisitor<P: Point, M> {
+    builtin: fn(&str) -> Option<DeserializeFn<P, M>>,
+}
+
+impl<'de, P: Point, M: Clone + 'static> Visitor<'de> for ShapeHandleVisitor<P, M> {
+    type Value = ShapeHandle<P, M>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a shape handle")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let id: u64 = match seq.next_element()? {
+            Some(id) => id,
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+        let seed = SharedShapeSeed {
+            id: id,
+            builtin: self.builtin,
+        };
+
+        match seq.next_element_seed(seed)? {
+            Some(handle) => Ok(handle),
+            None => Err(de::Error::invalid_length(1, &self)),
+        }
+    }
+}
+
+// Deserializes the shape of a handle, or retrieves it if it has already been deserialized.
+struct SharedShapeSeed<P: Point, M> {
+    id: u64,
+    builtin: fn(&str) -> Option<DeserializeFn<P, M>>,
+}
+
+impl<'de, P: Point, M: Clone + 'static> DeserializeSeed<'de> for SharedShapeSeed<P, M> {
+    type Value = ShapeHandle<P, M>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, P: Point, M: Clone + 'static> Visitor<'de> for SharedShapeSeed<P, M> {
+    type Value = ShapeHandle<P, M>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an optional tagged shape")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        match deserialized_shape(self.id) {
+            Some(handle) => Ok(handle),
+            None => Err(E::custom(format!("unknown shared shape {}", self.id))),
+        }
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let visitor = TaggedShapeVisitor {
+            builtin: self.builtin,
+            user_shapes: true,
+        };
+        let handle = deserializer.deserialize_tuple(2, visitor)?;
+
+        register_deserialized_shape(self.id, &handle);
+
+        Ok(handle)
+    }
+}
+
+struct TaggedShapeVisitor<P: Point, M> {
+    builtin: fn(&str) -> Option<DeserializeFn<P, M>>,
+    // Whether the user-defined shapes are looked up if the name is not a builtin one.
+    user_shapes: bool,
+}
+
+impl<'de, P: Point, M: Clone + 'static> Visitor<'de> for TaggedShapeVisitor<P, M> {
+    type Value = ShapeHandle<P, M>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tagged shape")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let name: String = match seq.next_element()? {
+            Some(name) => name,
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+
+        let mut deserialize = (self.builtin)(&name[..]);
+
+        if deserialize.is_none() && self.user_shapes {
+            deserialize = user_shapes::<P, M>()
+                .iter()
+                .find(|shape| shape.name == name)
+                .map(|shape| shape.deserialize);
+        }
+
+        let deserialize = match deserialize {
+            Some(deserialize) => deserialize,
+            None => return Err(de::Error::custom(format!("unknown shape type `{}`", name))),
+        };
+
+        match seq.next_element_seed(ShapeSeed(deserialize))? {
+            Some(handle) => Ok(handle),
+            None => Err(de::Error::invalid_length(1, &self)),
+        }
+    }
+}
+
+struct ShapeSeed<P: Point, M>(DeserializeFn<P, M>);
+
+impl<'de, P: Point, M> DeserializeSeed<'de> for ShapeSeed<P, M> {
+    type Value = ShapeHandle<P, M>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let mut deserializer = erased_serde::Deserializer::erase(deserializer);
+
+        (self.0)(&mut deserializer).map_err(de::Error::custom)
+    }
+}
+
+// Deserializes a shape wrapping a builtin shape, tagged with the name of the wrapped shape.
+fn deserialize_wrapper_shape<'a, 'de, P, M>(
+    deserializer: &'a mut erased_serde::Deserializer<'de>,
+    wrapped: fn(&str) -> Option<DeserializeFn<P, M>>,
+) -> Result<ShapeHandle<P, M>, erased_serde::Error>
+where
+    P: Point,
+    M: Clone + 'static,
+{
+    let visitor = TaggedShapeVisitor {
+        builtin: wrapped,
+        user_shapes: false,
+    };
+
+    deserializer.deserialize_tuple(2, visitor)
+}
+
+fn deserialize_shape_handle<'de, P, M, D>(
+    deserializer: D,
+    builtin: fn(&str) -> Option<DeserializeFn<P, M>>,
+) -> Result<ShapeHandle<P, M>, D::Error>
+where
+    P: Point,
+    M: Clone + 'static,
+    D: Deserializer<'de>,
+{
+    with_shared_shapes(|| deserializer.deserialize_tuple(2, ShapeHandleVisitor { builtin: builtin }))
+}
+
+/*
+ * Shapes supported in each dimension.
+ */
+macro_rules! impl_serde_for_shape_handle(
+    ($Point: ident, $Vector: ident,
+     shapes: { $($name: tt => $Shape: ty),* $(,)* },
+     support_maps: { $($sm_name: tt => $SupportMap: ty),* $(,)* }) => {
+        impl<N, M> Serialize for ShapeHandle<$Point<N>, M>
+        where
+            N: Real + Serialize,
+            M: Isometry<$Point<N>> + Serialize,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                fn builtin<'a, N, M>(
+                    shape: &'a Shape<$Point<N>, M>,
+                ) -> Option<(&'static str, Box<erased_serde::Serialize + 'a>)>
+                where
+                    N: Real + Serialize,
+                    M: Isometry<$Point<N>> + Serialize,
+                {
+                    $(
+                    if let Some(shape) = shape.as_shape::<$Shape>() {
+                        return Some(($name, Box::new(shape)));
+                    }
+
+                    if let Some(shape) = shape.as_shape::<Scaled<$Vector<N>, $Shape>>() {
+                        return Some(("Scaled", Box::new(TaggedShape($name, shape))));
+                    }
+                    )*
+
+                    $(
+                    if let Some(shape) = shape.as_shape::<RoundShape<N, $SupportMap>>() {
+                        return Some(("RoundShape", Box::new(TaggedShape($sm_name, shape))));
+                    }
+                    )*
+
+                    None
+                }
+
+                serialize_shape_handle(self, serializer, builtin::<N, M>)
+            }
+        }
+
+        impl<'de, N, M> Deserialize<'de> for ShapeHandle<$Point<N>, M>
+        where
+            N: Real + DeserializeOwned,
+            M: Isometry<$Point<N>> + DeserializeOwned,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                fn builtin<N, M>(name: &str) -> Option<DeserializeFn<$Point<N>, M>>
+                where
+                    N: Real + DeserializeOwned,
+                    M: Isometry<$Point<N>> + DeserializeOwned,
+                {
+                    match name {
+                        $($name => Some(deserialize_user_shape::<$Point<N>, M, $Shape>),)*
+                        "Scaled" => Some(deserialize_scaled::<N, M>),
+                        "RoundShape" => Some(deserialize_round_shape::<N, M>),
+                        _ => None,
+                    }
+                }
+
+                fn deserialize_scaled<'a, 'de, N, M>(
+                    deserializer: &'a mut erased_serde::Deserializer<'de>,
+                ) -> Result<ShapeHandle<$Point<N>, M>, erased_serde::Error>
+                where
+                    N: Real + DeserializeOwned,
+                    M: Isometry<$Point<N>> + DeserializeOwned,
+                {
+                    fn scaled<N, M>(name: &str) -> Option<DeserializeFn<$Point<N>, M>>
+                    where
+                        N: Real + DeserializeOwned,
+                        M: Isometry<$Point<N>> + DeserializeOwned,
+                    {
+                        match name {
+                            $($name => Some(
+                                deserialize_user_shape::<$Point<N>, M, Scaled<$Vector<N>, $Shape>>
+                            ),)*
+                            _ => None,
+                        }
+                    }
+
+                    deserialize_wrapper_shape(deserializer, scaled::<N, M>)
+                }
+
+                fn deserialize_round_shape<'a, 'de, N, M>(
+                    deserializer: &'a mut erased_serde::Deserializer<'de>,
+                ) -> Result<ShapeHandle<$Point<N>, M>, erased_serde::Error>
+                where
+                    N: Real + DeserializeOwned,
+                    M: Isometry<$Point<N>> + DeserializeOwned,
+                {
+                    fn round_shape<N, M>(name: &str) -> Option<DeserializeFn<$Point<N>, M>>
+                    where
+                        N: Real + DeserializeOwned,
+                        M: Isometry<$Point<N>> + DeserializeOwned,
+                    {
+                        match name {
+                            $($sm_name => Some(
+                                deserialize_user_shape::<$Point<N>, M, RoundShape<N, $SupportMap>>
+                            ),)*
+                            _ => None,
+                        }
+                    }
+
+                    deserialize_wrapper_shape(deserializer, round_shape::<N, M>)
+                }
+
+                deserialize_shape_handle(deserializer, builtin::<N, M>)
+            }
+        }
+    }
+);
+
+impl_serde_for_shape_handle!(Point2, Vector2,
+    shapes: {
+        "Ball" => Ball<N>,
+        "Cuboid" => Cuboid<Vector2<N>>,
+        "Cylinder" => Cylinder<N>,
+        "Cone" => Cone<N>,
+        "Capsule" => Capsule<N>,
+        "ConvexHull" => ConvexHull<Point2<N>>,
+        "ConvexPolygon" => ConvexPolygon<N>,
+        "Segment" => Segment<Point2<N>>,
+        "Triangle" => Triangle<Point2<N>>,
+        "Plane" => Plane<Vector2<N>>,
+        "Ellipsoid" => Ellipsoid<Vector2<N>>,
+        "Torus" => Torus<N>,
+        "Compound" => Compound<Point2<N>, M>,
+        "DynamicCompound" => DynamicCompound<Point2<N>, M>,
+        "Polyline" => Polyline<Point2<N>>,
+        "HeightField" => HeightField<Point2<N>>,
+        "SdfGrid" => SdfGrid<Point2<N>>,
+    },
+    support_maps: {
+        "Ball" => Ball<N>,
+        "Cuboid" => Cuboid<Vector2<N>>,
+        "Cylinder" => Cylinder<N>,
+        "Cone" => Cone<N>,
+        "Capsule" => Capsule<N>,
+        "ConvexHull" => ConvexHull<Point2<N>>,
+        "ConvexPolygon" => ConvexPolygon<N>,
+        "Segment" => Segment<Point2<N>>,
+        "Triangle" => Triangle<Point2<N>>,
+        "Ellipsoid" => Ellipsoid<Vector2<N>>,
+    }
+);
+
+impl_serde_for_shape_handle!(Point3, Vector3,
+    shapes: {
+        "Ball" => Ball<N>,
+        "Cuboid" => Cuboid<Vector3<N>>,
+        "Cylinder" => Cylinder<N>,
+        "Cone" => Cone<N>,
+        "Capsule" => Capsule<N>,
+        "ConvexHull" => ConvexHull<Point3<N>>,
+        "ConvexPolyhedron" => ConvexPolyhedron<N>,
+        "Segment" => Segment<Point3<N>>,
+        "Triangle" => Triangle<Point3<N>>,
+        "Tetrahedron" => Tetrahedron<Point3<N>>,
+        "Plane" => Plane<Vector3<N>>,
+        "Ellipsoid" => Ellipsoid<Vector3<N>>,
+        "Torus" => Torus<N>,
+        "Compound" => Compound<Point3<N>, M>,
+        "DynamicCompound" => DynamicCompound<Point3<N>, M>,
+        "TriMesh" => TriMesh<Point3<N>>,
+        "Polyline" => Polyline<Point3<N>>,
+        "TetMesh" => TetMesh<Point3<N>>,
+        "HeightField" => HeightField<Point3<N>>,
+        "SdfGrid" => SdfGrid<Point3<N>>,
+    },
+    support_maps: {
+        "Ball" => Ball<N>,
+        "Cuboid" => Cuboid<Vector3<N>>,
+        "Cylinder" => Cylinder<N>,
+        "Cone" => Cone<N>,
+        "Capsule" => Capsule<N>,
+        "ConvexHull" => ConvexHull<Point3<N>>,
+        "ConvexPolyhedron" => ConvexPolyhedron<N>,
+        "Segment" => Segment<Point3<N>>,
+        "Triangle" => Triangle<Point3<N>>,
+        "Tetrahedron" => Tetrahedron<Point3<N>>,
+        "Ellipsoid" => Ellipsoid<Vector3<N>>,
+    }
+);
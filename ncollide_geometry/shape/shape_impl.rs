@@ -1,9 +1,24 @@
-use bounding_volume::{self, BoundingSphere, AABB};
+use alga::general::Real;
+use na::{self, Point2, Point3};
+
+use bounding_volume::{self, BoundingSphere, HasBoundingVolume, AABB};
+use mass_properties::MassProperties;
 use query::{PointQuery, RayCast};
-use shape::{Ball, CompositeShape, Compound, Cone, ConvexHull, Cuboid, Cylinder, Plane, Polyline,
-            Segment, Shape, SupportMap, TriMesh, Triangle};
+use shape::{Ball, Capsule, CompositeShape, Compound, Cone, ConvexHull, ConvexPolygon,
+            ConvexPolyhedron, Cuboid, Cylinder, DynamicCompound, Ellipsoid, HeightField, Plane,
+            Polyline, RoundShape, Scaled, ScaledCompoundPart, SdfGrid, Segment, Shape,
+            SupportMap, TetMesh, Tetrahedron, Torus, TriMesh, Triangle};
 use math::{Isometry, Point};
 
+macro_rules! impl_type_name(
+    ($name: expr) => {
+        #[inline]
+        fn type_name(&self) -> &'static str {
+            $name
+        }
+    }
+);
+
 macro_rules! impl_as_support_map(
     () => {
         #[inline]
@@ -32,6 +47,15 @@ macro_rules! impl_as_composite_shape(
     }
 );
 
+macro_rules! impl_as_mass_properties(
+    () => {
+        #[inline]
+        fn as_mass_properties(&self) -> Option<&MassProperties<P>> {
+            Some(self)
+        }
+    }
+);
+
 macro_rules! impl_shape_common(
     () => {
         #[inline]
@@ -56,56 +80,311 @@ macro_rules! impl_shape_common(
     }
 );
 
+// The capabilities of a scaled shape depend on the capabilities of the shape it scales.
+macro_rules! impl_as_scaled_shape(
+    ($inner: ident) => {
+        #[inline]
+        fn aabb(&self, m: &M) -> AABB<P> {
+            bounding_volume::aabb(self, m)
+        }
+
+        #[inline]
+        fn bounding_sphere(&self, m: &M) -> BoundingSphere<P> {
+            bounding_volume::bounding_sphere(self, m)
+        }
+
+        #[inline]
+        fn as_ray_cast(&self) -> Option<&RayCast<P, M>> {
+            if self.$inner().as_ray_cast().is_some() {
+                Some(self)
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn as_point_query(&self) -> Option<&PointQuery<P, M>> {
+            let inner: &Shape<P, M> = self.$inner();
+
+            if inner.is_support_map() || inner.is_composite_shape() {
+                Some(self)
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn as_support_map(&self) -> Option<&SupportMap<P, M>> {
+            if self.$inner().is_support_map() {
+                Some(self)
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn as_composite_shape(&self) -> Option<&CompositeShape<P, M>> {
+            if self.$inner().is_composite_shape() {
+                Some(self)
+            } else {
+                None
+            }
+        }
+    }
+);
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Triangle<P> {
+    impl_type_name!("Triangle");
     impl_shape_common!();
     impl_as_support_map!();
+    impl_as_mass_properties!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Segment<P> {
+    impl_type_name!("Segment");
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Tetrahedron<P> {
+    impl_type_name!("Tetrahedron");
     impl_shape_common!();
     impl_as_support_map!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Ball<P::Real> {
+    impl_type_name!("Ball");
     impl_shape_common!();
     impl_as_support_map!();
+    impl_as_mass_properties!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Cuboid<P::Vector> {
+    impl_type_name!("Cuboid");
     impl_shape_common!();
     impl_as_support_map!();
+    impl_as_mass_properties!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Cylinder<P::Real> {
+    impl_type_name!("Cylinder");
     impl_shape_common!();
     impl_as_support_map!();
+    impl_as_mass_properties!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Cone<P::Real> {
+    impl_type_name!("Cone");
     impl_shape_common!();
     impl_as_support_map!();
+    impl_as_mass_properties!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Capsule<P::Real> {
+    impl_type_name!("Capsule");
+    impl_shape_common!();
+    impl_as_support_map!();
+    impl_as_mass_properties!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for ConvexHull<P> {
+    impl_type_name!("ConvexHull");
     impl_shape_common!();
     impl_as_support_map!();
+    impl_as_mass_properties!();
+}
+
+// The macros cannot be used for shapes of a specific dimension.
+impl<N: Real, M: Isometry<Point3<N>>> Shape<Point3<N>, M> for ConvexPolyhedron<N> {
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        "ConvexPolyhedron"
+    }
+
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<Point3<N>> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<Point3<N>> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&SupportMap<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn is_support_map(&self) -> bool {
+        true
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> Shape<Point2<N>, M> for ConvexPolygon<N> {
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        "ConvexPolygon"
+    }
+
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<Point2<N>> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<Point2<N>> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&SupportMap<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn is_support_map(&self) -> bool {
+        true
+    }
 }
 
 impl<P: Point, M: 'static + Send + Sync + Isometry<P>> Shape<P, M> for Compound<P, M> {
+    impl_type_name!("Compound");
+    impl_shape_common!();
+    impl_as_composite_shape!();
+
+    #[inline]
+    fn as_mass_properties(&self) -> Option<&MassProperties<P>> {
+        if self.has_mass_properties() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: Point, M: 'static + Send + Sync + Isometry<P>> Shape<P, M> for DynamicCompound<P, M> {
+    impl_type_name!("DynamicCompound");
     impl_shape_common!();
     impl_as_composite_shape!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for TriMesh<P> {
+    impl_type_name!("TriMesh");
     impl_shape_common!();
     impl_as_composite_shape!();
+
+    #[inline]
+    fn as_mass_properties(&self) -> Option<&MassProperties<P>> {
+        if na::dimension::<P::Vector>() == 3 && self.is_closed() {
+            Some(self)
+        } else {
+            None
+        }
+    }
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Polyline<P> {
+    impl_type_name!("Polyline");
+    impl_shape_common!();
+    impl_as_composite_shape!();
+
+    #[inline]
+    fn as_mass_properties(&self) -> Option<&MassProperties<P>> {
+        if na::dimension::<P::Vector>() == 2 && self.is_closed() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for TetMesh<P> {
+    impl_type_name!("TetMesh");
+    impl_shape_common!();
+    impl_as_composite_shape!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for HeightField<P> {
+    impl_type_name!("HeightField");
     impl_shape_common!();
     impl_as_composite_shape!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for SdfGrid<P> {
+    impl_type_name!("SdfGrid");
+    impl_shape_common!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Plane<P::Vector> {
+    impl_type_name!("Plane");
     impl_shape_common!();
 }
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Ellipsoid<P::Vector> {
+    impl_type_name!("Ellipsoid");
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Torus<P::Real> {
+    impl_type_name!("Torus");
+    impl_shape_common!();
+}
+
+impl<P, M, S> Shape<P, M> for RoundShape<P::Real, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>
+        + HasBoundingVolume<M, AABB<P>>
+        + HasBoundingVolume<M, BoundingSphere<P>>
+        + Send
+        + Sync
+        + 'static,
+{
+    impl_type_name!("RoundShape");
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    #[inline]
+    fn as_round_shape(&self) -> Option<(&SupportMap<P, M>, P::Real)> {
+        Some((self.inner(), self.radius()))
+    }
+}
+
+impl<P, M, S> Shape<P, M> for Scaled<P::Vector, S>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M>,
+{
+    impl_type_name!("Scaled");
+    impl_as_scaled_shape!(inner);
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for ScaledCompoundPart<P, M> {
+    impl_type_name!("ScaledCompoundPart");
+    impl_as_scaled_shape!(shape);
+}
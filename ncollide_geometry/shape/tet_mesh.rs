@@ -0,0 +1,163 @@
+//! 3d tetrahedral mesh.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use na::{self, Point4};
+use partitioning::{BVTCostFn, BVTVisitor, BVT};
+use bounding_volume::AABB;
+use shape::{BaseMesh, CompositeShape, Shape, Tetrahedron};
+use math::{Isometry, Point};
+
+/// Shape commonly known as a 3d tetrahedral mesh.
+///
+/// Unlike a `TriMesh`, a tetrahedral mesh is a volume: point queries and ray casts consider the
+/// interior of its tetrahedra as solid.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct TetMesh<P: Point> {
+    #[cfg_attr(feature = "serde-serialize",
+               serde(bound(serialize = "BaseMesh<P, Point4<usize>, Tetrahedron<P>>: ::serde::Serialize",
+                           deserialize = "BaseMesh<P, Point4<usize>, Tetrahedron<P>>: ::serde::Deserialize<'de>")))]
+    mesh: BaseMesh<P, Point4<usize>, Tetrahedron<P>>,
+    boundary: Arc<Vec<[bool; 4]>>,
+}
+
+impl<P: Point> Clone for TetMesh<P> {
+    fn clone(&self) -> TetMesh<P> {
+        TetMesh {
+            mesh: self.mesh.clone(),
+            boundary: self.boundary.clone(),
+        }
+    }
+}
+
+impl<P: Point> TetMesh<P> {
+    /// Builds a new tetrahedral mesh.
+    ///
+    /// Each element of `indices` identifies the four vertices of a tetrahedron. The faces of the
+    /// tetrahedra that are not shared by two tetrahedra form the boundary of the mesh.
+    pub fn new(vertices: Arc<Vec<P>>, indices: Arc<Vec<Point4<usize>>>) -> TetMesh<P> {
+        assert!(
+            na::dimension::<P::Vector>() == 3,
+            "Tetrahedral meshes are only supported in 3D."
+        );
+
+        let mut face_count = HashMap::new();
+
+        for idx in indices.iter() {
+            for k in 0..4 {
+                *face_count.entry(sorted_face(idx, k)).or_insert(0usize) += 1;
+            }
+        }
+
+        let boundary = indices
+            .iter()
+            .map(|idx| {
+                let mut faces = [false; 4];
+
+                for k in 0..4 {
+                    faces[k] = face_count[&sorted_face(idx, k)] == 1;
+                }
+
+                faces
+            })
+            .collect();
+
+        TetMesh {
+            mesh: BaseMesh::new(vertices, indices, None, None),
+            boundary: Arc::new(boundary),
+        }
+    }
+
+    /// The base representation of this mesh.
+    #[inline]
+    pub fn base_mesh(&self) -> &BaseMesh<P, Point4<usize>, Tetrahedron<P>> {
+        &self.mesh
+    }
+
+    /// The vertices of this mesh.
+    #[inline]
+    pub fn vertices(&self) -> &Arc<Vec<P>> {
+        self.mesh.vertices()
+    }
+
+    /// Bounding volumes of the tetrahedra.
+    #[inline]
+    pub fn bounding_volumes(&self) -> &[AABB<P>] {
+        self.mesh.bounding_volumes()
+    }
+
+    /// The indices of this mesh.
+    #[inline]
+    pub fn indices(&self) -> &Arc<Vec<Point4<usize>>> {
+        self.mesh.indices()
+    }
+
+    /// For each tetrahedron, whether each of its faces lies on the boundary of this mesh.
+    ///
+    /// The faces are ordered as in `Tetrahedron::face`.
+    #[inline]
+    pub fn boundary_faces(&self) -> &[[bool; 4]] {
+        &self.boundary[..]
+    }
+
+    /// The acceleration structure used for efficient collision detection and ray casting.
+    #[inline]
+    pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
+        self.mesh.bvt()
+    }
+
+    /// Gets the i-th mesh element.
+    #[inline]
+    pub fn tetrahedron_at(&self, i: usize) -> Tetrahedron<P> {
+        self.mesh.element_at(i)
+    }
+}
+
+// The sorted vertex indices of the `k`-th face of a tetrahedron, in the same order as
+// `Tetrahedron::face`.
+fn sorted_face(idx: &Point4<usize>, k: usize) -> [usize; 3] {
+    let mut face = match k {
+        0 => [idx.x, idx.y, idx.z],
+        1 => [idx.x, idx.y, idx.w],
+        2 => [idx.x, idx.z, idx.w],
+        _ => [idx.y, idx.z, idx.w],
+    };
+
+    face.sort();
+    face
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for TetMesh<P> {
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline(always)]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let element = self.tetrahedron_at(i);
+
+        f(m, &element)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        self.bounding_volumes()[i].clone()
+    }
+
+    #[inline]
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.bvt().visit(visitor)
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        self.bvt().best_first_search(cost_fn).map(|(part, _)| *part)
+    }
+}
@@ -1,12 +1,16 @@
 //! Definition of the tetrahedron shape.
 
 use std::mem;
-use na;
-use shape::{Segment, Triangle};
-use math::Point;
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Point4};
+use shape::{BaseMeshElement, Segment, SupportMap, Triangle};
+use utils;
+use math::{Isometry, Point};
 
 /// A tetrahedron with 4 vertices.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Tetrahedron<P> {
     a: P,
     b: P,
@@ -86,4 +90,57 @@ impl<P: Point> Tetrahedron<P> {
             _ => panic!("Tetrahedron edge index out of bounds (must be < 6)."),
         }
     }
+
+    /// Computes the barycentric coordinates of the given point wrt. the vertices of this
+    /// tetrahedron.
+    ///
+    /// The point is expressed in the local space of this tetrahedron and is inside of it iff. all
+    /// its barycentric coordinates are non-negative. Returns `None` if this tetrahedron is
+    /// degenerate. This is only supported in 3D.
+    pub fn barycentric_coordinates(&self, p: &P) -> Option<[P::Real; 4]> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ad = self.d - self.a;
+        let ap = *p - self.a;
+
+        let det = na::dot(&ab, &utils::cross3(&ac, &ad));
+
+        if det.abs() <= P::Real::default_epsilon() {
+            return None;
+        }
+
+        let b = na::dot(&ap, &utils::cross3(&ac, &ad)) / det;
+        let c = na::dot(&ab, &utils::cross3(&ap, &ad)) / det;
+        let d = na::dot(&ab, &utils::cross3(&ac, &ap)) / det;
+        let a = na::one::<P::Real>() - b - c - d;
+
+        Some([a, b, c, d])
+    }
+}
+
+impl<P: Point> BaseMeshElement<Point4<usize>, P> for Tetrahedron<P> {
+    #[inline]
+    fn new_with_vertices_and_indices(vs: &[P], is: &Point4<usize>) -> Tetrahedron<P> {
+        Tetrahedron::new(vs[is.x], vs[is.y], vs[is.z], vs[is.w])
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Tetrahedron<P> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let mut res = &self.a;
+        let mut best = na::dot(&self.a.coordinates(), &local_dir);
+
+        for pt in [&self.b, &self.c, &self.d].iter() {
+            let dot = na::dot(&pt.coordinates(), &local_dir);
+
+            if dot > best {
+                res = *pt;
+                best = dot;
+            }
+        }
+
+        m.transform_point(res)
+    }
 }
@@ -1,7 +1,14 @@
 use alga::general::Real;
+use na;
+use shape::Segment;
+use math::Point;
 
-/// A torus.
+/// A torus with its principal axis aligned with the `y` axis.
+///
+/// The torus is the set of points at a distance smaller than `minor_radius` from its core circle:
+/// the circle of radius `major_radius` centered at the origin and orthogonal to the `y` axis.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Torus<N> {
     major_radius: N,
     minor_radius: N,
@@ -30,4 +37,90 @@ impl<N: Real> Torus<N> {
     pub fn major_radius(&self) -> N {
         self.major_radius
     }
+
+    /// The `i`-th segment of the polygon approximating the core circle of this torus.
+    ///
+    /// The segment is expressed in the local space of the torus. In 2D, the core circle degenerates
+    /// to the two points `(-major_radius, 0)` and `(major_radius, 0)` so only two degenerate
+    /// segments exist.
+    pub fn core_segment<P: Point<Real = N>>(&self, i: usize) -> Segment<P> {
+        let mut a = P::origin();
+        let mut b = P::origin();
+
+        if na::dimension::<P::Vector>() == 2 {
+            assert!(i < 2, "Torus core segment index out of bounds.");
+            let x = if i == 0 {
+                self.major_radius
+            } else {
+                -self.major_radius
+            };
+
+            a[0] = x;
+            b[0] = x;
+        } else {
+            let nsubdivs = self.core_subdivisions();
+            assert!(i < nsubdivs, "Torus core segment index out of bounds.");
+
+            let dtheta = N::two_pi() / na::convert(nsubdivs as f64);
+            let theta_a = dtheta * na::convert(i as f64);
+            let theta_b = dtheta * na::convert((i + 1) as f64);
+
+            a[0] = theta_a.cos() * self.major_radius;
+            a[2] = theta_a.sin() * self.major_radius;
+            b[0] = theta_b.cos() * self.major_radius;
+            b[2] = theta_b.sin() * self.major_radius;
+        }
+
+        Segment::new(a, b)
+    }
+
+    /// The number of segments returned by `self.core_segment(...)` for the dimension of `P`.
+    ///
+    /// In 3D, the subdivision is chosen such that the polygonal approximation of the core circle
+    /// deviates from it by less than 1% of the minor radius.
+    #[inline]
+    pub fn num_core_segments<P: Point<Real = N>>(&self) -> usize {
+        if na::dimension::<P::Vector>() == 2 {
+            2
+        } else {
+            self.core_subdivisions()
+        }
+    }
+
+    /// The point of the core circle closest to `pt`, expressed in the local space of the torus.
+    ///
+    /// If `pt` lies on the principal axis, all the points of the core circle are equally close so
+    /// an arbitrary one is returned.
+    pub fn core_circle_point<P: Point<Real = N>>(&self, pt: &P) -> P {
+        let mut radial = pt.coordinates();
+        radial[1] = na::zero();
+
+        let mut res = P::origin();
+
+        match na::try_normalize(&radial, N::default_epsilon()) {
+            Some(dir) => res += dir * self.major_radius,
+            None => res[0] = self.major_radius,
+        }
+
+        res
+    }
+
+    fn core_subdivisions(&self) -> usize {
+        let mut tolerance = self.minor_radius / self.major_radius * na::convert(0.01f64);
+
+        if tolerance > na::one() {
+            tolerance = na::one();
+        }
+
+        let max_angle = (N::one() - tolerance).acos();
+        let nsubdivs: f64 = na::try_convert((N::pi() / max_angle).ceil()).unwrap_or(256.0);
+
+        if nsubdivs > 256.0 {
+            256
+        } else if nsubdivs < 8.0 {
+            8
+        } else {
+            nsubdivs as usize
+        }
+    }
 }
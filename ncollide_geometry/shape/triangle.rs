@@ -8,6 +8,7 @@ use utils;
 
 /// A triangle shape.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Triangle<P> {
     a: P,
     b: P,
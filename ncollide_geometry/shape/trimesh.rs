@@ -1,15 +1,25 @@
 //! 2d line strip, 3d triangle mesh, and nd subsimplex mesh.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use approx::ApproxEq;
+use num::Zero;
+
+use alga::general::Real;
 use na::{self, Point2, Point3};
-use partitioning::BVT;
+use partitioning::{BVTCostFn, BVTVisitor, BVT};
 use bounding_volume::AABB;
-use shape::{BaseMesh, CompositeShape, Shape, Triangle};
+use shape::{BaseMesh, CompositeShape, MeshPseudoNormals, Shape, Triangle};
+use utils;
 use math::{Isometry, Point};
 
 /// Shape commonly known as a 2d line strip or a 3d triangle mesh.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct TriMesh<P: Point> {
+    #[cfg_attr(feature = "serde-serialize",
+               serde(bound(serialize = "BaseMesh<P, Point3<usize>, Triangle<P>>: ::serde::Serialize",
+                           deserialize = "BaseMesh<P, Point3<usize>, Triangle<P>>: ::serde::Deserialize<'de>")))]
     mesh: BaseMesh<P, Point3<usize>, Triangle<P>>,
 }
 
@@ -75,6 +85,140 @@ impl<P: Point> TriMesh<P> {
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
         self.mesh.bvt()
     }
+
+    /// Replaces the vertices of this mesh without modifying its topology.
+    ///
+    /// The new vertex buffer must have the same length as the current one. The bounding volume
+    /// tree is refitted, the normals are discarded, and the pseudo-normals are recomputed if this
+    /// mesh is closed.
+    pub fn set_vertices(&mut self, vertices: Arc<Vec<P>>) {
+        let closed = self.is_closed();
+        self.mesh.set_vertices(vertices);
+
+        if closed {
+            let _ = self.mark_as_closed();
+        }
+    }
+
+    /// Sets the per-vertex normals of this mesh.
+    ///
+    /// If not `None`, there must be exactly one normal per vertex.
+    pub fn set_normals(&mut self, normals: Option<Arc<Vec<P::Vector>>>) {
+        self.mesh.set_normals(normals)
+    }
+
+    /// Modifies the vertices of this mesh in-place without modifying its topology.
+    ///
+    /// The vertex buffer is cloned first if it is shared. The bounding volume tree is refitted,
+    /// the normals are discarded, and the pseudo-normals are recomputed if this mesh is closed.
+    pub fn update_vertices<F: FnOnce(&mut [P])>(&mut self, f: F) {
+        let closed = self.is_closed();
+        self.mesh.update_vertices(f);
+
+        if closed {
+            let _ = self.mark_as_closed();
+        }
+    }
+
+    /// Sets the degradation of the bounding volume tree that triggers its rebuild after a vertex
+    /// update.
+    ///
+    /// See `BaseMesh::set_bvt_rebuild_threshold` for details.
+    #[inline]
+    pub fn set_bvt_rebuild_threshold(&mut self, threshold: Option<P::Real>) {
+        self.mesh.set_bvt_rebuild_threshold(threshold)
+    }
+
+    /// Rebuilds the bounding volume tree of this mesh from scratch.
+    #[inline]
+    pub fn rebuild_bvt(&mut self) {
+        self.mesh.rebuild_bvt()
+    }
+
+    /// Whether this mesh has been marked as the closed boundary of a solid.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.mesh.is_closed()
+    }
+
+    /// Marks this mesh as the closed boundary of a solid.
+    ///
+    /// This computes the pseudo-normals that allow point queries and solid ray casts to detect
+    /// points inside of the mesh. Returns `false` and leaves this mesh unchanged if it is not a
+    /// 3D mesh, or if it is not watertight with consistently oriented triangles.
+    pub fn mark_as_closed(&mut self) -> bool {
+        if na::dimension::<P::Vector>() != 3 {
+            return false;
+        }
+
+        let pseudo_normals = {
+            let vs = &self.mesh.vertices()[..];
+            let is = &self.mesh.indices()[..];
+            let mut adjacent_tris = HashMap::new();
+
+            for (i, idx) in is.iter().enumerate() {
+                for k in 0..3 {
+                    if adjacent_tris.insert((idx[k], idx[(k + 1) % 3]), i).is_some() {
+                        return false;
+                    }
+                }
+            }
+
+            let mut faces = Vec::with_capacity(is.len());
+            let mut vertices = vec![na::zero::<P::Vector>(); vs.len()];
+            let mut volume = na::zero::<P::Real>();
+
+            for idx in is.iter() {
+                let (a, b, c) = (vs[idx[0]], vs[idx[1]], vs[idx[2]]);
+                let normal = utils::cross3(&(b - a), &(c - a));
+                let normal = na::try_normalize(&normal, P::Real::default_epsilon())
+                    .unwrap_or(na::zero());
+
+                let bc = utils::cross3(&b.coordinates(), &c.coordinates());
+                volume += na::dot(&a.coordinates(), &bc);
+
+                for k in 0..3 {
+                    let p = vs[idx[k]];
+                    let e1 = vs[idx[(k + 1) % 3]] - p;
+                    let e2 = vs[idx[(k + 2) % 3]] - p;
+                    let denom = na::norm(&e1) * na::norm(&e2);
+
+                    if !denom.is_zero() {
+                        let cos = na::dot(&e1, &e2) / denom;
+                        let _1: P::Real = na::one();
+                        let angle = cos.max(-_1).min(_1).acos();
+                        vertices[idx[k]] += normal * angle;
+                    }
+                }
+
+                faces.push(normal);
+            }
+
+            let mut edges = Vec::with_capacity(is.len() * 3);
+
+            for (i, idx) in is.iter().enumerate() {
+                for k in 0..3 {
+                    match adjacent_tris.get(&(idx[(k + 1) % 3], idx[k])) {
+                        Some(j) => edges.push(faces[i] + faces[*j]),
+                        None => return false,
+                    }
+                }
+            }
+
+            // Make the normals point outward if the triangles are clockwise.
+            if volume < na::zero() {
+                for n in faces.iter_mut().chain(edges.iter_mut()).chain(vertices.iter_mut()) {
+                    *n = -*n;
+                }
+            }
+
+            MeshPseudoNormals::new(faces, edges, vertices)
+        };
+
+        self.mesh.set_pseudo_normals(pseudo_normals);
+
+        true
+    }
 }
 
 impl<P: Point> TriMesh<P> {
@@ -106,7 +250,15 @@ impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for TriMesh<P> {
     }
 
     #[inline]
-    fn bvt(&self) -> &BVT<usize, AABB<P>> {
-        self.bvt()
+    fn visit_parts(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.bvt().visit(visitor)
+    }
+
+    #[inline]
+    fn best_first_search_part(
+        &self,
+        cost_fn: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        self.bvt().best_first_search(cost_fn).map(|(part, _)| *part)
     }
 }
@@ -1,6 +1,6 @@
 use std::any::Any;
 
-use geometry::query::Ray;
+use geometry::query::{Ray, RayIntersection};
 use math::Point;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -54,6 +54,45 @@ pub trait BroadPhase<P: Point, BV, T>: Any + Sync + Send {
     /// Collects every object which might intersect a given ray.
     fn interferences_with_ray<'a>(&'a self, ray: &Ray<P>, out: &mut Vec<&'a T>);
 
+    /// Collects every object which might intersect a given ray with a time of impact smaller than
+    /// `max_toi`.
+    ///
+    /// The default implementation ignores `max_toi` and collects every object which might
+    /// intersect the ray.
+    fn interferences_with_bounded_ray<'a>(
+        &'a self,
+        ray: &Ray<P>,
+        _max_toi: P::Real,
+        out: &mut Vec<&'a T>,
+    ) {
+        self.interferences_with_ray(ray, out)
+    }
+
+    /// Finds the object with the smallest time of impact with a given ray.
+    ///
+    /// The objects which might intersect the ray are given to `cast` which computes their actual
+    /// intersection with the ray, or returns `None` to ignore them. The default implementation
+    /// calls `cast` on every object which might intersect the ray.
+    fn first_interference_with_ray<'a>(
+        &'a self,
+        ray: &Ray<P>,
+        cast: &mut FnMut(&T) -> Option<RayIntersection<P::Vector>>,
+    ) -> Option<(&'a T, RayIntersection<P::Vector>)> {
+        let mut candidates = Vec::new();
+        let mut res: Option<(&'a T, RayIntersection<P::Vector>)> = None;
+        self.interferences_with_ray(ray, &mut candidates);
+
+        for data in candidates {
+            if let Some(inter) = cast(data) {
+                if res.as_ref().map_or(true, |best| inter.toi < best.1.toi) {
+                    res = Some((data, inter))
+                }
+            }
+        }
+
+        res
+    }
+
     /// Collects every object which might contain a given point.
     fn interferences_with_point<'a>(&'a self, point: &P, out: &mut Vec<&'a T>);
 }
@@ -7,9 +7,9 @@ use alga::general::Id;
 use math::Point;
 use utils::data::SortedPair;
 use geometry::bounding_volume::{BoundingVolume, BoundingVolumeInterferencesCollector};
-use geometry::partitioning::{DBVT, DBVTLeaf, DBVTLeafId};
+use geometry::partitioning::{BVTCostFn, DBVT, DBVTLeaf, DBVTLeafId};
 use geometry::query::{PointInterferencesCollector, PointQuery, Ray, RayCast,
-                      RayInterferencesCollector};
+                      RayInterferencesCollector, RayIntersection};
 use broad_phase::{BroadPhase, ProxyHandle};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -339,25 +339,24 @@ where
     }
 
     fn deferred_recompute_all_proximities(&mut self) {
-        unimplemented!()
-        /*
-        for (proxy_key, proxy) in self.proxies.iter() {
-            let bv;
-            match proxy.status() {
-                ProxyStatus::OnStaticTree  => { bv = &self.stree[proxy.leaf].bounding_volume; }
-                ProxyStatus::OnDynamicTree => { bv = &self.tree[proxy.leaf].bounding_volume; }
-                ProxyStatus::Detached      => continue
-            }
+        let mut proxies_to_update = Vec::with_capacity(self.proxies.len());
 
-            match self.actions.entry(proxy_key) {
-                Entry::Vacant(entry) => {
-                    // FIXME: too bad we have to clone the bounding volume…
-                    let _  = entry.insert(Action::Modify(bv.clone(), None));
-                },
-                Entry::Occupied(_) => { }
-            }
+        for (uid, proxy) in self.proxies.iter() {
+            let bv = match proxy.status {
+                ProxyStatus::OnStaticTree(leaf) => &self.stree[leaf].bounding_volume,
+                ProxyStatus::OnDynamicTree(leaf, _) => &self.tree[leaf].bounding_volume,
+                ProxyStatus::Detached(_) | ProxyStatus::Deleted => continue,
+            };
+
+            proxies_to_update.push((ProxyHandle(uid), bv.clone()));
         }
-        */
+
+        // The pending updates come last so that their bounding volumes override the current ones.
+        proxies_to_update.extend(self.proxies_to_update.drain(..));
+        self.proxies_to_update = proxies_to_update;
+
+        // Every proximity will be reported again when the proxies are re-inserted.
+        self.pairs.clear();
     }
 
     fn interferences_with_bounding_volume<'a>(&'a self, bv: &BV, out: &mut Vec<&'a T>) {
@@ -390,6 +389,52 @@ where
         }
     }
 
+    fn interferences_with_bounded_ray<'a>(
+        &'a self,
+        ray: &Ray<P>,
+        max_toi: P::Real,
+        out: &mut Vec<&'a T>,
+    ) {
+        let mut collector = Vec::new();
+
+        {
+            let mut visitor =
+                RayInterferencesCollector::new_with_max_toi(ray, max_toi, &mut collector);
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        for l in collector.into_iter() {
+            out.push(&self.proxies[l.uid()].data)
+        }
+    }
+
+    fn first_interference_with_ray<'a>(
+        &'a self,
+        ray: &Ray<P>,
+        cast: &mut FnMut(&T) -> Option<RayIntersection<P::Vector>>,
+    ) -> Option<(&'a T, RayIntersection<P::Vector>)> {
+        let mut cost_fn = FirstRayInterferenceCostFn {
+            proxies: &self.proxies,
+            ray: ray,
+            max_toi: None,
+            cast: cast,
+        };
+
+        let first = self.tree.best_first_search(&mut cost_fn);
+
+        // The static tree only needs to be searched up to the first hit on the dynamic one.
+        if let Some((_, ref inter)) = first {
+            cost_fn.max_toi = Some(inter.toi);
+        }
+
+        match self.stree.best_first_search(&mut cost_fn) {
+            Some((handle, inter)) => Some((&self.proxies[handle.uid()].data, inter)),
+            None => first.map(|(handle, inter)| (&self.proxies[handle.uid()].data, inter)),
+        }
+    }
+
     fn interferences_with_point<'a>(&'a self, point: &P, out: &mut Vec<&'a T>) {
         let mut collector = Vec::new();
 
@@ -405,3 +450,44 @@ where
         }
     }
 }
+
+struct FirstRayInterferenceCostFn<'a, 'b, P: 'a + Point, T: 'a> {
+    proxies: &'a Slab<DBVTBroadPhaseProxy<T>>,
+    ray: &'a Ray<P>,
+    max_toi: Option<P::Real>,
+    cast: &'b mut FnMut(&T) -> Option<RayIntersection<P::Vector>>,
+}
+
+impl<'a, 'b, P, BV, T> BVTCostFn<P::Real, ProxyHandle, BV>
+    for FirstRayInterferenceCostFn<'a, 'b, P, T>
+where
+    P: Point,
+    BV: RayCast<P, Id>,
+{
+    type UserData = RayIntersection<P::Vector>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &BV) -> Option<P::Real> {
+        match self.max_toi {
+            Some(max_toi) => bv.toi_with_bounded_ray(&Id::new(), self.ray, max_toi, true),
+            None => bv.toi_with_ray(&Id::new(), self.ray, true),
+        }
+    }
+
+    #[inline]
+    fn compute_b_cost(
+        &mut self,
+        b: &ProxyHandle,
+    ) -> Option<(P::Real, RayIntersection<P::Vector>)> {
+        match (self.cast)(&self.proxies[b.uid()].data) {
+            Some(inter) => {
+                if self.max_toi.map_or(true, |max_toi| inter.toi <= max_toi) {
+                    Some((inter.toi, inter))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        }
+    }
+}
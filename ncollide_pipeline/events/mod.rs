@@ -2,7 +2,7 @@
 
 use std::slice::Iter;
 use std::iter::IntoIterator;
-use geometry::query::Proximity;
+use geometry::query::{Proximity, Unsupported};
 use world::CollisionObjectHandle;
 
 // FIXME: we want a structure where we can add elements, iterate on them, but not remove them
@@ -16,6 +16,8 @@ pub struct EventPool<E> {
 pub type ContactEvents = EventPool<ContactEvent>;
 /// A set of proximity events.
 pub type ProximityEvents = EventPool<ProximityEvent>;
+/// A set of unsupported pair events.
+pub type UnsupportedPairEvents = EventPool<UnsupportedPairEvent>;
 
 impl<E> EventPool<E> {
     /// Creates a new empty set of events.
@@ -101,3 +103,31 @@ impl ProximityEvent {
         }
     }
 }
+
+#[derive(Copy, Clone, Debug)]
+/// Event occuring when the narrow phase does not know how to handle a pair of collision objects.
+///
+/// No contact or proximity is computed for this pair while it remains unsupported.
+pub struct UnsupportedPairEvent {
+    /// The first collider of the unsupported pair.
+    pub collider1: CollisionObjectHandle,
+    /// The second collider of the unsupported pair.
+    pub collider2: CollisionObjectHandle,
+    /// The error naming the types of the shapes of both collision objects.
+    pub error: Unsupported,
+}
+
+impl UnsupportedPairEvent {
+    /// Instanciates a new unsupported pair event.
+    pub fn new(
+        collider1: CollisionObjectHandle,
+        collider2: CollisionObjectHandle,
+        error: Unsupported,
+    ) -> UnsupportedPairEvent {
+        UnsupportedPairEvent {
+            collider1,
+            collider2,
+            error,
+        }
+    }
+}
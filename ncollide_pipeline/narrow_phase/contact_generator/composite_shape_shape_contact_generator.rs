@@ -44,7 +44,7 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
         {
             let mut visitor =
                 BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
-            g1.bvt().visit(&mut visitor);
+            g1.visit_parts(&mut visitor);
         }
 
         for i in self.interferences.iter() {
@@ -66,29 +66,50 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
         self.interferences.clear();
 
         // Update all collisions
+        let to_delete = &mut self.to_delete;
+
         for detector in self.sub_detectors.elements_mut().iter_mut() {
             let key = detector.key;
-            if ls_aabb2.intersects(&g1.aabb_at(key)) {
+
+            if !g1.contains_part(key) {
+                // The part has been removed from the composite shape.
+                to_delete.push(key);
+            } else if ls_aabb2.intersects(&g1.aabb_at(key)) {
                 g1.map_transformed_part_at(key, m1, &mut |m1, g1| {
-                    if swap {
-                        assert!(
-                            detector
-                                .value
-                                .update(dispatcher, m2, g2, m1, g1, prediction),
-                            "Internal error: the shape was no longer valid."
-                        );
+                    let valid = if swap {
+                        detector
+                            .value
+                            .update(dispatcher, m2, g2, m1, g1, prediction)
                     } else {
-                        assert!(
-                            detector
-                                .value
-                                .update(dispatcher, m1, g1, m2, g2, prediction),
-                            "Internal error: the shape was no longer valid."
-                        );
+                        detector
+                            .value
+                            .update(dispatcher, m1, g1, m2, g2, prediction)
+                    };
+
+                    // The part has been replaced by a shape this algorithm cannot handle.
+                    if !valid {
+                        let new_detector = if swap {
+                            dispatcher.get_contact_algorithm(g2, g1)
+                        } else {
+                            dispatcher.get_contact_algorithm(g1, g2)
+                        };
+
+                        match new_detector {
+                            Some(mut new_detector) => {
+                                let _ = if swap {
+                                    new_detector.update(dispatcher, m2, g2, m1, g1, prediction)
+                                } else {
+                                    new_detector.update(dispatcher, m1, g1, m2, g2, prediction)
+                                };
+                                detector.value = new_detector;
+                            }
+                            None => to_delete.push(key),
+                        }
                     }
                 });
             } else {
                 // FIXME: ask the detector if it wants to be removed or not
-                self.to_delete.push(key);
+                to_delete.push(key);
             }
         }
 
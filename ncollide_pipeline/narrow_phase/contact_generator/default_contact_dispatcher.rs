@@ -1,15 +1,22 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 use na;
 use math::{Isometry, Point};
-use geometry::shape::{Ball, Plane, Shape};
+use geometry::shape::{Ball, Plane, SdfGrid, Shape, Torus};
+use geometry::query::QueryDispatcher;
 use geometry::query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use narrow_phase::{BallBallContactGenerator, CompositeShapeShapeContactGenerator,
                    ContactAlgorithm, ContactDispatcher, OneShotContactManifoldGenerator,
-                   PlaneSupportMapContactGenerator, ShapeCompositeShapeContactGenerator,
-                   SupportMapPlaneContactGenerator, SupportMapSupportMapContactGenerator};
+                   PlaneSupportMapContactGenerator, QueryDispatcherContactGenerator,
+                   RoundSupportMapContactGenerator,
+                   SdfGridSupportMapContactGenerator, ShapeCompositeShapeContactGenerator,
+                   SupportMapPlaneContactGenerator, SupportMapSdfGridContactGenerator,
+                   SupportMapSupportMapContactGenerator, SupportMapTorusContactGenerator,
+                   TorusSupportMapContactGenerator};
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
 pub struct DefaultContactDispatcher<P: Point, M> {
+    query_dispatcher: Option<Arc<QueryDispatcher<P, M>>>,
     _point_type: PhantomData<P>,
     _matrix_type: PhantomData<M>,
 }
@@ -18,6 +25,19 @@ impl<P: Point, M> DefaultContactDispatcher<P, M> {
     /// Creates a new basic collision dispatcher.
     pub fn new() -> DefaultContactDispatcher<P, M> {
         DefaultContactDispatcher {
+            query_dispatcher: None,
+            _point_type: PhantomData,
+            _matrix_type: PhantomData,
+        }
+    }
+
+    /// Creates a new basic collision dispatcher that relies on `query_dispatcher` for the pairs of
+    /// shapes it does not handle.
+    pub fn with_query_dispatcher(
+        query_dispatcher: Arc<QueryDispatcher<P, M>>,
+    ) -> DefaultContactDispatcher<P, M> {
+        DefaultContactDispatcher {
+            query_dispatcher: Some(query_dispatcher),
             _point_type: PhantomData,
             _matrix_type: PhantomData,
         }
@@ -53,6 +73,53 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
             } else {
                 Some(Box::new(wo_manifold))
             }
+        } else if a.is_shape::<Torus<P::Real>>() && b.is_support_map() {
+            let wo_manifold = TorusSupportMapContactGenerator::<P, M>::new();
+
+            if !b_is_ball {
+                let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+                Some(Box::new(manifold))
+            } else {
+                Some(Box::new(wo_manifold))
+            }
+        } else if b.is_shape::<Torus<P::Real>>() && a.is_support_map() {
+            let wo_manifold = SupportMapTorusContactGenerator::<P, M>::new();
+
+            if !a_is_ball {
+                let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+                Some(Box::new(manifold))
+            } else {
+                Some(Box::new(wo_manifold))
+            }
+        } else if a.is_shape::<SdfGrid<P>>() && b.is_support_map() {
+            let wo_manifold = SdfGridSupportMapContactGenerator::<P, M>::new();
+
+            if !b_is_ball {
+                let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+                Some(Box::new(manifold))
+            } else {
+                Some(Box::new(wo_manifold))
+            }
+        } else if b.is_shape::<SdfGrid<P>>() && a.is_support_map() {
+            let wo_manifold = SupportMapSdfGridContactGenerator::<P, M>::new();
+
+            if !a_is_ball {
+                let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+                Some(Box::new(manifold))
+            } else {
+                Some(Box::new(wo_manifold))
+            }
+        } else if (a.as_round_shape().is_some() || b.as_round_shape().is_some())
+            && a.is_support_map() && b.is_support_map()
+        {
+            let wo_manifold = RoundSupportMapContactGenerator::<P, M>::new();
+
+            if !a_is_ball && !b_is_ball {
+                let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+                Some(Box::new(manifold))
+            } else {
+                Some(Box::new(wo_manifold))
+            }
         } else if a.is_support_map() && b.is_support_map() {
             match na::dimension::<P::Vector>() {
                 2 => {
@@ -94,6 +161,8 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
             Some(Box::new(CompositeShapeShapeContactGenerator::<P, M>::new()))
         } else if b.is_composite_shape() {
             Some(Box::new(ShapeCompositeShapeContactGenerator::<P, M>::new()))
+        } else if let Some(ref query_dispatcher) = self.query_dispatcher {
+            Some(Box::new(QueryDispatcherContactGenerator::new(query_dispatcher.clone())))
         } else {
             None
         }
@@ -6,10 +6,16 @@ pub use self::ball_ball_contact_generator::BallBallContactGenerator;
 pub use self::plane_support_map_contact_generator::{PlaneSupportMapContactGenerator,
                                                     SupportMapPlaneContactGenerator};
 pub use self::support_map_support_map_contact_generator::SupportMapSupportMapContactGenerator;
+pub use self::torus_support_map_contact_generator::{SupportMapTorusContactGenerator,
+                                                    TorusSupportMapContactGenerator};
+pub use self::sdf_grid_support_map_contact_generator::{SdfGridSupportMapContactGenerator,
+                                                       SupportMapSdfGridContactGenerator};
+pub use self::round_support_map_contact_generator::RoundSupportMapContactGenerator;
 pub use self::incremental_contact_manifold_generator::IncrementalContactManifoldGenerator;
 pub use self::one_shot_contact_manifold_generator::OneShotContactManifoldGenerator;
 pub use self::composite_shape_shape_contact_generator::{CompositeShapeShapeContactGenerator,
                                                         ShapeCompositeShapeContactGenerator};
+pub use self::query_dispatcher_contact_generator::QueryDispatcherContactGenerator;
 
 // FIXME: un-hide this and move everything to a folder.
 #[doc(hidden)]
@@ -18,6 +24,10 @@ mod default_contact_dispatcher;
 mod ball_ball_contact_generator;
 mod plane_support_map_contact_generator;
 mod support_map_support_map_contact_generator;
+mod torus_support_map_contact_generator;
+mod sdf_grid_support_map_contact_generator;
+mod round_support_map_contact_generator;
 mod incremental_contact_manifold_generator;
 mod one_shot_contact_manifold_generator;
 mod composite_shape_shape_contact_generator;
+mod query_dispatcher_contact_generator;
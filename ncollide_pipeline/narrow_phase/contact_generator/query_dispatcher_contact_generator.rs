@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::{Contact, ContactPrediction, QueryDispatcher};
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Contact generator computing one contact point with a `QueryDispatcher`.
+pub struct QueryDispatcherContactGenerator<P: Point, M> {
+    dispatcher: Arc<QueryDispatcher<P, M>>,
+    contact: Option<Contact<P>>,
+}
+
+impl<P: Point, M> QueryDispatcherContactGenerator<P, M> {
+    /// Creates a new contact generator using `dispatcher` to compute contacts.
+    #[inline]
+    pub fn new(dispatcher: Arc<QueryDispatcher<P, M>>) -> QueryDispatcherContactGenerator<P, M> {
+        QueryDispatcherContactGenerator {
+            dispatcher: dispatcher,
+            contact: None,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for QueryDispatcherContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        match self.dispatcher.contact(ma, a, mb, b, prediction.linear) {
+            Ok(contact) => {
+                self.contact = contact;
+                true
+            }
+            Err(_) => {
+                self.contact = None;
+                false
+            }
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
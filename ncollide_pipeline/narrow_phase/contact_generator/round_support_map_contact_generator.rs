@@ -0,0 +1,89 @@
+use std::marker::PhantomData;
+use na;
+use math::{Isometry, Point};
+use geometry::shape::{Shape, SupportMap};
+use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between two shapes implementing the `SupportMap` trait, at least one of
+/// them being a rounded shape.
+///
+/// The contact is computed between the core shapes and offset by their radiuses. This detector
+/// generates only one contact point. For a full manifold generation, see
+/// `IncrementalContactManifoldGenerator`.
+#[derive(Clone)]
+pub struct RoundSupportMapContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> RoundSupportMapContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between two shapes with support mapping
+    /// functions, at least one of them being a rounded shape.
+    #[inline]
+    pub fn new() -> RoundSupportMapContactGenerator<P, M> {
+        RoundSupportMapContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for RoundSupportMapContactGenerator<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some((ca, ra)), Some((cb, rb))) = (round_core(a), round_core(b)) {
+            self.contact = contacts_internal::round_support_map_against_round_support_map(
+                ma,
+                ca,
+                ra,
+                mb,
+                cb,
+                rb,
+                prediction.linear,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
+
+// The core of a rounded shape, or the shape itself with a zero radius if it is a plain support
+// map.
+fn round_core<P, M>(shape: &Shape<P, M>) -> Option<(&SupportMap<P, M>, P::Real)>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match shape.as_round_shape() {
+        Some(core) => Some(core),
+        None => shape.as_support_map().map(|s| (s, na::zero())),
+    }
+}
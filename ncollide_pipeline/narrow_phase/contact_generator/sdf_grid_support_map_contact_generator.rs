@@ -0,0 +1,146 @@
+use std::marker::PhantomData;
+use math::{Isometry, Point};
+use geometry::shape::{SdfGrid, Shape};
+use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between a signed distance field grid and a shape implementing the
+/// `SupportMap` trait.
+///
+/// This detector generates only one contact point. For a full manifold generation, see
+/// `IncrementalContactManifoldGenerator`.
+#[derive(Clone)]
+pub struct SdfGridSupportMapContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> SdfGridSupportMapContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a signed distance field grid and a
+    /// shape with a support mapping function.
+    #[inline]
+    pub fn new() -> SdfGridSupportMapContactGenerator<P, M> {
+        SdfGridSupportMapContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+/// Collision detector between a shape implementing the `SupportMap` trait and a signed distance
+/// field grid.
+///
+/// This detector generates only one contact point. For a full manifold generation, see
+/// `IncrementalContactManifoldGenerator`.
+#[derive(Clone)]
+pub struct SupportMapSdfGridContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> SupportMapSdfGridContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a shape with a support mapping
+    /// function and a signed distance field grid.
+    #[inline]
+    pub fn new() -> SupportMapSdfGridContactGenerator<P, M> {
+        SupportMapSdfGridContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P, M> ContactGenerator<P, M> for SdfGridSupportMapContactGenerator<P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        sdf: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(d), Some(sm)) = (sdf.as_shape::<SdfGrid<P>>(), b.as_support_map()) {
+            self.contact = contacts_internal::sdf_grid_against_support_map(
+                ma,
+                d,
+                mb,
+                sm,
+                prediction.linear,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
+
+impl<P, M> ContactGenerator<P, M> for SupportMapSdfGridContactGenerator<P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        sdf: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(sm), Some(d)) = (a.as_support_map(), sdf.as_shape::<SdfGrid<P>>()) {
+            self.contact = contacts_internal::support_map_against_sdf_grid(
+                ma,
+                sm,
+                mb,
+                d,
+                prediction.linear,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+use math::{Isometry, Point};
+use geometry::shape::{Shape, Torus};
+use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between a torus and a shape implementing the `SupportMap` trait.
+///
+/// This detector generates only one contact point. For a full manifold generation, see
+/// `IncrementalContactManifoldGenerator`.
+#[derive(Clone)]
+pub struct TorusSupportMapContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> TorusSupportMapContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a torus and a shape with a support
+    /// mapping function.
+    #[inline]
+    pub fn new() -> TorusSupportMapContactGenerator<P, M> {
+        TorusSupportMapContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+/// Collision detector between a shape implementing the `SupportMap` trait and a torus.
+///
+/// This detector generates only one contact point. For a full manifold generation, see
+/// `IncrementalContactManifoldGenerator`.
+#[derive(Clone)]
+pub struct SupportMapTorusContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> SupportMapTorusContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a shape with a support mapping
+    /// function and a torus.
+    #[inline]
+    pub fn new() -> SupportMapTorusContactGenerator<P, M> {
+        SupportMapTorusContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for TorusSupportMapContactGenerator<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        torus: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(t), Some(sm)) = (torus.as_shape::<Torus<P::Real>>(), b.as_support_map()) {
+            self.contact =
+                contacts_internal::torus_against_support_map(ma, t, mb, sm, prediction.linear);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for SupportMapTorusContactGenerator<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        torus: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(sm), Some(t)) = (a.as_support_map(), torus.as_shape::<Torus<P::Real>>()) {
+            self.contact =
+                contacts_internal::support_map_against_torus(ma, sm, mb, t, prediction.linear);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
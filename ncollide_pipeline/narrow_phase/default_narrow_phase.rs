@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry;
 
 use utils::data::SortedPair;
-use geometry::query::Proximity;
+use geometry::query::{Proximity, Unsupported};
 use narrow_phase::{ContactAlgorithm, ContactDispatcher, ContactPairs, NarrowPhase,
                    ProximityAlgorithm, ProximityDispatcher, ProximityPairs};
 use world::{CollisionObjectHandle, CollisionObjectSlab, GeometricQueryType};
-use events::{ContactEvent, ContactEvents, ProximityEvent, ProximityEvents};
+use events::{ContactEvent, ContactEvents, ProximityEvent, ProximityEvents, UnsupportedPairEvent,
+             UnsupportedPairEvents};
 use math::Point;
 
 // FIXME: move this to the `narrow_phase` module.
@@ -17,6 +18,9 @@ pub struct DefaultNarrowPhase<P, M> {
 
     proximity_dispatcher: Box<ProximityDispatcher<P, M>>,
     proximity_detectors: HashMap<SortedPair<CollisionObjectHandle>, ProximityAlgorithm<P, M>>,
+
+    // Pairs reported by the broad phase for which no algorithm could be found.
+    unsupported_pairs: HashSet<SortedPair<CollisionObjectHandle>>,
 }
 
 impl<P: Point, M: 'static> DefaultNarrowPhase<P, M> {
@@ -31,6 +35,8 @@ impl<P: Point, M: 'static> DefaultNarrowPhase<P, M> {
 
             proximity_dispatcher: proximity_dispatcher,
             proximity_detectors: HashMap::new(),
+
+            unsupported_pairs: HashSet::new(),
         }
     }
 }
@@ -41,8 +47,50 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
         objects: &CollisionObjectSlab<P, M, T>,
         contact_events: &mut ContactEvents,
         proximity_events: &mut ProximityEvents,
+        unsupported_pair_events: &mut UnsupportedPairEvents,
         timestamp: usize,
     ) {
+        // The shape of one of the objects of an unsupported pair may have been replaced by a shape
+        // the dispatchers know how to handle. The new algorithms are updated right below.
+        let contact_dispatcher = &*self.contact_dispatcher;
+        let proximity_dispatcher = &*self.proximity_dispatcher;
+        let contact_generators = &mut self.contact_generators;
+        let proximity_detectors = &mut self.proximity_detectors;
+
+        self.unsupported_pairs.retain(|key| {
+            let co1 = &objects[key.0];
+            let co2 = &objects[key.1];
+
+            if co1.timestamp != timestamp && co2.timestamp != timestamp {
+                return true;
+            }
+
+            match (co1.query_type(), co2.query_type()) {
+                (GeometricQueryType::Contacts(..), GeometricQueryType::Contacts(..)) => {
+                    match contact_dispatcher
+                        .get_contact_algorithm(co1.shape().as_ref(), co2.shape().as_ref())
+                    {
+                        Some(detector) => {
+                            let _ = contact_generators.insert(*key, detector);
+                            false
+                        }
+                        None => true,
+                    }
+                }
+                _ => match proximity_dispatcher
+                    .get_proximity_algorithm(co1.shape().as_ref(), co2.shape().as_ref())
+                {
+                    Some(detector) => {
+                        let _ = proximity_detectors.insert(*key, detector);
+                        false
+                    }
+                    None => true,
+                },
+            }
+        });
+
+        let mut unsupported_pairs = Vec::new();
+
         for (key, value) in self.contact_generators.iter_mut() {
             let co1 = &objects[key.0];
             let co2 = &objects[key.1];
@@ -51,7 +99,7 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
                 let had_contacts = value.num_contacts() != 0;
 
                 if let Some(prediction) = co1.query_type().contact_queries_to_prediction(co2.query_type()) {
-                    let _ = value.update(
+                    let valid = value.update(
                         &*self.contact_dispatcher,
                         &co1.position(),
                         co1.shape().as_ref(),
@@ -59,6 +107,49 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
                         co2.shape().as_ref(),
                         &prediction,
                     );
+
+                    // One of the shapes has been replaced by a shape this algorithm cannot handle,
+                    // or the algorithm relies on a `QueryDispatcher` that does not support them.
+                    if !valid {
+                        let dispatcher = &*self.contact_dispatcher;
+                        let replacement = dispatcher
+                            .get_contact_algorithm(co1.shape().as_ref(), co2.shape().as_ref())
+                            .and_then(|mut detector| {
+                                let valid = detector.update(
+                                    dispatcher,
+                                    &co1.position(),
+                                    co1.shape().as_ref(),
+                                    &co2.position(),
+                                    co2.shape().as_ref(),
+                                    &prediction,
+                                );
+
+                                if valid {
+                                    Some(detector)
+                                } else {
+                                    None
+                                }
+                            });
+
+                        if let Some(detector) = replacement {
+                            *value = detector;
+                        } else {
+                            // The old algorithm is dropped so it does not report stale contacts.
+                            let error =
+                                Unsupported::new(co1.shape().as_ref(), co2.shape().as_ref());
+                            let event =
+                                UnsupportedPairEvent::new(co1.handle(), co2.handle(), error);
+                            unsupported_pair_events.push(event);
+                            unsupported_pairs.push(*key);
+
+                            if had_contacts {
+                                contact_events
+                                    .push(ContactEvent::Stopped(co1.handle(), co2.handle()));
+                            }
+
+                            continue;
+                        }
+                    }
                 } else {
                     panic!("Unable to compute contact between collision objects with query types different from `GeometricQueryType::Contacts(..)`.")
                 } 
@@ -75,6 +166,11 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
             }
         }
 
+        for key in unsupported_pairs.drain(..) {
+            let _ = self.contact_generators.remove(&key);
+            let _ = self.unsupported_pairs.insert(key);
+        }
+
         for (key, value) in self.proximity_detectors.iter_mut() {
             let co1 = &objects[key.0];
             let co2 = &objects[key.1];
@@ -82,15 +178,61 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
             if co1.timestamp == timestamp || co2.timestamp == timestamp {
                 let prev_prox = value.proximity();
 
-                let _ = value.update(
+                let margin = co1.query_type().query_limit() + co2.query_type().query_limit();
+                let valid = value.update(
                     &*self.proximity_dispatcher,
                     &co1.position(),
                     co1.shape().as_ref(),
                     &co2.position(),
                     co2.shape().as_ref(),
-                    co1.query_type().query_limit() + co2.query_type().query_limit(),
+                    margin,
                 );
 
+                // One of the shapes has been replaced by a shape this algorithm cannot handle,
+                // or the algorithm relies on a `QueryDispatcher` that does not support them.
+                if !valid {
+                    let dispatcher = &*self.proximity_dispatcher;
+                    let replacement = dispatcher
+                        .get_proximity_algorithm(co1.shape().as_ref(), co2.shape().as_ref())
+                        .and_then(|mut detector| {
+                            let valid = detector.update(
+                                dispatcher,
+                                &co1.position(),
+                                co1.shape().as_ref(),
+                                &co2.position(),
+                                co2.shape().as_ref(),
+                                margin,
+                            );
+
+                            if valid {
+                                Some(detector)
+                            } else {
+                                None
+                            }
+                        });
+
+                    if let Some(detector) = replacement {
+                        *value = detector;
+                    } else {
+                        // The old algorithm is dropped so it does not report a stale proximity.
+                        let error = Unsupported::new(co1.shape().as_ref(), co2.shape().as_ref());
+                        let event = UnsupportedPairEvent::new(co1.handle(), co2.handle(), error);
+                        unsupported_pair_events.push(event);
+                        unsupported_pairs.push(*key);
+
+                        if prev_prox != Proximity::Disjoint {
+                            proximity_events.push(ProximityEvent::new(
+                                co1.handle(),
+                                co2.handle(),
+                                prev_prox,
+                                Proximity::Disjoint,
+                            ));
+                        }
+
+                        continue;
+                    }
+                }
+
                 let new_prox = value.proximity();
 
                 if new_prox != prev_prox {
@@ -103,20 +245,28 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
                 }
             }
         }
+
+        for key in unsupported_pairs {
+            let _ = self.proximity_detectors.remove(&key);
+            let _ = self.unsupported_pairs.insert(key);
+        }
     }
 
     fn handle_interaction(
         &mut self,
         contact_events: &mut ContactEvents,
         proximity_events: &mut ProximityEvents,
+        unsupported_pair_events: &mut UnsupportedPairEvents,
         objects: &CollisionObjectSlab<P, M, T>,
         handle1: CollisionObjectHandle,
         handle2: CollisionObjectHandle,
         started: bool,
     ) {
+        // The algorithms are always updated with the objects in the order of the sorted pair, so
+        // they must be created with the same order.
         let key = SortedPair::new(handle1, handle2);
-        let co1 = &objects[handle1];
-        let co2 = &objects[handle2];
+        let co1 = &objects[key.0];
+        let co2 = &objects[key.1];
 
         match (co1.query_type(), co2.query_type()) {
             (GeometricQueryType::Contacts(..), GeometricQueryType::Contacts(..)) => {
@@ -128,10 +278,19 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
                             .get_contact_algorithm(co1.shape().as_ref(), co2.shape().as_ref())
                         {
                             let _ = entry.insert(detector);
+                        } else {
+                            let error =
+                                Unsupported::new(co1.shape().as_ref(), co2.shape().as_ref());
+                            let event =
+                                UnsupportedPairEvent::new(co1.handle(), co2.handle(), error);
+                            unsupported_pair_events.push(event);
+                            let _ = self.unsupported_pairs.insert(key);
                         }
                     }
                 } else {
                     // Proximity stopped.
+                    let _ = self.unsupported_pairs.remove(&key);
+
                     if let Some(detector) = self.contact_generators.remove(&key) {
                         // Register a collision lost event if there was a contact.
                         if detector.num_contacts() != 0 {
@@ -149,10 +308,19 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
                             .get_proximity_algorithm(co1.shape().as_ref(), co2.shape().as_ref())
                         {
                             let _ = entry.insert(detector);
+                        } else {
+                            let error =
+                                Unsupported::new(co1.shape().as_ref(), co2.shape().as_ref());
+                            let event =
+                                UnsupportedPairEvent::new(co1.handle(), co2.handle(), error);
+                            unsupported_pair_events.push(event);
+                            let _ = self.unsupported_pairs.insert(key);
                         }
                     }
                 } else {
                     // Proximity stopped.
+                    let _ = self.unsupported_pairs.remove(&key);
+
                     if let Some(detector) = self.proximity_detectors.remove(&key) {
                         // Register a proximity lost signal if they were not disjoint.
                         let prev_prox = detector.proximity();
@@ -181,6 +349,7 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
         let key = SortedPair::new(handle1, handle2);
         let _ = self.proximity_detectors.remove(&key);
         let _ = self.contact_generators.remove(&key);
+        let _ = self.unsupported_pairs.remove(&key);
     }
 
     fn contact_pairs<'a>(
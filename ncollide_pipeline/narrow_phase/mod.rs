@@ -10,18 +10,28 @@ pub use self::contact_generator::{BallBallContactGenerator, CompositeShapeShapeC
                                   DefaultContactDispatcher, IncrementalContactManifoldGenerator,
                                   OneShotContactManifoldGenerator,
                                   PlaneSupportMapContactGenerator,
+                                  QueryDispatcherContactGenerator,
+                                  RoundSupportMapContactGenerator,
+                                  SdfGridSupportMapContactGenerator,
                                   ShapeCompositeShapeContactGenerator,
                                   SupportMapPlaneContactGenerator,
-                                  SupportMapSupportMapContactGenerator};
+                                  SupportMapSdfGridContactGenerator,
+                                  SupportMapSupportMapContactGenerator,
+                                  SupportMapTorusContactGenerator,
+                                  TorusSupportMapContactGenerator};
 
 #[doc(inline)]
 pub use self::proximity_detector::{BallBallProximityDetector,
                                    CompositeShapeShapeProximityDetector,
                                    DefaultProximityDispatcher, PlaneSupportMapProximityDetector,
                                    ProximityAlgorithm, ProximityDetector, ProximityDispatcher,
+                                   QueryDispatcherProximityDetector,
+                                   RoundSupportMapProximityDetector,
                                    ShapeCompositeShapeProximityDetector,
                                    SupportMapPlaneProximityDetector,
-                                   SupportMapSupportMapProximityDetector};
+                                   SupportMapSupportMapProximityDetector,
+                                   SupportMapTorusProximityDetector,
+                                   TorusSupportMapProximityDetector};
 
 #[doc(hidden)]
 pub mod contact_generator;
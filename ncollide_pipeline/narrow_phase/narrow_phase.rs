@@ -4,7 +4,7 @@ use std::collections::hash_map::Iter;
 use utils::data::SortedPair;
 use geometry::query::Contact;
 use narrow_phase::{ContactAlgorithm, ContactGenerator, ProximityAlgorithm, ProximityDetector};
-use events::{ContactEvents, ProximityEvents};
+use events::{ContactEvents, ProximityEvents, UnsupportedPairEvents};
 use world::{CollisionObject, CollisionObjectHandle, CollisionObjectSlab};
 use math::Point;
 
@@ -19,6 +19,7 @@ pub trait NarrowPhase<P: Point, M, T>: Any + Send + Sync {
         objects: &CollisionObjectSlab<P, M, T>,
         contact_events: &mut ContactEvents,
         proximity_events: &mut ProximityEvents,
+        unsupported_pair_events: &mut UnsupportedPairEvents,
         timestamp: usize,
     );
 
@@ -27,6 +28,7 @@ pub trait NarrowPhase<P: Point, M, T>: Any + Send + Sync {
         &mut self,
         contact_signal: &mut ContactEvents,
         proximity_signal: &mut ProximityEvents,
+        unsupported_pair_signal: &mut UnsupportedPairEvents,
         objects: &CollisionObjectSlab<P, M, T>,
         handle1: CollisionObjectHandle,
         handle2: CollisionObjectHandle,
@@ -49,13 +49,10 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
         self.interferences.clear();
 
         // First, test if the previously intersecting shapes are still intersecting.
-        if self.proximity == Proximity::Intersecting {
+        if self.proximity == Proximity::Intersecting && g1.contains_part(self.intersecting_key) {
             let detector = self.sub_detectors.find_mut(&self.intersecting_key).unwrap();
             g1.map_transformed_part_at(self.intersecting_key, m1, &mut |m1, g1| {
-                assert!(
-                    detector.update(disp, m1, g1, m2, g2, margin),
-                    "The shape was no longer valid."
-                );
+                update_sub_detector(disp, detector, m1, g1, m2, g2, margin)
             });
 
             match detector.proximity() {
@@ -74,6 +71,12 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
         for detector in self.sub_detectors.elements_mut().iter_mut() {
             let key = detector.key;
 
+            if !g1.contains_part(key) {
+                // The part has been removed from the composite shape.
+                self.to_delete.push(key);
+                continue;
+            }
+
             if key == self.intersecting_key {
                 // We already dealt with that one.
                 continue;
@@ -81,10 +84,7 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
 
             if ls_aabb2.intersects(&g1.aabb_at(key)) {
                 g1.map_transformed_part_at(key, m1, &mut |m1, g1| {
-                    assert!(
-                        detector.value.update(disp, m1, g1, m2, g2, margin),
-                        "The shape was no longer valid."
-                    );
+                    update_sub_detector(disp, &mut detector.value, m1, g1, m2, g2, margin)
                 });
 
                 match detector.value.proximity() {
@@ -106,7 +106,7 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
         {
             let mut visitor =
                 BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
-            g1.bvt().visit(&mut visitor);
+            g1.visit_parts(&mut visitor);
         }
 
         for key in self.interferences.iter() {
@@ -142,6 +142,25 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
     }
 }
 
+// Updates the proximity detector of a part, replacing it if the part has been replaced by a shape
+// it cannot handle.
+fn update_sub_detector<P: Point, M: Isometry<P>>(
+    disp: &ProximityDispatcher<P, M>,
+    detector: &mut ProximityAlgorithm<P, M>,
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    margin: P::Real,
+) {
+    if !detector.update(disp, m1, g1, m2, g2, margin) {
+        if let Some(mut new_detector) = disp.get_proximity_algorithm(g1, g2) {
+            let _ = new_detector.update(disp, m1, g1, m2, g2, margin);
+            *detector = new_detector;
+        }
+    }
+}
+
 /// Proximity detector between a shape and a concave shape.
 pub struct ShapeCompositeShapeProximityDetector<P: Point, M> {
     sub_detector: CompositeShapeShapeProximityDetector<P, M>,
@@ -1,17 +1,24 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 use math::{Isometry, Point};
 use na;
-use geometry::shape::{Ball, Plane, Shape};
+use geometry::shape::{Ball, Plane, Shape, Torus};
+use geometry::query::QueryDispatcher;
 use geometry::query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use narrow_phase::proximity_detector::{BallBallProximityDetector,
                                        CompositeShapeShapeProximityDetector,
                                        PlaneSupportMapProximityDetector, ProximityAlgorithm,
-                                       ProximityDispatcher, ShapeCompositeShapeProximityDetector,
+                                       ProximityDispatcher, QueryDispatcherProximityDetector,
+                                       RoundSupportMapProximityDetector,
+                                       ShapeCompositeShapeProximityDetector,
                                        SupportMapPlaneProximityDetector,
-                                       SupportMapSupportMapProximityDetector};
+                                       SupportMapSupportMapProximityDetector,
+                                       SupportMapTorusProximityDetector,
+                                       TorusSupportMapProximityDetector};
 
 /// Proximity dispatcher for shapes defined by `ncollide_entities`.
 pub struct DefaultProximityDispatcher<P: Point, M> {
+    query_dispatcher: Option<Arc<QueryDispatcher<P, M>>>,
     _point_type: PhantomData<P>,
     _matrix_type: PhantomData<M>,
 }
@@ -20,6 +27,19 @@ impl<P: Point, M> DefaultProximityDispatcher<P, M> {
     /// Creates a new basic proximity dispatcher.
     pub fn new() -> DefaultProximityDispatcher<P, M> {
         DefaultProximityDispatcher {
+            query_dispatcher: None,
+            _point_type: PhantomData,
+            _matrix_type: PhantomData,
+        }
+    }
+
+    /// Creates a new basic proximity dispatcher that relies on `query_dispatcher` for the pairs of
+    /// shapes it does not handle.
+    pub fn with_query_dispatcher(
+        query_dispatcher: Arc<QueryDispatcher<P, M>>,
+    ) -> DefaultProximityDispatcher<P, M> {
+        DefaultProximityDispatcher {
+            query_dispatcher: Some(query_dispatcher),
             _point_type: PhantomData,
             _matrix_type: PhantomData,
         }
@@ -41,6 +61,14 @@ impl<P: Point, M: Isometry<P>> ProximityDispatcher<P, M> for DefaultProximityDis
             Some(Box::new(PlaneSupportMapProximityDetector::<P, M>::new()))
         } else if b.is_shape::<Plane<P::Vector>>() && a.is_support_map() {
             Some(Box::new(SupportMapPlaneProximityDetector::<P, M>::new()))
+        } else if a.is_shape::<Torus<P::Real>>() && b.is_support_map() {
+            Some(Box::new(TorusSupportMapProximityDetector::<P, M>::new()))
+        } else if b.is_shape::<Torus<P::Real>>() && a.is_support_map() {
+            Some(Box::new(SupportMapTorusProximityDetector::<P, M>::new()))
+        } else if (a.as_round_shape().is_some() || b.as_round_shape().is_some())
+            && a.is_support_map() && b.is_support_map()
+        {
+            Some(Box::new(RoundSupportMapProximityDetector::<P, M>::new()))
         } else if a.is_support_map() && b.is_support_map() {
             if na::dimension::<P::Vector>() == 2 {
                 let simplex = VoronoiSimplex2::new();
@@ -66,6 +94,8 @@ impl<P: Point, M: Isometry<P>> ProximityDispatcher<P, M> for DefaultProximityDis
             Some(Box::new(
                 ShapeCompositeShapeProximityDetector::<P, M>::new(),
             ))
+        } else if let Some(ref query_dispatcher) = self.query_dispatcher {
+            Some(Box::new(QueryDispatcherProximityDetector::new(query_dispatcher.clone())))
         } else {
             None
         }
@@ -5,14 +5,21 @@ pub use self::ball_ball_proximity_detector::BallBallProximityDetector;
 pub use self::plane_support_map_proximity_detector::{PlaneSupportMapProximityDetector,
                                                      SupportMapPlaneProximityDetector};
 pub use self::support_map_support_map_proximity_detector::SupportMapSupportMapProximityDetector;
+pub use self::torus_support_map_proximity_detector::{SupportMapTorusProximityDetector,
+                                                     TorusSupportMapProximityDetector};
+pub use self::round_support_map_proximity_detector::RoundSupportMapProximityDetector;
 pub use self::composite_shape_shape_proximity_detector::{CompositeShapeShapeProximityDetector,
                                                          ShapeCompositeShapeProximityDetector};
 pub use self::default_proximity_dispatcher::DefaultProximityDispatcher;
+pub use self::query_dispatcher_proximity_detector::QueryDispatcherProximityDetector;
 
 #[doc(hidden)]
 pub mod proximity_detector;
 mod ball_ball_proximity_detector;
 mod plane_support_map_proximity_detector;
 mod support_map_support_map_proximity_detector;
+mod torus_support_map_proximity_detector;
+mod round_support_map_proximity_detector;
 mod composite_shape_shape_proximity_detector;
 mod default_proximity_dispatcher;
+mod query_dispatcher_proximity_detector;
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::{Proximity, QueryDispatcher};
+use narrow_phase::{ProximityDetector, ProximityDispatcher};
+
+/// Proximity detector relying on a `QueryDispatcher`.
+pub struct QueryDispatcherProximityDetector<P: Point, M> {
+    dispatcher: Arc<QueryDispatcher<P, M>>,
+    proximity: Proximity,
+}
+
+impl<P: Point, M> QueryDispatcherProximityDetector<P, M> {
+    /// Creates a new proximity detector using `dispatcher` to compute proximities.
+    #[inline]
+    pub fn new(dispatcher: Arc<QueryDispatcher<P, M>>) -> QueryDispatcherProximityDetector<P, M> {
+        QueryDispatcherProximityDetector {
+            dispatcher: dispatcher,
+            proximity: Proximity::Disjoint,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M>
+    for QueryDispatcherProximityDetector<P, M> {
+    fn update(
+        &mut self,
+        _: &ProximityDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        margin: P::Real,
+    ) -> bool {
+        match self.dispatcher.proximity(ma, a, mb, b, margin) {
+            Ok(proximity) => {
+                self.proximity = proximity;
+                true
+            }
+            Err(_) => {
+                self.proximity = Proximity::Disjoint;
+                false
+            }
+        }
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+}
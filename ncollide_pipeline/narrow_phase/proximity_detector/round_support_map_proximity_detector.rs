@@ -0,0 +1,76 @@
+use std::marker::PhantomData;
+use na;
+use math::{Isometry, Point};
+use geometry::shape::{Shape, SupportMap};
+use geometry::query::Proximity;
+use geometry::query::proximity_internal;
+use narrow_phase::{ProximityDetector, ProximityDispatcher};
+
+/// Proximity detector between two shapes implementing the `SupportMap` trait, at least one of
+/// them being a rounded shape.
+#[derive(Clone)]
+pub struct RoundSupportMapProximityDetector<P: Point, M> {
+    proximity: Proximity,
+    pt_type: PhantomData<P>,  // FIXME: can we avoid this?
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> RoundSupportMapProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector between two shapes with support mapping
+    /// functions, at least one of them being a rounded shape.
+    #[inline]
+    pub fn new() -> RoundSupportMapProximityDetector<P, M> {
+        RoundSupportMapProximityDetector {
+            proximity: Proximity::Disjoint,
+            pt_type: PhantomData,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for RoundSupportMapProximityDetector<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ProximityDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        margin: P::Real,
+    ) -> bool {
+        if let (Some((ca, ra)), Some((cb, rb))) = (round_core(a), round_core(b)) {
+            self.proximity = proximity_internal::round_support_map_against_round_support_map(
+                ma,
+                ca,
+                ra,
+                mb,
+                cb,
+                rb,
+                margin,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+}
+
+// The core of a rounded shape, or the shape itself with a zero radius if it is a plain support
+// map.
+fn round_core<P, M>(shape: &Shape<P, M>) -> Option<(&SupportMap<P, M>, P::Real)>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match shape.as_round_shape() {
+        Some(core) => Some(core),
+        None => shape.as_support_map().map(|s| (s, na::zero())),
+    }
+}
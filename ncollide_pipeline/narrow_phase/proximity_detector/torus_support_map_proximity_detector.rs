@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+use math::{Isometry, Point};
+use geometry::shape::{Shape, Torus};
+use geometry::query::Proximity;
+use geometry::query::proximity_internal;
+use narrow_phase::{ProximityDetector, ProximityDispatcher};
+
+/// Proximity detector between a torus and a shape implementing the `SupportMap` trait.
+#[derive(Clone)]
+pub struct TorusSupportMapProximityDetector<P: Point, M> {
+    proximity: Proximity,
+    pt_type: PhantomData<P>,  // FIXME: can we avoid this?
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> TorusSupportMapProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector between a torus and a shape with a support
+    /// mapping function.
+    #[inline]
+    pub fn new() -> TorusSupportMapProximityDetector<P, M> {
+        TorusSupportMapProximityDetector {
+            proximity: Proximity::Disjoint,
+            pt_type: PhantomData,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+/// Proximity detector between a shape implementing the `SupportMap` trait and a torus.
+#[derive(Clone)]
+pub struct SupportMapTorusProximityDetector<P: Point, M> {
+    subdetector: TorusSupportMapProximityDetector<P, M>,
+}
+
+impl<P: Point, M> SupportMapTorusProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector between a shape with a support mapping
+    /// function and a torus.
+    #[inline]
+    pub fn new() -> SupportMapTorusProximityDetector<P, M> {
+        SupportMapTorusProximityDetector {
+            subdetector: TorusSupportMapProximityDetector::new(),
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for TorusSupportMapProximityDetector<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ProximityDispatcher<P, M>,
+        ma: &M,
+        torus: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        margin: P::Real,
+    ) -> bool {
+        if let (Some(t), Some(sm)) = (torus.as_shape::<Torus<P::Real>>(), b.as_support_map()) {
+            self.proximity = proximity_internal::torus_against_support_map(ma, t, mb, sm, margin);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for SupportMapTorusProximityDetector<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        disp: &ProximityDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        margin: P::Real,
+    ) -> bool {
+        self.subdetector.update(disp, mb, b, ma, a, margin)
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.subdetector.proximity()
+    }
+}
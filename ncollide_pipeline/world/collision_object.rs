@@ -151,6 +151,12 @@ impl<P: Point, M, T> CollisionObject<P, M, T> {
         &self.shape
     }
 
+    /// Sets the shape of the collision object.
+    #[inline]
+    pub(crate) fn set_shape(&mut self, shape: ShapeHandle<P, M>) {
+        self.shape = shape
+    }
+
     /// The collision groups of the collision object.
     #[inline]
     pub fn collision_groups(&self) -> &CollisionGroups {
@@ -1,17 +1,20 @@
+use std::cmp::Ordering;
 use std::mem;
+use std::sync::Arc;
 use std::vec::IntoIter;
 
 use math::{Isometry, Point};
 use geometry::bounding_volume::{self, BoundingVolume, AABB};
 use geometry::shape::ShapeHandle;
-use geometry::query::{PointQuery, Ray, RayCast, RayIntersection};
+use geometry::query::{ray_internal, CullMode, PointQuery, QueryDispatcher, Ray, RayCast,
+                      RayIntersection};
 use narrow_phase::{ContactPairs, Contacts, DefaultContactDispatcher, DefaultNarrowPhase,
                    DefaultProximityDispatcher, NarrowPhase, ProximityPairs};
 use broad_phase::{BroadPhase, BroadPhasePairFilter, BroadPhasePairFilters, DBVTBroadPhase,
                   ProxyHandle};
 use world::{CollisionGroups, CollisionGroupsPairFilter, CollisionObject, CollisionObjectHandle,
             CollisionObjectSlab, CollisionObjects, GeometricQueryType};
-use events::{ContactEvent, ContactEvents, ProximityEvents};
+use events::{ContactEvent, ContactEvents, ProximityEvents, UnsupportedPairEvents};
 
 /// Type of the narrow phase trait-object used by the collision world.
 pub type NarrowPhaseObject<P, M, T> = Box<NarrowPhase<P, M, T>>;
@@ -25,6 +28,7 @@ pub struct CollisionWorld<P: Point, M, T> {
     narrow_phase: Box<NarrowPhase<P, M, T>>,
     contact_events: ContactEvents,
     proximity_events: ProximityEvents,
+    unsupported_pair_events: UnsupportedPairEvents,
     pair_filters: BroadPhasePairFilters<P, M, T>,
     timestamp: usize, // FIXME: allow modification of the other properties too.
 }
@@ -44,6 +48,7 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         CollisionWorld {
             contact_events: ContactEvents::new(),
             proximity_events: ProximityEvents::new(),
+            unsupported_pair_events: UnsupportedPairEvents::new(),
             objects: objects,
             broad_phase: broad_phase,
             narrow_phase: Box::new(narrow_phase),
@@ -97,10 +102,11 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         self.perform_narrow_phase();
     }
 
-    /// Empty the contact and proximity event pools.
+    /// Empty the contact, proximity, and unsupported pair event pools.
     pub fn clear_events(&mut self) {
         self.contact_events.clear();
         self.proximity_events.clear();
+        self.unsupported_pair_events.clear();
     }
 
     /// Removed the specified set of collision objects from the world.
@@ -131,6 +137,8 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         let objects = &self.objects;
         self.proximity_events
             .retain(|e| objects.contains(e.collider1) && objects.contains(e.collider2));
+        self.unsupported_pair_events
+            .retain(|e| objects.contains(e.collider1) && objects.contains(e.collider2));
         self.contact_events.retain(|e| match *e {
             ContactEvent::Started(co1, co2) | ContactEvent::Stopped(co1, co2) => {
                 objects.contains(co1) && objects.contains(co2)
@@ -151,6 +159,24 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
             .deferred_set_bounding_volume(co.proxy_handle(), aabb);
     }
 
+    /// Sets the shape of the collision object attached to the specified object.
+    ///
+    /// This can be used to deform a shape, e.g., a `TriMesh` with updated vertices, between two
+    /// updates of the collision world. The contact and proximity algorithms are kept if they
+    /// still apply to the new shape, and replaced otherwise. Pairs previously reported as
+    /// unsupported are dispatched again during the next update.
+    pub fn set_shape(&mut self, handle: CollisionObjectHandle, shape: ShapeHandle<P, M>) {
+        let co = self.objects
+            .get_mut(handle)
+            .expect("Set shape: collision object not found.");
+        co.set_shape(shape);
+        co.timestamp = self.timestamp;
+        let mut aabb = bounding_volume::aabb(co.shape().as_ref(), co.position());
+        aabb.loosen(co.query_type().query_limit());
+        self.broad_phase
+            .deferred_set_bounding_volume(co.proxy_handle(), aabb);
+    }
+
     /// Adds a filter that tells if a potential collision pair should be ignored or not.
     ///
     /// The proximity filter returns `false` for a given pair of collision objects if they should
@@ -179,6 +205,7 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         let nf = &mut self.narrow_phase;
         let sig = &mut self.contact_events;
         let prox = &mut self.proximity_events;
+        let unsupported = &mut self.unsupported_pair_events;
         let filts = &self.pair_filters;
         let objs = &self.objects;
 
@@ -186,7 +213,9 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
             // Filter:
             &mut |b1, b2| CollisionWorld::filter_collision(filts, objs, *b1, *b2),
             // Handler:
-            &mut |b1, b2, started| nf.handle_interaction(sig, prox, objs, *b1, *b2, started),
+            &mut |b1, b2, started| {
+                nf.handle_interaction(sig, prox, unsupported, objs, *b1, *b2, started)
+            },
         );
     }
 
@@ -196,6 +225,7 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
             &self.objects,
             &mut self.contact_events,
             &mut self.proximity_events,
+            &mut self.unsupported_pair_events,
             self.timestamp,
         );
         self.timestamp = self.timestamp + 1;
@@ -213,9 +243,32 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         let old = mem::replace(&mut self.narrow_phase, narrow_phase);
         self.broad_phase.deferred_recompute_all_proximities();
 
+        // Ensure the new narrow phase updates every pair, even between non-moving objects.
+        let handles: Vec<_> = self.objects.iter().map(|co| co.handle()).collect();
+
+        for handle in handles {
+            self.objects[handle].timestamp = self.timestamp;
+        }
+
         old
     }
 
+    /// Replaces the narrow phase by a `DefaultNarrowPhase` that relies on `query_dispatcher` for
+    /// the pairs of shapes not handled by the default contact and proximity dispatchers.
+    ///
+    /// This has the same overhead as `.set_narrow_phase(...)` during the next update.
+    pub fn set_query_dispatcher(&mut self, query_dispatcher: Arc<QueryDispatcher<P, M>>) {
+        let coll_dispatcher = Box::new(DefaultContactDispatcher::with_query_dispatcher(
+            query_dispatcher.clone(),
+        ));
+        let prox_dispatcher = Box::new(DefaultProximityDispatcher::with_query_dispatcher(
+            query_dispatcher,
+        ));
+        let narrow_phase = DefaultNarrowPhase::new(coll_dispatcher, prox_dispatcher);
+
+        let _ = self.set_narrow_phase(Box::new(narrow_phase));
+    }
+
     /// Iterates through all the contact pairs detected since the last update.
     #[inline]
     pub fn contact_pairs(&self) -> ContactPairs<P, M, T> {
@@ -271,12 +324,125 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
 
         InterferencesWithRay {
             ray: ray,
+            mode: RayCastMode::Plain,
             groups: groups,
             objects: &self.objects,
             handles: handles.into_iter(),
         }
     }
 
+    /// Computes the interferences between every rigid bodies on this world and a ray, ignoring the
+    /// intersections with a time of impact greater than `max_toi`.
+    ///
+    /// The objects farther than `max_toi` along the ray are pruned by the broad phase.
+    #[inline]
+    pub fn interferences_with_bounded_ray<'a>(
+        &'a self,
+        ray: &'a Ray<P>,
+        max_toi: P::Real,
+        groups: &'a CollisionGroups,
+    ) -> InterferencesWithRay<'a, P, M, T> {
+        // FIXME: avoid allocation.
+        let mut handles = Vec::new();
+        self.broad_phase
+            .interferences_with_bounded_ray(ray, max_toi, &mut handles);
+
+        InterferencesWithRay {
+            ray: ray,
+            mode: RayCastMode::Bounded(max_toi),
+            groups: groups,
+            objects: &self.objects,
+            handles: handles.into_iter(),
+        }
+    }
+
+    /// Computes the interferences between every rigid bodies on this world and a ray, ignoring the
+    /// faces selected by `cull`.
+    ///
+    /// See `RayCast::toi_and_normal_with_culled_ray` for the shapes supporting culling.
+    #[inline]
+    pub fn interferences_with_culled_ray<'a>(
+        &'a self,
+        ray: &'a Ray<P>,
+        cull: CullMode,
+        groups: &'a CollisionGroups,
+    ) -> InterferencesWithRay<'a, P, M, T> {
+        // FIXME: avoid allocation.
+        let mut handles = Vec::new();
+        self.broad_phase.interferences_with_ray(ray, &mut handles);
+
+        InterferencesWithRay {
+            ray: ray,
+            mode: RayCastMode::Culled(cull),
+            groups: groups,
+            objects: &self.objects,
+            handles: handles.into_iter(),
+        }
+    }
+
+    /// Computes the first intersection between a ray and the objects on this world.
+    ///
+    /// Only the objects that can interact with `groups` and for which `filter` returns `true` are
+    /// considered, e.g., to ignore the object casting the ray. The broad phase is searched from
+    /// the closest to the farthest object along the ray, so that the farther objects are not ray
+    /// cast once a hit has been found.
+    pub fn cast_ray<F>(
+        &self,
+        ray: &Ray<P>,
+        groups: &CollisionGroups,
+        mut filter: F,
+    ) -> Option<(CollisionObjectHandle, RayIntersection<P::Vector>)>
+    where
+        F: FnMut(&CollisionObject<P, M, T>) -> bool,
+    {
+        let objects = &self.objects;
+        let mut cast = |handle: &CollisionObjectHandle| {
+            let co = &objects[*handle];
+
+            if co.collision_groups().can_interact_with_groups(groups) && filter(co) {
+                co.shape()
+                    .toi_and_normal_with_ray(co.position(), ray, true)
+            } else {
+                None
+            }
+        };
+
+        self.broad_phase
+            .first_interference_with_ray(ray, &mut cast)
+            .map(|(handle, inter)| (*handle, inter))
+    }
+
+    /// Computes all the intersections between the objects on this world and a ray, sorted by time
+    /// of impact.
+    ///
+    /// Unlike `interferences_with_ray`, an object may be reported several times: once for each of
+    /// the parts of a composite shape crossed by the ray, and once for the point the ray leaves a
+    /// solid shape. See `query::ray_internal::ray_intersections_with_shape` for details.
+    pub fn intersections_with_ray<'a>(
+        &'a self,
+        ray: &Ray<P>,
+        groups: &CollisionGroups,
+    ) -> Vec<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)> {
+        let mut handles = Vec::new();
+        self.broad_phase.interferences_with_ray(ray, &mut handles);
+
+        let mut res = Vec::new();
+
+        for handle in handles {
+            let co = &self.objects[*handle];
+
+            if co.collision_groups().can_interact_with_groups(groups) {
+                let inters =
+                    ray_internal::ray_intersections_with_shape(co.position(), &**co.shape(), ray);
+                res.extend(inters.into_iter().map(|inter| (co, inter)));
+            }
+        }
+
+        res.sort_by(|a, b| a.1.toi.partial_cmp(&b.1.toi).unwrap_or(Ordering::Equal));
+
+        res
+    }
+
     /// Computes the interferences between every rigid bodies of a given broad phase, and a point.
     #[inline]
     pub fn interferences_with_point<'a>(
@@ -326,6 +492,11 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         &self.proximity_events
     }
 
+    /// The pool of events reporting the pairs of collision objects the narrow phase cannot handle.
+    pub fn unsupported_pair_events(&self) -> &UnsupportedPairEvents {
+        &self.unsupported_pair_events
+    }
+
     // Filters by group and by the user-provided callback.
     #[inline]
     fn filter_collision(
@@ -342,9 +513,17 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
     }
 }
 
+// The ray cast performed on each object by `InterferencesWithRay`.
+enum RayCastMode<N> {
+    Plain,
+    Bounded(N),
+    Culled(CullMode),
+}
+
 /// Iterator through all the objects on the world that intersect a specific ray.
 pub struct InterferencesWithRay<'a, P: 'a + Point, M: 'a, T: 'a> {
     ray: &'a Ray<P>,
+    mode: RayCastMode<P::Real>,
     objects: &'a CollisionObjectSlab<P, M, T>,
     groups: &'a CollisionGroups,
     handles: IntoIter<&'a CollisionObjectHandle>,
@@ -359,8 +538,22 @@ impl<'a, P: Point, M: Isometry<P>, T> Iterator for InterferencesWithRay<'a, P, M
             let co = &self.objects[*handle];
 
             if co.collision_groups().can_interact_with_groups(self.groups) {
-                let inter = co.shape()
-                    .toi_and_normal_with_ray(&co.position(), self.ray, true);
+                let inter = match self.mode {
+                    RayCastMode::Plain => co.shape()
+                        .toi_and_normal_with_ray(&co.position(), self.ray, true),
+                    RayCastMode::Bounded(max_toi) => co.shape().toi_and_normal_with_bounded_ray(
+                        &co.position(),
+                        self.ray,
+                        max_toi,
+                        true,
+                    ),
+                    RayCastMode::Culled(cull) => co.shape().toi_and_normal_with_culled_ray(
+                        &co.position(),
+                        self.ray,
+                        cull,
+                        true,
+                    ),
+                };
 
                 if let Some(inter) = inter {
                     return Some((co, inter));
@@ -0,0 +1,75 @@
+use alga::general::Real;
+use na;
+use na::{Point3, Vector3};
+use super::{IndexBuffer, TriMesh};
+use super::utils;
+
+/// Generates a torus centered at the origin and with its principal axis aligned with the `y`
+/// axis.
+///
+/// # Arguments:
+/// * `major_radius` - the radius of the circle swept by the center of the tube.
+/// * `minor_radius` - the radius of the tube.
+/// * `nmajor_subdiv` - the number of subdivisions along the tube.
+/// * `nminor_subdiv` - the number of subdivisions around the tube.
+pub fn torus<N: Real>(
+    major_radius: N,
+    minor_radius: N,
+    nmajor_subdiv: u32,
+    nminor_subdiv: u32,
+) -> TriMesh<Point3<N>> {
+    assert!(
+        nmajor_subdiv >= 3 && nminor_subdiv >= 3,
+        "A torus needs at least 3 subdivisions along each direction."
+    );
+
+    let dtheta = N::two_pi() / na::convert(nmajor_subdiv as f64);
+    let dphi = N::two_pi() / na::convert(nminor_subdiv as f64);
+    let mut coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+
+    let mut curr_theta = N::zero();
+
+    for _ in 0..nmajor_subdiv {
+        let radial = Vector3::new(curr_theta.cos(), na::zero(), curr_theta.sin());
+        let mut curr_phi = N::zero();
+
+        for _ in 0..nminor_subdiv {
+            let normal = radial * curr_phi.cos() + Vector3::y() * curr_phi.sin();
+
+            coords.push(Point3::from_coordinates(
+                radial * major_radius + normal * minor_radius,
+            ));
+            normals.push(normal);
+
+            curr_phi = curr_phi + dphi;
+        }
+
+        curr_theta = curr_theta + dtheta;
+    }
+
+    for i in 0..nmajor_subdiv {
+        let base = i * nminor_subdiv;
+        let next_base = ((i + 1) % nmajor_subdiv) * nminor_subdiv;
+
+        for j in 0..nminor_subdiv {
+            let next_j = (j + 1) % nminor_subdiv;
+
+            utils::push_rectangle_indices(
+                base + j,
+                next_base + j,
+                base + next_j,
+                next_base + next_j,
+                &mut indices,
+            );
+        }
+    }
+
+    TriMesh::new(
+        coords,
+        Some(normals),
+        None,
+        Some(IndexBuffer::Unified(indices)),
+    )
+}
@@ -11,7 +11,8 @@ use na::{Isometry2, Point2, Point3};
 use na;
 use ncollide::world::{CollisionObject2, CollisionWorld2};
 use ncollide::transformation;
-use ncollide::shape::{Ball2, Compound2, ConvexHull2, Cuboid2, Plane2, Polyline2, Segment2, Shape2};
+use ncollide::shape::{Ball2, Compound2, ConvexHull2, Cuboid2, HeightField2, Plane2, Polyline2,
+                      Segment2, Shape2};
 use camera::Camera;
 use objects::{Ball, Box, Lines, SceneNode, Segment};
 
@@ -76,6 +77,8 @@ impl<'a, N: Real + ToPrimitive> GraphicsManager<'a, N> {
             }
         } else if let Some(s) = shape.as_shape::<Polyline2<N>>() {
             self.add_lines(object, delta, s, out)
+        } else if let Some(s) = shape.as_shape::<HeightField2<N>>() {
+            self.add_heightfield(object, delta, s, out)
         } else {
             panic!("Not yet implemented.")
         }
@@ -141,6 +144,37 @@ impl<'a, N: Real + ToPrimitive> GraphicsManager<'a, N> {
         out.push(SceneNode::LinesNode(Lines::new(*delta, vs, is, color)))
     }
 
+    fn add_heightfield<T>(
+        &mut self,
+        object: &CollisionObject2<N, T>,
+        delta: &Isometry2<N>,
+        shape: &HeightField2<N>,
+        out: &mut Vec<SceneNode<N>>,
+    ) {
+        let color = self.color_for_object(object.uid);
+
+        let mut vs = Vec::new();
+        let mut is = Vec::new();
+
+        for i in 0..shape.num_parts() {
+            let segment = shape.segment_at(i);
+
+            if i == 0 {
+                vs.push(*segment.a());
+            }
+
+            vs.push(*segment.b());
+            is.push(Point2::new(i, i + 1));
+        }
+
+        out.push(SceneNode::LinesNode(Lines::new(
+            *delta,
+            Arc::new(vs),
+            Arc::new(is),
+            color,
+        )))
+    }
+
     fn add_box<T>(
         &mut self,
         object: &CollisionObject2<N, T>,
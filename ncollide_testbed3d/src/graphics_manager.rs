@@ -6,9 +6,9 @@ use na::{self, Isometry3, Point3};
 use kiss3d::window::Window;
 use kiss3d::scene::SceneNode;
 use kiss3d::camera::{ArcBall, Camera, FirstPerson};
-use ncollide::shape::{Ball3, Compound3, Cone3, ConvexHull3, Cuboid3, Cylinder3, Plane3, Shape3,
-                      TriMesh3};
-use ncollide::transformation;
+use ncollide::shape::{Ball3, Capsule3, Compound3, Cone3, ConvexHull3, Cuboid3, Cylinder3,
+                      HeightField3, Plane3, Shape3, Torus3, TriMesh3};
+use ncollide::transformation::{self, ToTriMesh};
 use ncollide::world::{CollisionObject3, CollisionWorld3};
 use objects::ball::Ball;
 use objects::box_node::Box;
@@ -176,12 +176,18 @@ impl GraphicsManager {
             self.add_cylinder(window, object, delta, s, color, out)
         } else if let Some(s) = shape.as_shape::<Cone3<f32>>() {
             self.add_cone(window, object, delta, s, color, out)
+        } else if let Some(s) = shape.as_shape::<Capsule3<f32>>() {
+            self.add_capsule(window, object, delta, s, color, out)
+        } else if let Some(s) = shape.as_shape::<Torus3<f32>>() {
+            self.add_torus(window, object, delta, s, color, out)
         } else if let Some(s) = shape.as_shape::<Compound3<f32>>() {
             for &(t, ref s) in s.shapes().iter() {
                 self.add_shape(window, object.clone(), delta * t, s.as_ref(), color, out)
             }
         } else if let Some(s) = shape.as_shape::<TriMesh3<f32>>() {
             self.add_mesh(window, object, delta, s, color, out);
+        } else if let Some(s) = shape.as_shape::<HeightField3<f32>>() {
+            self.add_heightfield(window, object, delta, s, color, out);
         } else {
             panic!("Not yet implemented.")
         }
@@ -234,6 +240,38 @@ impl GraphicsManager {
         )))
     }
 
+    fn add_heightfield<T>(
+        &mut self,
+        window: &mut Window,
+        object: &CollisionObject3<f32, T>,
+        delta: Isometry3<f32>,
+        shape: &HeightField3<f32>,
+        color: Point3<f32>,
+        out: &mut Vec<Node>,
+    ) {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for i in 0..shape.num_parts() {
+            let triangle = shape.triangle_at(i);
+            let base = vertices.len() as u32;
+
+            vertices.push(*triangle.a());
+            vertices.push(*triangle.b());
+            vertices.push(*triangle.c());
+            indices.push(Point3::new(base, base + 1, base + 2));
+        }
+
+        out.push(Node::Mesh(Mesh::new(
+            object,
+            delta,
+            vertices,
+            indices,
+            color,
+            window,
+        )))
+    }
+
     fn add_ball<T>(
         &mut self,
         window: &mut Window,
@@ -331,6 +369,48 @@ impl GraphicsManager {
         out.push(Node::Cone(Cone::new(object, delta, r, h, color, window)))
     }
 
+    fn add_capsule<T>(
+        &mut self,
+        window: &mut Window,
+        object: &CollisionObject3<f32, T>,
+        delta: Isometry3<f32>,
+        shape: &Capsule3<f32>,
+        color: Point3<f32>,
+        out: &mut Vec<Node>,
+    ) {
+        let mesh = shape.to_trimesh((20, 20));
+
+        out.push(Node::Mesh(Mesh::new(
+            object,
+            delta,
+            mesh.coords,
+            mesh.indices.unwrap_unified(),
+            color,
+            window,
+        )))
+    }
+
+    fn add_torus<T>(
+        &mut self,
+        window: &mut Window,
+        object: &CollisionObject3<f32, T>,
+        delta: Isometry3<f32>,
+        shape: &Torus3<f32>,
+        color: Point3<f32>,
+        out: &mut Vec<Node>,
+    ) {
+        let mesh = shape.to_trimesh((40, 20));
+
+        out.push(Node::Mesh(Mesh::new(
+            object,
+            delta,
+            mesh.coords,
+            mesh.indices.unwrap_unified(),
+            color,
+            window,
+        )))
+    }
+
     pub fn draw<T>(&mut self, world: &CollisionWorld3<f32, T>) {
         for (uid, ns) in self.uid2sn.iter_mut() {
             if let Some(object) = world.collision_object(*uid) {
@@ -22,15 +22,16 @@ extern crate num_traits as num;
 pub use to_trimesh::ToTriMesh;
 pub use to_polyline::ToPolyline;
 pub use hacd::hacd;
-pub use convex_hull3::convex_hull3;
-pub use convex_hull2::{convex_hull2, convex_hull2_idx};
+pub use convex_hull3::{convex_hull3, convex_polyhedron};
+pub use convex_hull2::{convex_hull2, convex_polygon};
+pub use geometry::convex_hull::convex_hull2_idx;
 pub use triangulate::triangulate;
 
 mod to_trimesh;
 mod to_polyline;
 mod hacd;
 #[doc(hidden)]
-pub mod convex_hull_utils; // Internal implementation details.
+pub use geometry::convex_hull as convex_hull_utils; // Internal implementation details.
 mod convex_hull2;
 mod convex_hull3;
 mod triangulate;
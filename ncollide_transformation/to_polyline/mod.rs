@@ -8,6 +8,7 @@ mod capsule_to_polyline;
 mod cone_to_polyline;
 mod cuboid_to_polyline;
 mod cylinder_to_polyline;
+mod ellipsoid_to_polyline;
 // mod minkowski_sum_to_polyline;
 mod reflection_to_polyline;
 mod segment_to_polyline;
@@ -2,6 +2,7 @@ use std::ops::{Index, IndexMut};
 use std::mem;
 
 /// A sparse vector data structure.
+#[derive(Clone)]
 pub struct SparseVec<T> {
     data: Vec<Option<T>>,
     free: Vec<usize>,
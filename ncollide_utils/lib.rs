@@ -37,6 +37,7 @@ pub use cross3::cross3;
 pub use perp2::perp2;
 pub use point_cloud_support_point::point_cloud_support_point;
 pub use repeat::repeat;
+pub use polynomial::{polynomial_value, real_polynomial_roots};
 
 pub mod data;
 mod center;
@@ -56,3 +57,4 @@ mod cross3;
 mod perp2;
 mod point_cloud_support_point;
 mod repeat;
+mod polynomial;
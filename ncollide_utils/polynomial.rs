@@ -0,0 +1,126 @@
+use alga::general::Real;
+use na;
+
+/// The maximum degree of the polynomials supported by `real_polynomial_roots`.
+const MAX_DEGREE: usize = 4;
+
+/// Evaluates a polynomial at `x`.
+///
+/// The coefficients are given by increasing degree, i.e., `coeffs[i]` multiplies `x^i`.
+#[inline]
+pub fn polynomial_value<N: Real>(coeffs: &[N], x: N) -> N {
+    let mut res = N::zero();
+
+    for c in coeffs.iter().rev() {
+        res = res * x + *c;
+    }
+
+    res
+}
+
+/// Computes the real roots of a polynomial of degree at most 4 lying inside of `[min, max]`.
+///
+/// The coefficients are given by increasing degree, i.e., `coeffs[i]` multiplies `x^i`. The roots
+/// are written to `out` by increasing order and their number is returned. The interval is split
+/// at the roots of the derivative so that each root is isolated and refined by bisection. Roots
+/// with an even multiplicity (where the polynomial does not change sign) are only found if the
+/// polynomial vanishes exactly at one of the interval bounds.
+pub fn real_polynomial_roots<N: Real>(coeffs: &[N], min: N, max: N, out: &mut [N; 4]) -> usize {
+    assert!(
+        coeffs.len() <= MAX_DEGREE + 1,
+        "Only polynomials with a degree smaller than 4 are supported."
+    );
+
+    let mut len = coeffs.len();
+
+    while len > 0 && coeffs[len - 1].is_zero() {
+        len -= 1;
+    }
+
+    if len <= 1 || min > max {
+        // Constant polynomial.
+        return 0;
+    }
+
+    let coeffs = &coeffs[..len];
+
+    if len == 2 {
+        let root = -coeffs[0] / coeffs[1];
+
+        if root >= min && root <= max {
+            out[0] = root;
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    /*
+     * Split the interval into pieces where the polynomial is monotonic.
+     */
+    let mut dcoeffs = [N::zero(); MAX_DEGREE];
+
+    for i in 1..len {
+        dcoeffs[i - 1] = coeffs[i] * na::convert(i as f64);
+    }
+
+    let mut critical = [N::zero(); 4];
+    let ncritical = real_polynomial_roots(&dcoeffs[..len - 1], min, max, &mut critical);
+
+    let mut nroots = 0;
+    let mut a = min;
+    let mut fa = polynomial_value(coeffs, a);
+
+    for i in 0..ncritical + 1 {
+        let b = if i < ncritical { critical[i] } else { max };
+        let fb = polynomial_value(coeffs, b);
+
+        if fa.is_zero() {
+            if nroots == 0 || out[nroots - 1] != a {
+                out[nroots] = a;
+                nroots += 1;
+            }
+        } else if fa * fb < N::zero() {
+            out[nroots] = bisect(coeffs, a, fa, b);
+            nroots += 1;
+        }
+
+        a = b;
+        fa = fb;
+    }
+
+    if fa.is_zero() && (nroots == 0 || out[nroots - 1] != a) {
+        out[nroots] = a;
+        nroots += 1;
+    }
+
+    nroots
+}
+
+// Finds the root of a polynomial with a sign change on `[a, b]`.
+fn bisect<N: Real>(coeffs: &[N], mut a: N, mut fa: N, mut b: N) -> N {
+    let _0_5: N = na::convert(0.5);
+
+    for _ in 0..100 {
+        let mid = (a + b) * _0_5;
+
+        if mid <= a || mid >= b {
+            break;
+        }
+
+        let fmid = polynomial_value(coeffs, mid);
+
+        if fmid.is_zero() {
+            return mid;
+        }
+
+        if fa * fmid < N::zero() {
+            b = mid;
+        } else {
+            a = mid;
+            fa = fmid;
+        }
+    }
+
+    (a + b) * _0_5
+}
@@ -56,7 +56,7 @@ pub extern crate ncollide_utils;
 
 pub use ncollide_math as math;
 pub use ncollide_utils as utils;
-pub use ncollide_geometry::{bounding_volume, partitioning, query, shape};
+pub use ncollide_geometry::{bounding_volume, mass_properties, partitioning, query, shape};
 pub use ncollide_pipeline::{broad_phase, events, narrow_phase, world};
 pub use ncollide_procedural as procedural;
 pub use ncollide_transformation as transformation;
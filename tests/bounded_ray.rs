@@ -0,0 +1,129 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::bounding_volume::AABB;
+use ncollide::query::{Ray, RayCast, RayIntersection};
+use ncollide::shape::{Ball, Compound, Shape, ShapeHandle, TriMesh};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+static TRIPWIRE_CASTS: AtomicUsize = AtomicUsize::new(0);
+
+// A box that counts the rays cast on it.
+struct Tripwire;
+
+impl RayCast<Point2<f64>, Isometry2<f64>> for Tripwire {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry2<f64>,
+        ray: &Ray<Point2<f64>>,
+        solid: bool,
+    ) -> Option<RayIntersection<Vector2<f64>>> {
+        let _ = TRIPWIRE_CASTS.fetch_add(1, Ordering::SeqCst);
+        self.aabb(m).toi_and_normal_with_ray(&Isometry2::identity(), ray, solid)
+    }
+}
+
+impl Shape<Point2<f64>, Isometry2<f64>> for Tripwire {
+    fn aabb(&self, m: &Isometry2<f64>) -> AABB<Point2<f64>> {
+        let center = Point2::from_coordinates(m.translation.vector);
+
+        AABB::new(center - Vector2::repeat(1.0), center + Vector2::repeat(1.0))
+    }
+
+    fn as_ray_cast(&self) -> Option<&RayCast<Point2<f64>, Isometry2<f64>>> {
+        Some(self)
+    }
+}
+
+#[test]
+fn bounded_ray_simple_shapes() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry2::new(Vector2::new(5.0, 0.0), 0.0);
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+
+    assert_eq!(ball.toi_with_bounded_ray(&m, &ray, 5.0, true), Some(4.0));
+    assert_eq!(ball.toi_with_bounded_ray(&m, &ray, 3.0, true), None);
+    assert!(ball.intersects_bounded_ray(&m, &ray, 4.5));
+    assert!(!ball.intersects_bounded_ray(&m, &ray, 3.5));
+
+    let shape: &Shape<Point2<f64>, Isometry2<f64>> = &ball;
+    let inter = shape.toi_and_normal_with_bounded_ray(&m, &ray, 5.0, true).unwrap();
+    assert_eq!(inter.toi, 4.0);
+    assert!(shape.toi_and_normal_with_bounded_ray(&m, &ray, 3.0, true).is_none());
+}
+
+#[test]
+fn bounded_ray_composite_shapes() {
+    let vertices = vec![
+        Point3::new(-1.0f64, -1.0, 2.0),
+        Point3::new(1.0, -1.0, 2.0),
+        Point3::new(0.0, 1.0, 2.0),
+    ];
+    let mesh = TriMesh::new(
+        Arc::new(vertices),
+        Arc::new(vec![Point3::new(0usize, 1, 2)]),
+        None,
+        None,
+    );
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+
+    assert_eq!(mesh.toi_with_bounded_ray(&m, &ray, 3.0, true), Some(2.0));
+    assert_eq!(mesh.toi_with_bounded_ray(&m, &ray, 1.0, true), None);
+    assert!(mesh.toi_and_normal_with_bounded_ray(&m, &ray, 1.0, true).is_none());
+
+    // The parts farther than `max_toi` are not ray cast.
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(0.0, 5.0), 0.0), ShapeHandle::new(Ball::new(1.0))),
+        (Isometry2::new(Vector2::new(10.0, 0.0), 0.0), ShapeHandle::new(Tripwire)),
+    ]);
+    let m = Isometry2::identity();
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+
+    assert!(compound.toi_with_bounded_ray(&m, &ray, 5.0, true).is_none());
+    assert_eq!(TRIPWIRE_CASTS.load(Ordering::SeqCst), 0);
+    assert_eq!(compound.toi_with_bounded_ray(&m, &ray, 10.0, true), Some(9.0));
+    assert_eq!(TRIPWIRE_CASTS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn bounded_non_solid_ray_inside_composite_shape_aabb() {
+    // The ray starts inside of the compound AABB, which it leaves farther than `max_toi`.
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-3.0, 0.0), 0.0), ShapeHandle::new(Ball::new(1.0f64))),
+        (Isometry2::new(Vector2::new(1.5, 0.0), 0.0), ShapeHandle::new(Ball::new(1.0))),
+    ]);
+    let m = Isometry2::identity();
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+
+    assert_eq!(compound.toi_with_bounded_ray(&m, &ray, 1.0, false), Some(0.5));
+
+    let inter = compound.toi_and_normal_with_bounded_ray(&m, &ray, 1.0, false).unwrap();
+    assert_eq!(inter.toi, 0.5);
+    assert_eq!(inter.part, Some(1));
+}
+
+#[test]
+fn bounded_ray_collision_world() {
+    let mut world = CollisionWorld2::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0f64));
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let m1 = Isometry2::new(Vector2::new(3.0, 0.0), 0.0);
+    let m2 = Isometry2::new(Vector2::new(10.0, 0.0), 0.0);
+    let near = world.add(m1, shape.clone(), groups, query, ());
+    let _ = world.add(m2, shape, groups, query, ());
+    world.update();
+
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    assert_eq!(world.interferences_with_ray(&ray, &groups).count(), 2);
+
+    let hits: Vec<_> = world.interferences_with_bounded_ray(&ray, 5.0, &groups).collect();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].0.handle(), near);
+    assert_eq!(hits[0].1.toi, 2.0);
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Capsule, ShapeHandle};
+use ncollide::query::{self, Ray, RayCast};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+#[test]
+fn capsule_shape_queries() {
+    let capsule = ShapeHandle::new(Capsule::new(1.0f64, 0.5));
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.9, 1.0, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &*capsule, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_eq!(query::distance(&m1, &*capsule, &m2, &*ball), 0.0);
+
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    let toi = capsule.toi_with_ray(&m1, &ray, true).unwrap();
+    assert_relative_eq!(toi, 3.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn capsule_in_collision_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let shape = ShapeHandle::new(Capsule::new(1.0f32, 0.5));
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let _ = world.add(
+        Isometry3::identity(),
+        shape.clone(),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.9, 0.0, 0.0), na::zero()),
+        shape.clone(),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+
+    assert!(world.contacts().count() > 0);
+}
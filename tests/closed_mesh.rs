@@ -0,0 +1,128 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Polyline, TriMesh};
+use ncollide::query::{PointQuery, Ray, RayCast};
+
+fn cube() -> TriMesh<Point3<f64>> {
+    let mut vertices = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        vertices.push(Point3::new(x, y, z));
+    }
+
+    // Counterclockwise when seen from outside of the cube.
+    let indices = vec![
+        Point3::new(0usize, 2, 3),
+        Point3::new(0, 3, 1),
+        Point3::new(4, 5, 7),
+        Point3::new(4, 7, 6),
+        Point3::new(0, 1, 5),
+        Point3::new(0, 5, 4),
+        Point3::new(2, 6, 7),
+        Point3::new(2, 7, 3),
+        Point3::new(0, 4, 6),
+        Point3::new(0, 6, 2),
+        Point3::new(1, 3, 7),
+        Point3::new(1, 7, 5),
+    ];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn closed_trimesh_point_queries() {
+    let mut mesh = cube();
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    assert!(!mesh.contains_point(&m, &Point3::new(1.0, 2.0, 3.0)));
+    assert!(mesh.mark_as_closed());
+    assert!(mesh.is_closed());
+
+    assert!(mesh.contains_point(&m, &Point3::new(1.0, 2.0, 3.0)));
+    assert!(mesh.contains_point(&m, &Point3::new(1.9, 2.9, 3.9)));
+    assert!(!mesh.contains_point(&m, &Point3::new(2.1, 2.0, 3.0)));
+    assert!(!mesh.contains_point(&m, &Point3::new(2.1, 3.1, 4.1)));
+
+    let pt = Point3::new(1.5, 2.0, 3.0);
+    let proj = mesh.project_point(&m, &pt, true);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, pt, epsilon = 1.0e-6);
+
+    let proj = mesh.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(2.0, 2.0, 3.0), epsilon = 1.0e-6);
+    assert_relative_eq!(mesh.distance_to_point(&m, &pt, false), -0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn closed_trimesh_ray_casts() {
+    let mut mesh = cube();
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(0.5, 0.0, 0.0), Vector3::x());
+
+    assert_relative_eq!(mesh.toi_with_ray(&m, &ray, true).unwrap(), 0.5, epsilon = 1.0e-6);
+    assert!(mesh.mark_as_closed());
+    assert_relative_eq!(mesh.toi_with_ray(&m, &ray, true).unwrap(), 0.0);
+    assert_relative_eq!(mesh.toi_with_ray(&m, &ray, false).unwrap(), 0.5, epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    assert_relative_eq!(mesh.toi_with_ray(&m, &ray, true).unwrap(), 4.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn open_trimesh_cannot_be_closed() {
+    let vertices = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let indices = vec![Point3::new(0usize, 1, 2)];
+    let mut mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    assert!(!mesh.mark_as_closed());
+    assert!(!mesh.is_closed());
+    assert!(mesh.base_mesh().pseudo_normals().is_none());
+}
+
+#[test]
+fn closed_polyline_queries() {
+    // Clockwise square.
+    let vertices = vec![
+        Point2::new(-1.0f64, -1.0),
+        Point2::new(-1.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, -1.0),
+    ];
+    let indices = vec![
+        Point2::new(0usize, 1),
+        Point2::new(1, 2),
+        Point2::new(2, 3),
+        Point2::new(3, 0),
+    ];
+    let mut polyline = Polyline::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let m = Isometry2::identity();
+
+    assert!(polyline.mark_as_closed());
+    assert!(polyline.contains_point(&m, &Point2::origin()));
+    assert!(polyline.contains_point(&m, &Point2::new(0.9, -0.9)));
+    assert!(!polyline.contains_point(&m, &Point2::new(1.1, 1.1)));
+
+    let proj = polyline.project_point(&m, &Point2::new(0.0, 0.5), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(0.0, 1.0), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point2::origin(), Vector2::y());
+    assert_relative_eq!(polyline.toi_with_ray(&m, &ray, true).unwrap(), 0.0);
+
+    let ray = Ray::new(Point2::new(0.0, -5.0), Vector2::y());
+    assert_relative_eq!(polyline.toi_with_ray(&m, &ray, true).unwrap(), 4.0, epsilon = 1.0e-6);
+}
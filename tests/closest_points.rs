@@ -0,0 +1,150 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Unit, Vector2, Vector3};
+use ncollide::query::{self, ClosestPoints};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, RoundShape, ShapeHandle, Torus, TriMesh};
+
+fn witnesses<P: Copy>(res: ClosestPoints<P>) -> (P, P) {
+    match res {
+        ClosestPoints::WithinMargin(p1, p2) => (p1, p2),
+        _ => panic!("The shapes should be within the margin."),
+    }
+}
+
+#[test]
+fn closest_points_ball_ball() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry2::new(Vector2::new(0.0, 0.0), 0.0);
+    let m2 = Isometry2::new(Vector2::new(0.0, 3.0), 0.0);
+
+    let (p1, p2) = witnesses(query::closest_points(&m1, &ball, &m2, &ball, 1.5));
+    assert_relative_eq!(p1, Point2::new(0.0, 1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(p2, Point2::new(0.0, 2.0), epsilon = 1.0e-10);
+
+    assert_eq!(
+        query::closest_points(&m1, &ball, &m2, &ball, 0.5),
+        ClosestPoints::Disjoint
+    );
+
+    let m2 = Isometry2::new(Vector2::new(0.0, 1.5), 0.0);
+    assert_eq!(
+        query::closest_points(&m1, &ball, &m2, &ball, 0.5),
+        ClosestPoints::Intersecting
+    );
+}
+
+#[test]
+fn closest_points_support_maps() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 3.0, 0.0), na::zero());
+
+    // The ball is closest to an edge of the cuboid.
+    let (p1, p2) = witnesses(query::closest_points(&m1, &cuboid, &m2, &ball, 10.0));
+    let dir = Vector3::new(1.0, 1.0, 0.0).normalize();
+    assert_relative_eq!(p1, Point3::new(1.0, 1.0, 0.0), epsilon = 1.0e-4);
+    assert_relative_eq!(p2, Point3::new(3.0, 3.0, 0.0) - dir * 0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(
+        na::distance(&p1, &p2),
+        query::distance(&m1, &cuboid, &m2, &ball),
+        epsilon = 1.0e-4
+    );
+
+    let (q2, q1) = witnesses(query::closest_points(&m2, &ball, &m1, &cuboid, 10.0));
+    assert_relative_eq!(p1, q1, epsilon = 1.0e-4);
+    assert_relative_eq!(p2, q2, epsilon = 1.0e-4);
+
+    assert_eq!(
+        query::closest_points(&m1, &cuboid, &m2, &ball, 1.0),
+        ClosestPoints::Disjoint
+    );
+
+    // Rounded shapes are offset by their radius.
+    let round = RoundShape::new(cuboid.clone(), 0.25);
+    let (p1, p2) = witnesses(query::closest_points(&m1, &round, &m2, &ball, 10.0));
+    assert_relative_eq!(p1, Point3::new(1.0, 1.0, 0.0) + dir * 0.25, epsilon = 1.0e-4);
+    assert_relative_eq!(p2, Point3::new(3.0, 3.0, 0.0) - dir * 0.5, epsilon = 1.0e-4);
+
+    let m2 = Isometry3::new(Vector3::new(1.25, 0.0, 0.0), na::zero());
+    assert_eq!(
+        query::closest_points(&m1, &cuboid, &m2, &ball, 1.0),
+        ClosestPoints::Intersecting
+    );
+}
+
+#[test]
+fn closest_points_plane_and_torus() {
+    let plane = Plane::new(Unit::new_normalize(Vector3::y()));
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(1.0, 2.0, 0.0), na::zero());
+
+    let (p2, p1) = witnesses(query::closest_points(&m2, &ball, &m1, &plane, 2.0));
+    assert_relative_eq!(p1, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(p2, Point3::new(1.0, 1.5, 0.0), epsilon = 1.0e-6);
+    assert_eq!(
+        query::closest_points(&m1, &plane, &m2, &ball, 1.0),
+        ClosestPoints::Disjoint
+    );
+
+    // The core circle of the torus is approximated by a polygon.
+    let torus = Torus::new(2.0f64, 0.5);
+    let m2 = Isometry3::new(Vector3::new(4.0, 0.0, 0.0), na::zero());
+    let (p1, p2) = witnesses(query::closest_points(&m1, &torus, &m2, &ball, 2.0));
+    assert_relative_eq!(p1, Point3::new(2.5, 0.0, 0.0), epsilon = 1.0e-2);
+    assert_relative_eq!(p2, Point3::new(3.5, 0.0, 0.0), epsilon = 1.0e-2);
+}
+
+#[test]
+fn closest_points_composite_shapes() {
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-3.0, 0.0), 0.0), ball.clone()),
+        (Isometry2::new(Vector2::new(3.0, 0.0), 0.0), ball.clone()),
+    ]);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(2.0, 2.0), 0.0);
+
+    // Only the closest part is reported.
+    let (p1, p2) = witnesses(query::closest_points(&m1, &compound, &m2, &*ball, 5.0));
+    let dir = Vector2::new(-1.0, 2.0).normalize();
+    assert_relative_eq!(p1, Point2::new(3.0, 0.0) + dir * 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(p2, Point2::new(2.0, 2.0) - dir * 0.5, epsilon = 1.0e-6);
+
+    let (q2, q1) = witnesses(query::closest_points(&m2, &*ball, &m1, &compound, 5.0));
+    assert_relative_eq!(p1, q1, epsilon = 1.0e-6);
+    assert_relative_eq!(p2, q2, epsilon = 1.0e-6);
+
+    assert_eq!(
+        query::closest_points(&m1, &compound, &m2, &*ball, 1.0),
+        ClosestPoints::Disjoint
+    );
+
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.5), 0.0);
+    assert_eq!(
+        query::closest_points(&m1, &compound, &m2, &*ball, 1.0),
+        ClosestPoints::Intersecting
+    );
+
+    let mesh = TriMesh::new(
+        Arc::new(vec![
+            Point3::new(0.0f64, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+        ]),
+        Arc::new(vec![Point3::new(0usize, 1, 2)]),
+        None,
+        None,
+    );
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.25, 2.0, 0.25), na::zero());
+    let (p1, p2) = witnesses(query::closest_points(&m1, &mesh, &m2, &Ball::new(0.5), 2.0));
+    assert_relative_eq!(p1, Point3::new(0.25, 0.0, 0.25), epsilon = 1.0e-4);
+    assert_relative_eq!(p2, Point3::new(0.25, 1.5, 0.25), epsilon = 1.0e-4);
+}
@@ -0,0 +1,198 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Unit, Vector2, Vector3};
+use ncollide::shape::{Ball, ConvexPolyhedron, FeatureId, ShapeHandle, SupportMap};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+use ncollide::transformation;
+
+fn cube() -> ConvexPolyhedron<f64> {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(x, y, z));
+    }
+
+    // An interior point that must not be part of the polyhedron.
+    points.push(Point3::new(0.1, 0.2, 0.3));
+
+    transformation::convex_polyhedron(&points).unwrap()
+}
+
+#[test]
+fn convex_polyhedron_topology() {
+    let cube = cube();
+
+    assert_eq!(cube.points().len(), 8);
+    assert_eq!(cube.edges().len(), 12);
+    assert_eq!(cube.faces().len(), 6);
+
+    for face in cube.faces() {
+        assert_eq!(face.vertices().len(), 4);
+        assert_eq!(face.edges().len(), 4);
+    }
+
+    for vertex in cube.vertices() {
+        assert_eq!(vertex.edges().len(), 3);
+        assert_eq!(vertex.faces().len(), 3);
+    }
+}
+
+#[test]
+fn convex_polyhedron_support_features() {
+    let cube = cube();
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+    let eps_angle = 1.0e-3;
+
+    let pt = cube.support_point(&m, &Vector3::new(1.0, 1.0, 1.0));
+    assert_relative_eq!(pt, Point3::new(2.0, 3.0, 4.0), epsilon = 1.0e-6);
+
+    let pt = cube.support_point(&m, &Vector3::new(-1.0, 0.5, -2.0));
+    assert_relative_eq!(pt, Point3::new(0.0, 3.0, 2.0), epsilon = 1.0e-6);
+
+    let feature = cube.support_feature_id_toward(&Vector3::x_axis(), eps_angle);
+    match feature {
+        FeatureId::Face(_) => {}
+        _ => panic!("Expected a face, found: {:?}", feature),
+    }
+    assert_relative_eq!(
+        cube.feature_normal(feature).unwrap(),
+        Vector3::x(),
+        epsilon = 1.0e-6
+    );
+
+    let dir = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    let feature = cube.support_feature_id_toward(&dir, eps_angle);
+    match feature {
+        FeatureId::Edge(_) => {}
+        _ => panic!("Expected an edge, found: {:?}", feature),
+    }
+    assert_relative_eq!(cube.feature_normal(feature), dir, epsilon = 1.0e-6);
+
+    let dir = Unit::new_normalize(Vector3::new(1.0, 1.0, 1.0));
+    match cube.support_feature_id_toward(&dir, eps_angle) {
+        FeatureId::Vertex(v) => {
+            assert_relative_eq!(cube.points()[v], Point3::new(1.0, 1.0, 1.0), epsilon = 1.0e-6)
+        }
+        feature => panic!("Expected a vertex, found: {:?}", feature),
+    }
+}
+
+#[test]
+fn convex_polyhedron_queries() {
+    let cube = cube();
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(-5.0, 0.5, 0.0), Vector3::x());
+    let inter = cube.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    assert_relative_eq!(cube.toi_with_ray(&m, &ray, true).unwrap(), 0.0);
+    let inter = cube.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(inter.toi, 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(-5.0, 1.5, 0.0), Vector3::x());
+    assert!(cube.toi_with_ray(&m, &ray, true).is_none());
+
+    let proj = cube.project_point(&m, &Point3::new(3.0, 0.0, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-6);
+    assert!(cube.contains_point(&m, &Point3::new(0.9, -0.9, 0.9)));
+
+    let cube = ShapeHandle::new(cube);
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let m2 = Isometry3::new(Vector3::new(1.4, 0.0, 0.0), na::zero());
+    let contact = query::contact(&m, &*cube, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn convex_polyhedron_drops_vertices_inside_of_faces() {
+    // A square pyramid which base is a fan of four triangles around its center, the point 0.
+    let points = vec![
+        Point3::new(0.0f64, -1.0, 0.0),
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, 1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let indices = [
+        Point3::new(0, 1, 2),
+        Point3::new(0, 2, 3),
+        Point3::new(0, 3, 4),
+        Point3::new(0, 4, 1),
+        Point3::new(5, 2, 1),
+        Point3::new(5, 3, 2),
+        Point3::new(5, 4, 3),
+        Point3::new(5, 1, 4),
+    ];
+    let pyramid = ConvexPolyhedron::try_new(points, &indices).unwrap();
+    let m = Isometry3::identity();
+
+    assert_eq!(pyramid.points().len(), 5);
+    assert_eq!(pyramid.faces().len(), 5);
+    assert_eq!(pyramid.edges().len(), 8);
+    assert!(!pyramid.points().contains(&Point3::new(0.0, -1.0, 0.0)));
+
+    let pt = pyramid.support_point(&m, &Vector3::y());
+    assert_relative_eq!(pt, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-6);
+    let pt = pyramid.support_point(&m, &Vector3::new(1.0, -1.0, 1.0));
+    assert_relative_eq!(pt, Point3::new(1.0, -1.0, 1.0), epsilon = 1.0e-6);
+
+    for v in 0..pyramid.vertices().len() {
+        let normal = pyramid.feature_normal(FeatureId::Vertex(v));
+        assert!(normal.iter().all(|x| x.is_finite()));
+    }
+}
+
+#[test]
+fn convex_polyhedron_rejects_open_mesh() {
+    let points = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+
+    assert!(ConvexPolyhedron::try_new(points, &[Point3::new(0, 1, 2)]).is_none());
+}
+
+#[test]
+fn convex_polygon_queries() {
+    let points = [
+        Point2::new(1.0f64, 1.0),
+        Point2::new(-1.0, 1.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(-1.0, -1.0),
+        Point2::new(0.2, 0.3),
+        Point2::new(1.0, -1.0),
+    ];
+    let square = transformation::convex_polygon(&points).unwrap();
+    let m = Isometry2::identity();
+
+    assert_eq!(square.points().len(), 4);
+
+    let pt = square.support_point(&m, &Vector2::new(1.0, 2.0));
+    assert_relative_eq!(pt, Point2::new(1.0, 1.0), epsilon = 1.0e-6);
+
+    match square.support_feature_id_toward(&Vector2::y_axis(), 1.0e-3) {
+        FeatureId::Face(f) => assert_relative_eq!(*square.normals()[f], Vector2::y()),
+        feature => panic!("Expected a face, found: {:?}", feature),
+    }
+
+    let ray = Ray::new(Point2::new(0.0, -5.0), Vector2::y());
+    let inter = square.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector2::y(), epsilon = 1.0e-6);
+
+    let proj = square.project_point(&m, &Point2::new(0.0, 3.0), true);
+    assert_relative_eq!(proj.point, Point2::new(0.0, 1.0), epsilon = 1.0e-6);
+}
@@ -0,0 +1,186 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, ShapeHandle, TriMesh};
+use ncollide::query::{PointQuery, Ray, RayCast};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// The closed boundary of the cube [-1, 1]³.
+fn cube() -> TriMesh<Point3<f64>> {
+    let mut vertices = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        vertices.push(Point3::new(x, y, z));
+    }
+
+    let indices = vec![
+        Point3::new(0usize, 2, 3),
+        Point3::new(0, 3, 1),
+        Point3::new(4, 5, 7),
+        Point3::new(4, 7, 6),
+        Point3::new(0, 1, 5),
+        Point3::new(0, 5, 4),
+        Point3::new(2, 6, 7),
+        Point3::new(2, 7, 3),
+        Point3::new(0, 4, 6),
+        Point3::new(0, 6, 2),
+        Point3::new(1, 3, 7),
+        Point3::new(1, 7, 5),
+    ];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+// A square of side 4 in the plane `y = 0`.
+fn ground() -> TriMesh<Point3<f64>> {
+    let vertices = vec![
+        Point3::new(-2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, 2.0),
+        Point3::new(-2.0, 0.0, 2.0),
+    ];
+    let indices = vec![Point3::new(0usize, 2, 1), Point3::new(0, 3, 2)];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn trimesh_update_vertices_refits_bvt() {
+    let mut mesh = cube();
+    let shared = mesh.vertices().clone();
+    let m = Isometry3::identity();
+
+    mesh.update_vertices(|vs| {
+        for v in vs.iter_mut() {
+            v.x *= 2.0;
+        }
+    });
+
+    // The shared vertex buffer is left untouched.
+    assert_relative_eq!(shared[1], Point3::new(1.0, -1.0, -1.0));
+    assert_relative_eq!(mesh.vertices()[1], Point3::new(2.0, -1.0, -1.0));
+
+    let aabb = bounding_volume::aabb(&mesh, &m);
+    let root = mesh.bvt().root_bounding_volume().unwrap();
+    assert_relative_eq!(*root.mins(), Point3::new(-2.0, -1.0, -1.0));
+    assert_relative_eq!(*root.maxs(), Point3::new(2.0, 1.0, 1.0));
+    assert_relative_eq!(*aabb.mins(), *root.mins());
+    assert_relative_eq!(*aabb.maxs(), *root.maxs());
+
+    let ray = Ray::new(Point3::new(5.0, 0.1, 0.2), -Vector3::x());
+    assert_relative_eq!(mesh.toi_with_ray(&m, &ray, true).unwrap(), 3.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn trimesh_set_vertices_keeps_closed() {
+    let mut mesh = cube();
+    assert!(mesh.mark_as_closed());
+
+    let vertices = mesh
+        .vertices()
+        .iter()
+        .map(|v| *v + Vector3::new(10.0, 0.0, 0.0))
+        .collect();
+    mesh.set_vertices(Arc::new(vertices));
+
+    let m = Isometry3::identity();
+    assert!(mesh.is_closed());
+    assert!(mesh.contains_point(&m, &Point3::new(10.5, 0.0, 0.0)));
+    assert!(!mesh.contains_point(&m, &Point3::origin()));
+}
+
+#[test]
+fn trimesh_update_vertices_discards_normals() {
+    let mesh = ground();
+    let normals = Some(Arc::new(vec![Vector3::y(); 4]));
+    let mut mesh = TriMesh::new(mesh.vertices().clone(), mesh.indices().clone(), None, normals);
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(0.5, 1.0, 0.5), -Vector3::y());
+
+    // Tilt the square around the `z` axis so that the given normals become invalid.
+    mesh.update_vertices(|vs| {
+        for v in vs.iter_mut() {
+            v.y = v.x;
+        }
+    });
+    assert!(mesh.normals().is_none());
+
+    let inter = mesh.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        inter.normal,
+        Vector3::new(-1.0, 1.0, 0.0).normalize(),
+        epsilon = 1.0e-6
+    );
+
+    let tilted = Arc::new(vec![Vector3::new(-1.0, 1.0, 0.0).normalize(); 4]);
+    mesh.set_normals(Some(tilted.clone()));
+    assert_eq!(mesh.normals().as_ref().unwrap()[..], tilted[..]);
+}
+
+#[test]
+fn trimesh_bvt_rebuild_threshold() {
+    let mut mesh = cube();
+    let m = Isometry3::identity();
+    mesh.set_bvt_rebuild_threshold(Some(1.5));
+
+    // Swap the two halves of the cube so that the refitted tree nodes overlap.
+    mesh.update_vertices(|vs| {
+        for v in vs.iter_mut() {
+            v.x = -v.x * 3.0;
+        }
+    });
+
+    let depth = mesh.bvt().depth();
+    mesh.rebuild_bvt();
+    assert_eq!(mesh.bvt().depth(), depth);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.1, 0.2), Vector3::x());
+    assert_relative_eq!(mesh.toi_with_ray(&m, &ray, true).unwrap(), 2.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn trimesh_deformed_in_collision_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut mesh = ground();
+
+    let mesh_handle = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(mesh.clone()),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.5, 1.0, 0.5), na::zero()),
+        ShapeHandle::new(Ball::new(0.5)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+
+    // Raise the ground so that it penetrates the ball.
+    mesh.update_vertices(|vs| {
+        for v in vs.iter_mut() {
+            v.y = 0.7;
+        }
+    });
+    world.set_shape(mesh_handle, ShapeHandle::new(mesh));
+    world.update();
+
+    let contacts: Vec<_> = world.contacts().collect();
+    assert!(contacts.len() > 0);
+    assert_relative_eq!(contacts[0].2.depth, 0.2, epsilon = 1.0e-6);
+}
@@ -0,0 +1,134 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Point2, Vector2, Vector3};
+use ncollide::bounding_volume;
+use ncollide::events::ContactEvent;
+use ncollide::shape::{Ball, CompositeShape, Cuboid, DynamicCompound, ShapeHandle};
+use ncollide::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+fn cuboid2() -> ShapeHandle<Point2<f64>, Isometry2<f64>> {
+    ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.5)))
+}
+
+#[test]
+fn dynamic_compound_edition() {
+    let mut compound = DynamicCompound::new();
+    let a = compound.insert(Isometry2::new(Vector2::new(-2.0, 0.0), 0.0), cuboid2());
+    let b = compound.insert(Isometry2::new(Vector2::new(2.0, 0.0), 0.0), cuboid2());
+    let m = Isometry2::identity();
+
+    assert_eq!(compound.len(), 2);
+    assert!(compound.contains_point(&m, &Point2::new(-2.2, 0.2)));
+    assert!(!compound.contains_point(&m, &Point2::origin()));
+
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    assert_relative_eq!(compound.toi_with_ray(&m, &ray, true).unwrap(), 2.5);
+
+    // Removed parts can no longer be hit and their indices are not reused.
+    assert!(compound.remove(a).is_some());
+    assert!(compound.remove(a).is_none());
+    assert!(!CompositeShape::<_, Isometry2<f64>>::contains_part(&compound, a));
+    assert_relative_eq!(compound.toi_with_ray(&m, &ray, true).unwrap(), 6.5);
+
+    let c = compound.insert(Isometry2::new(Vector2::new(0.0, 3.0), 0.0), cuboid2());
+    assert!(c != a && c != b);
+
+    // Move a part far away from its previous bounding volume.
+    compound.set_part_position(b, Isometry2::new(Vector2::new(0.0, -3.0), 0.0));
+    assert!(compound.toi_with_ray(&m, &ray, true).is_none());
+    let proj = compound.project_point(&m, &Point2::new(0.0, -5.0), true);
+    assert_relative_eq!(proj.point, Point2::new(0.0, -3.5));
+
+    let aabb = bounding_volume::aabb(&compound, &m);
+    assert!(aabb.mins().y <= -3.5 && aabb.maxs().y >= 3.5);
+}
+
+#[test]
+fn empty_dynamic_compound_queries() {
+    let mut compound = DynamicCompound::new();
+    let m = Isometry2::new(Vector2::new(1.0, 2.0), 0.0);
+    let pt = Point2::new(4.0, 6.0);
+
+    // An empty compound behaves like a single point at its local origin.
+    let proj = compound.project_point(&m, &pt, true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 2.0));
+    assert_relative_eq!(compound.distance_to_point(&m, &pt, true), 5.0);
+    assert!(!compound.contains_point(&m, &pt));
+
+    let ray = Ray::new(Point2::new(-5.0, 2.0), Vector2::x());
+    assert!(compound.toi_with_ray(&m, &ray, true).is_none());
+
+    // Pairwise queries do not find any part to interact with.
+    let ball = Ball::new(1.0);
+    let ball_m = Isometry2::new(Vector2::new(1.0, 2.0), 0.0);
+    assert_eq!(query::distance(&m, &compound, &ball_m, &ball), std::f64::MAX);
+    assert_eq!(query::distance(&ball_m, &ball, &m, &compound), std::f64::MAX);
+    assert!(query::contact(&m, &compound, &ball_m, &ball, 1.0).is_none());
+    assert_eq!(
+        query::proximity(&m, &compound, &ball_m, &ball, 1.0),
+        Proximity::Disjoint
+    );
+
+    // Removing all the parts of a compound makes it empty again.
+    let a = compound.insert(Isometry2::identity(), cuboid2());
+    assert_relative_eq!(compound.project_point(&m, &pt, true).point, Point2::new(1.5, 2.5));
+    assert!(compound.remove(a).is_some());
+    assert_relative_eq!(compound.project_point(&m, &pt, true).point, Point2::new(1.0, 2.0));
+}
+
+#[test]
+fn dynamic_compound_contact() {
+    let mut compound = DynamicCompound::new();
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)));
+    let _ = compound.insert(Isometry3::new(Vector3::new(0.0, 1.0, 0.0), na::zero()), cuboid);
+    let compound = ShapeHandle::new(compound);
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.9, 0.0), na::zero());
+    let contact = query::contact(&m1, &*compound, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::y(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn dynamic_compound_part_removal_in_collision_world() {
+    let mut world = CollisionWorld2::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut compound = DynamicCompound::new();
+    let a = compound.insert(Isometry2::new(Vector2::new(-1.0, 0.0), 0.0), cuboid2());
+    let _ = compound.insert(Isometry2::new(Vector2::new(1.0, 0.0), 0.0), cuboid2());
+
+    let compound_handle = world.add(
+        Isometry2::identity(),
+        ShapeHandle::new(compound.clone()),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::new(Vector2::new(-1.0, 0.9), 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+    assert_eq!(world.contacts().count(), 1);
+
+    // Destroy the part touching the ball.
+    let _ = compound.remove(a);
+    world.set_shape(compound_handle, ShapeHandle::new(compound));
+    world.update();
+
+    assert_eq!(world.contacts().count(), 0);
+    assert!(world.contact_events().iter().any(|e| match *e {
+        ContactEvent::Stopped(..) => true,
+        _ => false,
+    }));
+}
@@ -0,0 +1,122 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, Ellipsoid, ShapeHandle, SupportMap};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+use ncollide::transformation::{ToPolyline, ToTriMesh};
+
+#[test]
+fn ellipsoid_support_and_bounding_volumes() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(3.0f64, 2.0, 1.0));
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::z() * f64::consts::FRAC_PI_2);
+
+    // The local `x` axis is mapped to the world `y` axis.
+    let pt = ellipsoid.support_point(&m, &Vector3::y());
+    assert_relative_eq!(pt, Point3::new(1.0, 3.0, 0.0), epsilon = 1.0e-6);
+
+    let pt = ellipsoid.support_point(&Isometry3::identity(), &Vector3::new(1.0, 1.0, 0.0));
+    assert_relative_eq!(pt, Point3::new(9.0, 4.0, 0.0) / 13.0f64.sqrt(), epsilon = 1.0e-6);
+
+    let aabb = bounding_volume::aabb(&ellipsoid, &m);
+    assert_relative_eq!(*aabb.mins(), Point3::new(-1.0, -3.0, -1.0), epsilon = 1.0e-6);
+    assert_relative_eq!(*aabb.maxs(), Point3::new(3.0, 3.0, 1.0), epsilon = 1.0e-6);
+
+    let bs = bounding_volume::bounding_sphere(&ellipsoid, &m);
+    assert_relative_eq!(bs.radius(), 3.0);
+}
+
+#[test]
+fn ellipsoid_ray_cast() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(3.0f64, 2.0, 1.0));
+    let m = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 1.0), Vector3::x());
+    let inter = ellipsoid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(0.0, 0.0, 1.0), Vector3::y());
+    assert_relative_eq!(ellipsoid.toi_with_ray(&m, &ray, true).unwrap(), 0.0);
+    let inter = ellipsoid.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::y(), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(0.0, 0.0, 2.5), Vector3::x());
+    assert!(ellipsoid.toi_with_ray(&m, &ray, true).is_none());
+}
+
+#[test]
+fn ellipsoid_point_projection() {
+    let ellipsoid = Ellipsoid::new(Vector2::new(2.0f64, 1.0));
+    let m = Isometry2::new(Vector2::new(1.0, 1.0), 0.0);
+
+    assert!(ellipsoid.contains_point(&m, &Point2::new(2.9, 1.0)));
+    assert!(!ellipsoid.contains_point(&m, &Point2::new(2.0, 2.0)));
+
+    let proj = ellipsoid.project_point(&m, &Point2::new(5.0, 1.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(3.0, 1.0), epsilon = 1.0e-6);
+
+    // The projection of a point outside of the ellipse must be its closest point: the error
+    // vector is orthogonal to the boundary.
+    let pt = Point2::new(4.0, 3.0);
+    let proj = ellipsoid.project_point(&m, &pt, true);
+    let local = proj.point - Vector2::new(1.0, 1.0);
+    let gradient = Vector2::new(local.x / 4.0, local.y);
+    assert_relative_eq!(local.x * local.x / 4.0 + local.y * local.y, 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        na::normalize(&(pt - proj.point)),
+        na::normalize(&gradient),
+        epsilon = 1.0e-6
+    );
+
+    // The closest boundary point from the center is on the smallest axis.
+    let proj = ellipsoid.project_point(&m, &Point2::new(1.0, 1.0), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point.y, 2.0, epsilon = 1.0e-6);
+    let dist = ellipsoid.distance_to_point(&m, &Point2::new(1.0, 1.0), false);
+    assert_relative_eq!(dist, -1.0, epsilon = 1.0e-6);
+
+    // Interior point near the major axis: the projection leaves the axis.
+    let proj = ellipsoid.project_point(&Isometry2::identity(), &Point2::new(0.5, 0.0), false);
+    let expected = Point2::new(2.0 / 3.0, 8.0f64.sqrt() / 3.0);
+    assert_relative_eq!(proj.point, expected, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ellipsoid_against_ball() {
+    let ellipsoid = ShapeHandle::new(Ellipsoid::new(Vector3::new(2.0f64, 1.0, 1.0)));
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(2.4, 0.0, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &*ellipsoid, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn ellipsoid_discretization() {
+    let radii = Vector3::new(3.0f64, 2.0, 1.0);
+    let mesh = Ellipsoid::new(radii).to_trimesh((10, 10));
+
+    for pt in mesh.coords.iter() {
+        let x = pt.coords.component_div(&radii);
+        assert_relative_eq!(na::norm(&x), 1.0, epsilon = 1.0e-6);
+    }
+
+    let radii = Vector2::new(3.0f64, 1.0);
+    let polyline = Ellipsoid::new(radii).to_polyline(10);
+    assert_eq!(polyline.coords().len(), 10);
+
+    for pt in polyline.coords() {
+        let x = pt.coords.component_div(&radii);
+        assert_relative_eq!(na::norm(&x), 1.0, epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, HeightField, ShapeHandle};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// A 2x2 cells heightfield covering [-2, 2]x[-2, 2] with a bump at the origin.
+fn bump3() -> HeightField<Point3<f64>> {
+    let heights = DMatrix::from_row_slice(3, 3, &[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+
+    HeightField::new(heights, Vector3::new(4.0, 1.0, 4.0))
+}
+
+#[test]
+fn heightfield3_ray_cast() {
+    let hf = bump3();
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(0.5, 10.0, 0.25), -Vector3::y());
+    let inter = hf.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 9.25, epsilon = 1.0e-6);
+
+    // Horizontal ray crossing several cells before hitting the bump.
+    let ray = Ray::new(Point3::new(-2.5, 0.5, 0.5), Vector3::x());
+    let toi = hf.toi_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(toi, 2.0, epsilon = 1.0e-6);
+
+    // Outside of the heightfield domain.
+    let ray = Ray::new(Point3::new(3.0, 10.0, 0.0), -Vector3::y());
+    assert!(hf.toi_with_ray(&m, &ray, true).is_none());
+
+    // Above the heightfield.
+    let ray = Ray::new(Point3::new(-2.5, 1.5, -1.0), Vector3::new(1.0, 0.0, 0.3));
+    assert!(hf.toi_with_ray(&m, &ray, true).is_none());
+}
+
+#[test]
+fn heightfield3_point_projection() {
+    let hf = bump3();
+    let m = Isometry3::new(Vector3::new(0.0, 1.0, 0.0), na::zero());
+    let pt = Point3::new(1.5, 4.0, 1.0);
+
+    let proj = hf.project_point(&m, &pt, true);
+
+    let mut best = ::std::f64::MAX;
+    for i in 0..hf.num_parts() {
+        let p = hf.triangle_at(i).project_point(&m, &pt, true);
+        best = best.min(na::distance(&p.point, &pt));
+    }
+
+    assert_relative_eq!(na::distance(&proj.point, &pt), best, epsilon = 1.0e-6);
+}
+
+#[test]
+fn heightfield3_contact_with_ball() {
+    let hf = ShapeHandle::new(bump3());
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(1.8, 0.3, -1.8), na::zero());
+
+    let contact = query::contact(&m1, &*hf, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.2, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::y(), epsilon = 1.0e-6);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 3.0, 0.0), na::zero());
+    assert_relative_eq!(query::distance(&m1, &*hf, &m2, &*ball), 1.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn heightfield3_in_collision_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(bump3()),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(1.8, 0.3, -1.8), na::zero()),
+        ShapeHandle::new(Ball::new(0.5)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+
+    assert!(world.contacts().count() > 0);
+}
+
+#[test]
+fn heightfield2_queries() {
+    let heights = DMatrix::from_column_slice(3, 1, &[0.0, 1.0, 0.0]);
+    let hf = ShapeHandle::new(HeightField::new(heights, Vector2::new(4.0, 1.0)));
+    let m = Isometry2::identity();
+
+    let ray = Ray::new(Point2::new(1.0, 5.0), -Vector2::y());
+    let toi = hf.as_ray_cast().unwrap().toi_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(toi, 4.5, epsilon = 1.0e-6);
+
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m2 = Isometry2::new(Vector2::new(0.0, 3.0), na::zero());
+    assert_relative_eq!(query::distance(&m, &*hf, &m2, &*ball), 1.5, epsilon = 1.0e-6);
+}
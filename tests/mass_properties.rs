@@ -0,0 +1,177 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Matrix3, Point2, Point3, Vector2, Vector3};
+use ncollide::mass_properties::{AngularInertia, MassProperties};
+use ncollide::shape::{Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, Polyline,
+                      Shape, ShapeHandle, TriMesh};
+
+fn tensor(inertia: AngularInertia<f64>) -> Matrix3<f64> {
+    match inertia {
+        AngularInertia::Tensor(tensor) => tensor,
+        AngularInertia::Scalar(_) => panic!("Expected a 3D angular inertia."),
+    }
+}
+
+fn scalar(inertia: AngularInertia<f64>) -> f64 {
+    match inertia {
+        AngularInertia::Scalar(scalar) => scalar,
+        AngularInertia::Tensor(_) => panic!("Expected a 2D angular inertia."),
+    }
+}
+
+fn cuboid_trimesh(half_extents: Vector3<f64>) -> TriMesh<Point3<f64>> {
+    let mut vertices = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -half_extents.x } else { half_extents.x };
+        let y = if i & 2 == 0 { -half_extents.y } else { half_extents.y };
+        let z = if i & 4 == 0 { -half_extents.z } else { half_extents.z };
+        vertices.push(Point3::new(x, y, z));
+    }
+
+    let indices = vec![
+        Point3::new(0usize, 2, 1),
+        Point3::new(1, 2, 3),
+        Point3::new(4, 5, 6),
+        Point3::new(5, 7, 6),
+        Point3::new(0, 1, 4),
+        Point3::new(1, 5, 4),
+        Point3::new(2, 6, 3),
+        Point3::new(3, 6, 7),
+        Point3::new(0, 4, 2),
+        Point3::new(2, 4, 6),
+        Point3::new(1, 3, 5),
+        Point3::new(3, 7, 5),
+    ];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn mass_properties_primitives3() {
+    let ball = Ball::new(2.0f64);
+    let (mass, center, inertia) = MassProperties::<Point3<f64>>::mass_properties(&ball, 3.0);
+    let expected_mass = 3.0 * 4.0 / 3.0 * PI * 8.0;
+    assert_relative_eq!(mass, expected_mass, epsilon = 1.0e-7);
+    assert_relative_eq!(center, Point3::origin());
+    assert_relative_eq!(
+        tensor(inertia),
+        Matrix3::from_diagonal_element(expected_mass * 0.4 * 4.0),
+        epsilon = 1.0e-7
+    );
+
+    let cylinder = Cylinder::new(2.0f64, 1.0);
+    let cone = Cone::new(2.0f64, 1.0);
+    let capsule = Capsule::new(2.0f64, 1.0);
+    let cylinder_volume = MassProperties::<Point3<f64>>::volume(&cylinder);
+    let cone_center = MassProperties::<Point3<f64>>::center_of_mass(&cone);
+    let capsule_volume = MassProperties::<Point3<f64>>::volume(&capsule);
+    assert_relative_eq!(cylinder_volume, 4.0 * PI, epsilon = 1.0e-7);
+    assert_relative_eq!(cone_center, Point3::new(0.0, -1.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(capsule_volume, 4.0 * PI + 4.0 / 3.0 * PI, epsilon = 1.0e-7);
+
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let mesh = cuboid_trimesh(*cuboid.half_extents());
+    let (mass1, center1, inertia1) = MassProperties::<Point3<f64>>::mass_properties(&cuboid, 2.0);
+    let (mass2, center2, inertia2) = mesh.mass_properties(2.0);
+    assert_relative_eq!(mass1, 96.0, epsilon = 1.0e-7);
+    assert_relative_eq!(mass1, mass2, epsilon = 1.0e-7);
+    assert_relative_eq!(center1, center2, epsilon = 1.0e-7);
+    assert_relative_eq!(tensor(inertia1), tensor(inertia2), epsilon = 1.0e-7);
+
+    let hull = ConvexHull::new(mesh.vertices().to_vec());
+    let (mass3, center3, inertia3) = hull.mass_properties(2.0);
+    assert_relative_eq!(mass1, mass3, epsilon = 1.0e-7);
+    assert_relative_eq!(center1, center3, epsilon = 1.0e-7);
+    assert_relative_eq!(tensor(inertia1), tensor(inertia3), epsilon = 1.0e-7);
+}
+
+#[test]
+fn mass_properties_primitives2() {
+    let ball = Ball::new(2.0f64);
+    let (mass, _, inertia) = MassProperties::<Point2<f64>>::mass_properties(&ball, 1.0);
+    assert_relative_eq!(mass, 4.0 * PI, epsilon = 1.0e-7);
+    assert_relative_eq!(scalar(inertia), 4.0 * PI * 2.0, epsilon = 1.0e-7);
+
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 2.0));
+    let vertices = vec![
+        Point2::new(-1.0, -2.0),
+        Point2::new(1.0, -2.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(-1.0, 2.0),
+    ];
+    let indices = vec![
+        Point2::new(0usize, 1),
+        Point2::new(1, 2),
+        Point2::new(2, 3),
+        Point2::new(3, 0),
+    ];
+    let polyline = Polyline::new(Arc::new(vertices.clone()), Arc::new(indices), None, None);
+    let hull = ConvexHull::new(vertices);
+
+    let (mass1, center1, inertia1) = MassProperties::<Point2<f64>>::mass_properties(&cuboid, 1.0);
+    let (mass2, center2, inertia2) = polyline.mass_properties(1.0);
+    let (mass3, center3, inertia3) = hull.mass_properties(1.0);
+    assert_relative_eq!(mass1, 8.0, epsilon = 1.0e-7);
+    assert_relative_eq!(scalar(inertia1), 8.0 * 5.0 / 3.0, epsilon = 1.0e-7);
+    assert_relative_eq!(mass1, mass2, epsilon = 1.0e-7);
+    assert_relative_eq!(mass1, mass3, epsilon = 1.0e-7);
+    assert_relative_eq!(center1, center2, epsilon = 1.0e-7);
+    assert_relative_eq!(center1, center3, epsilon = 1.0e-7);
+    assert_relative_eq!(scalar(inertia1), scalar(inertia2), epsilon = 1.0e-7);
+    assert_relative_eq!(scalar(inertia1), scalar(inertia3), epsilon = 1.0e-7);
+}
+
+#[test]
+fn mass_properties_degenerate_hulls() {
+    let flat = ConvexHull::new(vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 0.5, 0.0),
+    ]);
+    let point = ConvexHull::new(vec![Point3::new(1.0f64, 2.0, 3.0); 4]);
+    let segment = ConvexHull::new(vec![
+        Point2::new(0.0f64, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 2.0),
+    ]);
+
+    assert_eq!(flat.mass_properties(1.0).0, 0.0);
+    assert_eq!(point.mass_properties(1.0).0, 0.0);
+    assert_eq!(segment.mass_properties(1.0).0, 0.0);
+}
+
+#[test]
+fn mass_properties_compound() {
+    // Two unit cubes side by side form a 2x1x1 cuboid.
+    let cube = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let delta1 = Isometry3::new(Vector3::new(0.5, 0.0, 0.0), Vector3::y() * 0.3);
+    let delta2 = Isometry3::new(Vector3::new(1.5, 0.0, 0.0), na::zero());
+    let compound = Compound::new(vec![(delta1, cube.clone()), (delta2, cube)]);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 0.5, 0.5));
+
+    let props = Shape::<Point3<f64>, Isometry3<f64>>::as_mass_properties(&compound).unwrap();
+    let (mass1, center1, inertia1) = props.mass_properties(1.0);
+    let (mass2, _, inertia2) = MassProperties::<Point3<f64>>::mass_properties(&cuboid, 1.0);
+    assert_relative_eq!(mass1, mass2, epsilon = 1.0e-7);
+    assert_relative_eq!(center1, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(tensor(inertia1), tensor(inertia2), epsilon = 1.0e-7);
+
+    // A compound with a part without mass properties has no mass properties.
+    let vertices = Arc::new(vec![Point2::new(0.0f64, 0.0), Point2::new(1.0, 0.0)]);
+    let open_polyline = Polyline::new(vertices, Arc::new(vec![Point2::new(0, 1)]), None, None);
+    let parts = vec![
+        (Isometry2::identity(), ShapeHandle::new(Ball::new(1.0f64))),
+        (Isometry2::identity(), ShapeHandle::new(open_polyline)),
+    ];
+    let compound = Compound::new(parts);
+    assert!(Shape::<Point2<f64>, Isometry2<f64>>::as_mass_properties(&compound).is_none());
+}
@@ -0,0 +1,136 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+
+use na::{Isometry2, Isometry3, Unit, Vector2, Vector3};
+use ncollide::query::{self, ConstantVelocityRigidMotion2, ConstantVelocityRigidMotion3,
+                      RigidMotion, TOIStatus};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, ShapeHandle};
+
+// Finds a root of a function decreasing on `[a, b]` by bisection.
+fn bisect<F: Fn(f64) -> f64>(f: F, mut a: f64, mut b: f64) -> f64 {
+    for _ in 0..100 {
+        let mid = (a + b) / 2.0;
+
+        if f(mid) > 0.0 {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+
+    a
+}
+
+#[test]
+fn nonlinear_toi_spinning_bar() {
+    let bar = Cuboid::new(Vector2::new(2.0f64, 0.1));
+    let ball = Ball::new(0.1f64);
+    let motion1 = ConstantVelocityRigidMotion2::new(Isometry2::identity(), na::zero(), 10.0);
+    let start2 = Isometry2::new(Vector2::y() * 1.5, 0.0);
+    let motion2 = ConstantVelocityRigidMotion2::new(start2, na::zero(), 0.0);
+
+    // The bar hits the ball when its upper face is at a distance 0.1 from the ball center.
+    let expected = (0.2f64 / 1.5).acos() / 10.0;
+    let toi = query::nonlinear_time_of_impact(&motion1, &bar, &motion2, &ball, 1.0, 1.0e-6, 1000);
+    let (toi, status) = toi.unwrap();
+    assert_relative_eq!(toi, expected, epsilon = 1.0e-4);
+    assert_eq!(status, TOIStatus::Converged);
+
+    // No collision before `max_toi`.
+    let toi = query::nonlinear_time_of_impact(&motion1, &bar, &motion2, &ball, 0.1, 1.0e-6, 1000);
+    assert_eq!(toi, None);
+
+    // The time returned when the iteration limit is reached is conservative.
+    let toi = query::nonlinear_time_of_impact(&motion1, &bar, &motion2, &ball, 1.0, 1.0e-6, 2);
+    let (toi, status) = toi.unwrap();
+    assert!(toi < expected);
+    assert_eq!(status, TOIStatus::Failed);
+
+    // The shapes already intersect.
+    let toi = query::nonlinear_time_of_impact(&motion1, &bar, &motion1, &ball, 1.0, 1.0e-6, 2);
+    assert_eq!(toi, Some((0.0, TOIStatus::Penetrating)));
+}
+
+#[test]
+fn nonlinear_toi_from_isometries() {
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let ball = Ball::new(0.5f64);
+    let start = Isometry3::new(Vector3::x() * -5.0, na::zero());
+    let end = Isometry3::new(Vector3::x() * 5.0, na::zero());
+    let motion1 = ConstantVelocityRigidMotion3::from_isometries(start, &end);
+    let motion2 = ConstantVelocityRigidMotion3::new(Isometry3::identity(), na::zero(), na::zero());
+
+    // Without rotation, this matches the translational time of impact.
+    let linear_toi = query::time_of_impact(
+        &start,
+        &(Vector3::x() * 10.0),
+        &cuboid,
+        &Isometry3::identity(),
+        &Vector3::zeros(),
+        &ball,
+    ).unwrap();
+    let toi = query::nonlinear_time_of_impact(&motion1, &cuboid, &motion2, &ball, 1.0, 1.0e-6, 100)
+        .unwrap()
+        .0;
+    assert_relative_eq!(linear_toi, 0.4, epsilon = 1.0e-6);
+    assert_relative_eq!(toi, linear_toi, epsilon = 1.0e-4);
+
+    // With a rotation, the shapes touch at the time of impact.
+    let end = Isometry3::new(Vector3::x() * 5.0, Vector3::z() * PI / 2.0);
+    let motion1 = ConstantVelocityRigidMotion3::from_isometries(start, &end);
+    assert_relative_eq!(motion1.position_at_time(1.0), end, epsilon = 1.0e-10);
+
+    let toi = query::nonlinear_time_of_impact(&motion1, &cuboid, &motion2, &ball, 1.0, 1.0e-6, 100)
+        .unwrap()
+        .0;
+    let m1 = motion1.position_at_time(toi);
+    assert!(toi > 0.3 && toi < 0.4);
+    assert!(query::distance(&m1, &cuboid, &Isometry3::identity(), &ball) < 1.0e-5);
+}
+
+#[test]
+fn nonlinear_toi_plane() {
+    let plane = Plane::new(Unit::new_normalize(Vector2::y()));
+    let bar = Cuboid::new(Vector2::new(2.0f64, 0.1));
+    let motion1 = ConstantVelocityRigidMotion2::new(Isometry2::identity(), na::zero(), 0.0);
+    let start2 = Isometry2::new(Vector2::y() * 3.0, 0.0);
+    let motion2 = ConstantVelocityRigidMotion2::new(start2, -Vector2::y(), 1.0);
+
+    // The bar falls while spinning.
+    let lowest = |t: f64| 3.0 - t - 2.0 * t.sin() - 0.1 * t.cos();
+    let expected = bisect(lowest, 0.0, PI / 2.0);
+    let toi = query::nonlinear_time_of_impact(&motion1, &plane, &motion2, &bar, 10.0, 1.0e-6, 1000);
+    assert_relative_eq!(toi.unwrap().0, expected, epsilon = 1.0e-4);
+
+    // The points of a rotating plane move arbitrarily fast.
+    let motion1 = ConstantVelocityRigidMotion2::new(Isometry2::identity(), na::zero(), 1.0);
+    let motion2 = ConstantVelocityRigidMotion2::new(start2, na::zero(), 0.0);
+    let toi = query::nonlinear_time_of_impact(&motion1, &plane, &motion2, &bar, 10.0, 1.0e-6, 1000);
+    assert_eq!(toi, Some((0.0, TOIStatus::Failed)));
+}
+
+#[test]
+fn nonlinear_toi_composite_shape() {
+    let ball = ShapeHandle::new(Ball::new(0.25f64));
+    let dumbbell = Compound::new(vec![
+        (Isometry2::new(Vector2::x() * -2.0, 0.0), ball.clone()),
+        (Isometry2::new(Vector2::x() * 2.0, 0.0), ball.clone()),
+    ]);
+    let motion1 = ConstantVelocityRigidMotion2::new(Isometry2::identity(), na::zero(), 1.0);
+    let motion2 =
+        ConstantVelocityRigidMotion2::new(Isometry2::new(Vector2::y() * 2.0, 0.0), na::zero(), 0.0);
+
+    // The part centers are at a distance 0.5 when `2 * sqrt(2 - 2 * sin(t)) = 0.5`.
+    let expected = (1.0f64 - 0.0625 / 2.0).asin();
+    let toi =
+        query::nonlinear_time_of_impact(&motion1, &dumbbell, &motion2, &*ball, 10.0, 1.0e-6, 1000);
+    assert_relative_eq!(toi.unwrap().0, expected, epsilon = 1.0e-4);
+
+    let toi =
+        query::nonlinear_time_of_impact(&motion2, &*ball, &motion1, &dumbbell, 10.0, 1.0e-6, 1000);
+    assert_relative_eq!(toi.unwrap().0, expected, epsilon = 1.0e-4);
+}
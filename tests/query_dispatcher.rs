@@ -0,0 +1,244 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Point2, Unit, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::query::{self, Contact, DefaultQueryDispatcher, Proximity, QueryDispatcher,
+                      Unsupported};
+use ncollide::shape::{Ball, Compound, Cuboid, Shape, ShapeHandle};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+// A disk that does not implement any of the traits used by the default dispatcher.
+struct Disk {
+    radius: f64,
+}
+
+impl Shape<Point2<f64>, Isometry2<f64>> for Disk {
+    fn aabb(&self, m: &Isometry2<f64>) -> AABB<Point2<f64>> {
+        let center = Point2::from_coordinates(m.translation.vector);
+        let half_extents = Vector2::repeat(self.radius);
+
+        AABB::new(center - half_extents, center + half_extents)
+    }
+}
+
+// Handles the pairs involving at least one disk, the other one being a disk or a ball.
+struct DiskDispatcher;
+
+impl DiskDispatcher {
+    fn disks(
+        &self,
+        m1: &Isometry2<f64>,
+        g1: &Shape<Point2<f64>, Isometry2<f64>>,
+        m2: &Isometry2<f64>,
+        g2: &Shape<Point2<f64>, Isometry2<f64>>,
+    ) -> Result<(Point2<f64>, f64, Point2<f64>, f64), Unsupported> {
+        if !g1.is_shape::<Disk>() && !g2.is_shape::<Disk>() {
+            return Err(Unsupported::new(g1, g2));
+        }
+
+        let radius = |g: &Shape<Point2<f64>, Isometry2<f64>>| {
+            if let Some(disk) = g.as_shape::<Disk>() {
+                Some(disk.radius)
+            } else {
+                g.as_shape::<Ball<f64>>().map(|b| b.radius())
+            }
+        };
+
+        match (radius(g1), radius(g2)) {
+            (Some(r1), Some(r2)) => Ok((
+                Point2::from_coordinates(m1.translation.vector),
+                r1,
+                Point2::from_coordinates(m2.translation.vector),
+                r2,
+            )),
+            _ => Err(Unsupported::new(g1, g2)),
+        }
+    }
+}
+
+impl QueryDispatcher<Point2<f64>, Isometry2<f64>> for DiskDispatcher {
+    fn contact(
+        &self,
+        m1: &Isometry2<f64>,
+        g1: &Shape<Point2<f64>, Isometry2<f64>>,
+        m2: &Isometry2<f64>,
+        g2: &Shape<Point2<f64>, Isometry2<f64>>,
+        prediction: f64,
+    ) -> Result<Option<Contact<Point2<f64>>>, Unsupported> {
+        self.disks(m1, g1, m2, g2).map(|(c1, r1, c2, r2)| {
+            let normal = Unit::new_normalize(c2 - c1);
+            let depth = r1 + r2 - na::distance(&c1, &c2);
+
+            if depth >= -prediction {
+                Some(Contact::new(
+                    c1 + *normal * r1,
+                    c2 - *normal * r2,
+                    normal,
+                    depth,
+                ))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn distance(
+        &self,
+        m1: &Isometry2<f64>,
+        g1: &Shape<Point2<f64>, Isometry2<f64>>,
+        m2: &Isometry2<f64>,
+        g2: &Shape<Point2<f64>, Isometry2<f64>>,
+    ) -> Result<f64, Unsupported> {
+        self.disks(m1, g1, m2, g2)
+            .map(|(c1, r1, c2, r2)| (na::distance(&c1, &c2) - r1 - r2).max(0.0))
+    }
+
+    fn proximity(
+        &self,
+        m1: &Isometry2<f64>,
+        g1: &Shape<Point2<f64>, Isometry2<f64>>,
+        m2: &Isometry2<f64>,
+        g2: &Shape<Point2<f64>, Isometry2<f64>>,
+        margin: f64,
+    ) -> Result<Proximity, Unsupported> {
+        self.distance(m1, g1, m2, g2).map(|dist| {
+            if dist == 0.0 {
+                Proximity::Intersecting
+            } else if dist <= margin {
+                Proximity::WithinMargin
+            } else {
+                Proximity::Disjoint
+            }
+        })
+    }
+
+    fn time_of_impact(
+        &self,
+        _: &Isometry2<f64>,
+        _: &Vector2<f64>,
+        g1: &Shape<Point2<f64>, Isometry2<f64>>,
+        _: &Isometry2<f64>,
+        _: &Vector2<f64>,
+        g2: &Shape<Point2<f64>, Isometry2<f64>>,
+    ) -> Result<Option<f64>, Unsupported> {
+        Err(Unsupported::new(g1, g2))
+    }
+}
+
+#[test]
+fn query_dispatcher_chain() {
+    let dispatcher = DiskDispatcher.chain(DefaultQueryDispatcher);
+    let disk = Disk { radius: 1.0 };
+    let ball = Ball::new(0.5);
+    let m1 = Isometry2::new(Vector2::new(0.0, 0.0), 0.0);
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.0), 0.0);
+
+    let dist = query::distance_with_dispatcher(&dispatcher, &m1, &disk, &m2, &disk).unwrap();
+    assert_relative_eq!(dist, 1.0, epsilon = 1.0e-10);
+    let dist = query::distance_with_dispatcher(&dispatcher, &m1, &disk, &m2, &ball).unwrap();
+    assert_relative_eq!(dist, 1.5, epsilon = 1.0e-10);
+
+    // Pairs not involving disks are still handled by the default dispatcher.
+    let dist = query::distance_with_dispatcher(&dispatcher, &m1, &ball, &m2, &ball).unwrap();
+    assert_relative_eq!(dist, query::distance(&m1, &ball, &m2, &ball), epsilon = 1.0e-10);
+    assert!(DiskDispatcher.distance(&m1, &ball, &m2, &ball).is_err());
+
+    let prox = query::proximity_with_dispatcher(&dispatcher, &m1, &disk, &m2, &ball, 2.0).unwrap();
+    assert_eq!(prox, Proximity::WithinMargin);
+
+    // The disk dispatcher relies on the default implementations of `closest_points` and
+    // `shape_cast`.
+    let pts = query::closest_points_with_dispatcher(&dispatcher, &m1, &disk, &m2, &disk, 2.0);
+    assert!(pts.is_err());
+
+    let vel = Vector2::x();
+    let toi = query::shape_cast_with_dispatcher(&dispatcher, &m1, &vel, &disk, &m2, &vel, &disk);
+    assert!(toi.is_err());
+}
+
+#[test]
+fn query_dispatcher_composite_parts() {
+    let dispatcher = DiskDispatcher.chain(DefaultQueryDispatcher);
+    let disk = ShapeHandle::new(Disk { radius: 1.0 });
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-2.0, 0.0), 0.0), disk.clone()),
+        (Isometry2::new(Vector2::new(2.0, 0.0), 0.0), disk),
+    ]);
+    let ball = Ball::new(0.5);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.0), 0.0);
+
+    // The parts of the compound are dispatched through the chain as well.
+    let contact = query::contact_with_dispatcher(&dispatcher, &m1, &compound, &m2, &ball, 0.0)
+        .unwrap()
+        .expect("The compound and the ball should be in contact.");
+    assert_relative_eq!(contact.depth, 0.5, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world1, Point2::new(3.0, 0.0), epsilon = 1.0e-10);
+
+    let contact = query::contact_with_dispatcher(&dispatcher, &m2, &ball, &m1, &compound, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.normal.unwrap(), -Vector2::x(), epsilon = 1.0e-10);
+
+    let dist = query::distance_with_dispatcher(&dispatcher, &m1, &compound, &m2, &ball).unwrap();
+    assert_relative_eq!(dist, 0.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn query_dispatcher_collision_world() {
+    let mut world = CollisionWorld2::new(0.1);
+    let disk = ShapeHandle::new(Disk { radius: 1.0 });
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry2::new(Vector2::new(0.0, 0.0), 0.0),
+        disk.clone(),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::new(Vector2::new(1.5, 0.0), 0.0),
+        disk,
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+
+    // Without the dispatcher, the pipeline does not know how to handle disks.
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+
+    world.set_query_dispatcher(Arc::new(DiskDispatcher));
+    world.update();
+
+    let contacts: Vec<_> = world.contacts().collect();
+    assert_eq!(contacts.len(), 1);
+    assert_relative_eq!(contacts[0].2.depth, 0.5, epsilon = 1.0e-10);
+}
+
+#[test]
+fn query_dispatcher_unsupported_pair_events() {
+    let mut world = CollisionWorld2::new(0.1);
+    let disk = ShapeHandle::new(Disk { radius: 1.0 });
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 1.0)));
+    let groups = CollisionGroups::new();
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let proximity = GeometricQueryType::Proximity(0.0);
+    let m = Isometry2::new(Vector2::new(1.5, 0.0), 0.0);
+
+    world.set_query_dispatcher(Arc::new(DiskDispatcher));
+    let _ = world.add(Isometry2::identity(), disk, groups, contacts, ());
+    let _ = world.add(m, cuboid.clone(), groups, contacts, ());
+    let _ = world.add(m, cuboid, groups, proximity, ());
+    world.update();
+
+    // The dispatcher does not support disks against cuboids: only the two cuboids remain paired.
+    assert_eq!(world.unsupported_pair_events().iter().count(), 2);
+    assert_eq!(world.contact_pairs().count(), 0);
+    assert_eq!(world.proximity_pairs().count(), 1);
+}
@@ -0,0 +1,138 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide::query::{CullMode, Ray, RayCast};
+use ncollide::shape::{Compound, DynamicCompound, Plane, Scaled, ShapeHandle, TriMesh, Triangle};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// Two triangles facing each other, at `z = 2` and `z = 5`.
+fn facing_triangles() -> TriMesh<Point3<f64>> {
+    TriMesh::new(
+        Arc::new(vec![
+            Point3::new(-1.0, -1.0, 2.0),
+            Point3::new(1.0, -1.0, 2.0),
+            Point3::new(0.0, 1.0, 2.0),
+            Point3::new(-1.0, -1.0, 5.0),
+            Point3::new(1.0, -1.0, 5.0),
+            Point3::new(0.0, 1.0, 5.0),
+        ]),
+        Arc::new(vec![Point3::new(0usize, 2, 1), Point3::new(3, 4, 5)]),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn culled_ray_triangle() {
+    // The normal of this triangle is `+z`.
+    let triangle = Triangle::new(
+        Point3::new(-1.0f64, -1.0, 2.0),
+        Point3::new(1.0, -1.0, 2.0),
+        Point3::new(0.0, 1.0, 2.0),
+    );
+    let m = Isometry3::identity();
+    let up = Ray::new(Point3::origin(), Vector3::z());
+    let down = Ray::new(Point3::new(0.0, 0.0, 4.0), -Vector3::z());
+
+    assert!(triangle.toi_and_normal_with_culled_ray(&m, &up, CullMode::Back, true).is_none());
+    assert!(triangle.toi_and_normal_with_culled_ray(&m, &down, CullMode::Front, true).is_none());
+
+    let inter = triangle.toi_and_normal_with_culled_ray(&m, &up, CullMode::Front, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-10);
+    let inter = triangle.toi_and_normal_with_culled_ray(&m, &down, CullMode::Back, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-10);
+    assert!(triangle.toi_and_normal_with_culled_ray(&m, &up, CullMode::None, true).is_some());
+
+    // The orientation is transformed with the triangle.
+    let flipped = Isometry3::new(Vector3::new(0.0, 0.0, 4.0), Vector3::x() * PI);
+    let inter = triangle.toi_and_normal_with_culled_ray(&flipped, &up, CullMode::Back, true);
+    assert_relative_eq!(inter.unwrap().toi, 2.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn culled_ray_trimesh() {
+    let mesh = facing_triangles();
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+
+    let inter = mesh.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Back, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-10);
+    assert_eq!(inter.part, Some(0));
+
+    // The ray goes through the front face of the first triangle.
+    let inter = mesh.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Front, true).unwrap();
+    assert_relative_eq!(inter.toi, 5.0, epsilon = 1.0e-10);
+    assert_eq!(inter.part, Some(1));
+    assert_relative_eq!(inter.normal, -Vector3::z(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn culled_ray_composite_and_scaled_shapes() {
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+    let delta = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+
+    let compound = Compound::new(vec![(delta, ShapeHandle::new(facing_triangles()))]);
+    let inter = compound.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Front, true).unwrap();
+    assert_relative_eq!(inter.toi, 6.0, epsilon = 1.0e-10);
+    assert_eq!(inter.part, Some(0));
+
+    let mut dynamic = DynamicCompound::new();
+    let _ = dynamic.insert(delta, ShapeHandle::new(facing_triangles()));
+    let inter = dynamic.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Front, true).unwrap();
+    assert_relative_eq!(inter.toi, 6.0, epsilon = 1.0e-10);
+    let inter = dynamic.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Back, true).unwrap();
+    assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-10);
+
+    let scaled = Scaled::new(facing_triangles(), Vector3::new(1.0, 1.0, 2.0));
+    let inter = scaled.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Front, true).unwrap();
+    assert_relative_eq!(inter.toi, 10.0, epsilon = 1.0e-10);
+    assert_relative_eq!(inter.normal, -Vector3::z(), epsilon = 1.0e-10);
+    let inter = scaled.toi_and_normal_with_culled_ray(&m, &ray, CullMode::Back, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn culled_ray_plane() {
+    let plane = Plane::new(Unit::new_normalize(Vector3::y()));
+    let m = Isometry3::identity();
+    let down = Ray::new(Point3::new(0.0f64, 3.0, 0.0), -Vector3::y());
+    let up = Ray::new(Point3::new(0.0f64, -3.0, 0.0), Vector3::y());
+
+    assert!(plane.toi_and_normal_with_culled_ray(&m, &down, CullMode::Front, true).is_none());
+    let inter = plane.toi_and_normal_with_culled_ray(&m, &down, CullMode::Back, true).unwrap();
+    assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-10);
+
+    // A ray starting inside of the solid half-space is not culled.
+    let inter = plane.toi_and_normal_with_culled_ray(&m, &up, CullMode::Back, true).unwrap();
+    assert_eq!(inter.toi, 0.0);
+    assert!(plane.toi_and_normal_with_culled_ray(&m, &up, CullMode::Back, false).is_none());
+    let inter = plane.toi_and_normal_with_culled_ray(&m, &up, CullMode::Front, false).unwrap();
+    assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn culled_ray_collision_world() {
+    let mut world = CollisionWorld3::new(0.0);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let shape = ShapeHandle::new(facing_triangles());
+    let _ = world.add(Isometry3::identity(), shape, groups, query, ());
+    world.update();
+
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+    let hits: Vec<_> = world
+        .interferences_with_culled_ray(&ray, CullMode::Front, &groups)
+        .collect();
+    assert_eq!(hits.len(), 1);
+    assert_relative_eq!(hits[0].1.toi, 5.0, epsilon = 1.0e-10);
+
+    let hits: Vec<_> = world.interferences_with_ray(&ray, &groups).collect();
+    assert_relative_eq!(hits[0].1.toi, 2.0, epsilon = 1.0e-10);
+}
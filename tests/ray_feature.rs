@@ -0,0 +1,280 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::query::{Ray, RayCast};
+use ncollide::shape::{Ball, Compound, ConvexPolygon, Cuboid, FeatureId, Polyline, Segment,
+                      ShapeHandle, Triangle, TriMesh};
+use ncollide::transformation;
+
+#[test]
+fn ray_feature_triangle() {
+    let triangle = Triangle::new(
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let m = Isometry3::identity();
+    let cast = |origin: Point3<f64>, dir: Vector3<f64>| {
+        triangle
+            .toi_and_normal_with_ray(&m, &Ray::new(origin, dir), true)
+            .unwrap()
+    };
+
+    let inter = cast(Point3::new(0.25, 0.25, 1.0), -Vector3::z());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+    assert_eq!(inter.part, None);
+
+    let inter = cast(Point3::new(0.25, 0.25, -1.0), Vector3::z());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let inter = cast(Point3::new(1.0, 0.0, 1.0), -Vector3::z());
+    assert_eq!(inter.feature, FeatureId::Vertex(1));
+
+    let inter = cast(Point3::new(0.5, 0.0, 1.0), -Vector3::z());
+    assert_eq!(inter.feature, FeatureId::Edge(0));
+
+    let inter = cast(Point3::new(0.5, 0.5, 1.0), -Vector3::z());
+    assert_eq!(inter.feature, FeatureId::Edge(1));
+
+    // Rounding errors on the barycentric coordinates do not hide the edge.
+    let inter = cast(Point3::new(0.1, 0.9 - 1.0e-12, 1.0), -Vector3::z());
+    assert_eq!(inter.feature, FeatureId::Edge(1));
+}
+
+#[test]
+fn ray_feature_transformed_triangle() {
+    let a = Point3::new(0.3f64, -0.2, 0.1);
+    let b = Point3::new(1.7, 0.4, -0.3);
+    let c = Point3::new(0.2, 1.1, 0.6);
+    let triangle = Triangle::new(a, b, c);
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, -1.2, 0.7));
+    let normal = m * (b - a).cross(&(c - a)).normalize();
+    let cast = |target: Point3<f64>| {
+        let ray = Ray::new(m * target + normal * 2.0, -normal);
+        triangle.toi_and_normal_with_ray(&m, &ray, true).unwrap()
+    };
+
+    // The target points are not exactly on the edges once transformed.
+    let inter = cast(na::center(&a, &b));
+    assert_eq!(inter.feature, FeatureId::Edge(0));
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-7);
+
+    let inter = cast(a + (b - a) * 0.3);
+    assert_eq!(inter.feature, FeatureId::Edge(0));
+
+    let inter = cast(b + (c - b) * 0.7);
+    assert_eq!(inter.feature, FeatureId::Edge(1));
+
+    let inter = cast(c + (a - c) * 0.2);
+    assert_eq!(inter.feature, FeatureId::Edge(2));
+
+    let inter = cast(c);
+    assert_eq!(inter.feature, FeatureId::Vertex(2));
+
+    let inter = cast(Point3::from_coordinates((a.coords + b.coords + c.coords) / 3.0));
+    assert_eq!(inter.feature, FeatureId::Face(0));
+}
+
+#[test]
+fn ray_feature_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+    let cast = |origin: Point3<f64>, dir: Vector3<f64>, solid: bool| {
+        cuboid
+            .toi_and_normal_with_ray(&m, &Ray::new(origin, dir), solid)
+            .unwrap()
+    };
+
+    let inter = cast(Point3::new(1.5, 0.5, 10.0), -Vector3::z(), true);
+    assert_eq!(inter.feature, FeatureId::Face(2));
+
+    let inter = cast(Point3::new(-10.0, 0.5, 0.5), Vector3::x(), true);
+    assert_eq!(inter.feature, FeatureId::Face(3));
+
+    // The edge parallel to `y` with positive `x` and negative `z`.
+    let inter = cast(Point3::new(2.0, 0.5, -10.0), Vector3::z(), true);
+    assert_eq!(inter.feature, FeatureId::Edge(4 + 1));
+
+    let inter = cast(Point3::new(2.0, -2.0, 10.0), -Vector3::z(), true);
+    assert_eq!(inter.feature, FeatureId::Vertex(0b101));
+
+    let inter = cast(Point3::new(1.0, 0.0, 0.0), Vector3::x(), true);
+    assert_eq!(inter.feature, FeatureId::Unknown);
+
+    let inter = cast(Point3::new(1.0, 0.0, 0.0), Vector3::x(), false);
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    let ray = Ray::new(Point3::new(1.5, 5.0, 0.5), -Vector3::y());
+    let inter = cuboid.toi_and_normal_and_uv_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.feature, FeatureId::Face(1));
+}
+
+#[test]
+fn ray_feature_convex_polytopes() {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0f64 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(x, y, z));
+    }
+
+    let cube = transformation::convex_polyhedron(&points).unwrap();
+    let m = Isometry3::identity();
+    let cast = |origin: Point3<f64>, dir: Vector3<f64>| {
+        cube.toi_and_normal_with_ray(&m, &Ray::new(origin, dir), true)
+            .unwrap()
+    };
+
+    let inter = cast(Point3::new(0.5, 0.5, 10.0), -Vector3::z());
+    match inter.feature {
+        FeatureId::Face(f) => assert_eq!(*cube.faces()[f].normal().as_ref(), Vector3::z()),
+        _ => panic!("The ray should hit a face."),
+    }
+
+    let inter = cast(Point3::new(1.0, 0.5, 10.0), -Vector3::z());
+    match inter.feature {
+        FeatureId::Edge(e) => {
+            for v in cube.edges()[e].vertices() {
+                assert_eq!(cube.points()[*v].x, 1.0);
+                assert_eq!(cube.points()[*v].z, 1.0);
+            }
+        }
+        _ => panic!("The ray should hit an edge."),
+    }
+
+    let inter = cast(Point3::new(1.0, -1.0, 10.0), -Vector3::z());
+    match inter.feature {
+        FeatureId::Vertex(v) => assert_eq!(cube.points()[v], Point3::new(1.0, -1.0, 1.0)),
+        _ => panic!("The ray should hit a vertex."),
+    }
+
+    let square = ConvexPolygon::try_new(vec![
+        Point2::new(-1.0f64, -1.0),
+        Point2::new(1.0, -1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(-1.0, 1.0),
+    ]).unwrap();
+    let m = Isometry2::identity();
+    let cast = |origin: Point2<f64>, dir: Vector2<f64>| {
+        square
+            .toi_and_normal_with_ray(&m, &Ray::new(origin, dir), true)
+            .unwrap()
+    };
+
+    let inter = cast(Point2::new(0.5, 10.0), -Vector2::y());
+    match inter.feature {
+        FeatureId::Face(f) => assert_eq!(*square.normals()[f].as_ref(), Vector2::y()),
+        _ => panic!("The ray should hit a face."),
+    }
+
+    let inter = cast(Point2::new(1.0, 10.0), -Vector2::y());
+    match inter.feature {
+        FeatureId::Vertex(v) => assert_eq!(square.points()[v], Point2::new(1.0, 1.0)),
+        _ => panic!("The ray should hit a vertex."),
+    }
+}
+
+#[test]
+fn ray_feature_segment() {
+    let segment = Segment::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 0.0));
+    let m = Isometry2::identity();
+    let cast = |origin: Point2<f64>, dir: Vector2<f64>| {
+        segment
+            .toi_and_normal_with_ray(&m, &Ray::new(origin, dir), true)
+            .unwrap()
+    };
+
+    let inter = cast(Point2::new(1.0, -5.0), Vector2::y());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    let inter = cast(Point2::new(1.0, 5.0), -Vector2::y());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let inter = cast(Point2::new(2.0, 5.0), -Vector2::y());
+    assert_eq!(inter.feature, FeatureId::Vertex(1));
+
+    let inter = cast(Point2::new(-5.0, 0.0), Vector2::x());
+    assert_eq!(inter.feature, FeatureId::Vertex(0));
+
+    let segment = Segment::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(1.0, -5.0, 0.0), Vector3::y());
+    let inter = segment.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.feature, FeatureId::Edge(0));
+}
+
+#[test]
+fn ray_feature_trimesh() {
+    let vertices = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(5.0, 0.0, 0.0),
+        Point3::new(6.0, 0.0, 0.0),
+        Point3::new(5.0, 1.0, 0.0),
+    ];
+    let indices = vec![Point3::new(0usize, 1, 2), Point3::new(3, 4, 5)];
+    let uvs = vec![Point2::new(0.0f64, 0.0); 6];
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), Some(Arc::new(uvs)), None);
+    let m = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+    let ray = Ray::new(Point3::new(5.25, 0.25, 5.0), -Vector3::z());
+
+    let inter = mesh.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.part, Some(1));
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    let inter = mesh.toi_and_normal_and_uv_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.part, Some(1));
+    assert_eq!(inter.feature, FeatureId::Face(0));
+    assert!(inter.uvs.is_some());
+}
+
+#[test]
+fn ray_feature_polyline_and_compound() {
+    let polyline = Polyline::new(
+        Arc::new(vec![
+            Point2::new(0.0f64, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 1.0),
+        ]),
+        Arc::new(vec![Point2::new(0usize, 1), Point2::new(1, 2)]),
+        None,
+        None,
+    );
+    let m = Isometry2::identity();
+    let ray = Ray::new(Point2::new(1.5, 5.0), -Vector2::y());
+    let inter = polyline.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.part, Some(1));
+
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-3.0, 0.0), 0.0), ShapeHandle::new(Ball::new(1.0f64))),
+        (
+            Isometry2::new(Vector2::new(3.0, 0.0), 0.0),
+            ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 1.0))),
+        ),
+    ]);
+    let ray = Ray::new(Point2::new(3.0, 5.0), -Vector2::y());
+    let inter = compound.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.part, Some(1));
+
+    let ray = Ray::new(Point2::new(-3.0, 5.0), -Vector2::y());
+    let inter = compound.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.part, Some(0));
+    assert_eq!(inter.feature, FeatureId::Unknown);
+
+    // The part of a nested composite shape is the index in the outermost one.
+    let nested = Compound::new(vec![
+        (Isometry2::identity(), ShapeHandle::new(Ball::new(0.5f64))),
+        (Isometry2::new(Vector2::new(0.0, -5.0), 0.0), ShapeHandle::new(polyline)),
+    ]);
+    let ray = Ray::new(Point2::new(1.5, -10.0), Vector2::y());
+    let inter = nested.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.part, Some(1));
+}
@@ -0,0 +1,118 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::query::Ray;
+use ncollide::query::ray_internal;
+use ncollide::shape::{Ball, Compound, Cuboid, Polyline, ShapeHandle, TriMesh};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+fn assert_tois<I: IntoIterator<Item = f64>>(tois: I, expected: &[f64]) {
+    let tois: Vec<_> = tois.into_iter().collect();
+    assert_eq!(tois.len(), expected.len());
+
+    for (toi, expected) in tois.iter().zip(expected.iter()) {
+        assert_relative_eq!(toi, expected, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn ray_intersections_convex_shapes() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry2::new(Vector2::new(5.0, 0.0), 0.0);
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+
+    let inters = ray_internal::ray_intersections_with_shape(&m, &ball, &ray);
+    assert_eq!(inters.len(), 2);
+    assert_relative_eq!(inters[0].toi, 4.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inters[0].normal, -Vector2::x(), epsilon = 1.0e-6);
+    assert_relative_eq!(inters[1].toi, 6.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inters[1].normal, Vector2::x(), epsilon = 1.0e-6);
+    assert!(inters[0].part.is_none());
+
+    // Only the exit point is reported if the ray starts inside of the shape.
+    let ray = Ray::new(Point2::new(5.0, 0.0), Vector2::x());
+    let inters = ray_internal::ray_intersections_with_shape(&m, &ball, &ray);
+    assert_eq!(inters.len(), 1);
+    assert_relative_eq!(inters[0].toi, 1.0, epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point2::new(0.0, 5.0), Vector2::x());
+    assert!(ray_internal::ray_intersections_with_shape(&m, &ball, &ray).is_empty());
+}
+
+#[test]
+fn ray_intersections_composite_shapes() {
+    // Two parallel triangles crossed once each.
+    let mesh = TriMesh::new(
+        Arc::new(vec![
+            Point3::new(-1.0f64, -1.0, 5.0),
+            Point3::new(1.0, -1.0, 5.0),
+            Point3::new(0.0, 1.0, 5.0),
+            Point3::new(-1.0, -1.0, 2.0),
+            Point3::new(1.0, -1.0, 2.0),
+            Point3::new(0.0, 1.0, 2.0),
+        ]),
+        Arc::new(vec![Point3::new(0usize, 1, 2), Point3::new(3, 4, 5)]),
+        None,
+        None,
+    );
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+    let inters = ray_internal::ray_intersections_with_shape(&Isometry3::identity(), &mesh, &ray);
+    assert_eq!(inters.len(), 2);
+    assert_relative_eq!(inters[0].toi, 2.0, epsilon = 1.0e-6);
+    assert_eq!(inters[0].part, Some(1));
+    assert_relative_eq!(inters[1].toi, 5.0, epsilon = 1.0e-6);
+    assert_eq!(inters[1].part, Some(0));
+
+    // Entry and exit points of each part.
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::new(0.5f64, 0.5)));
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(6.0, 0.0), 0.0), cuboid.clone()),
+        (Isometry2::new(Vector2::new(2.0, 0.0), 0.0), cuboid.clone()),
+    ]);
+    let m = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    let inters = ray_internal::ray_intersections_with_shape(&m, &compound, &ray);
+    let parts: Vec<_> = inters.iter().map(|inter| inter.part).collect();
+    assert_tois(inters.iter().map(|inter| inter.toi), &[2.5, 3.5, 6.5, 7.5]);
+    assert_eq!(parts, vec![Some(1), Some(1), Some(0), Some(0)]);
+
+    // A zigzag crossed by the ray on each segment.
+    let polyline = Polyline::new(
+        Arc::new(vec![
+            Point2::new(1.0f64, -1.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(3.0, -1.0),
+            Point2::new(4.0, 1.0),
+        ]),
+        Arc::new(vec![Point2::new(0usize, 1), Point2::new(1, 2), Point2::new(2, 3)]),
+        None,
+        None,
+    );
+    let m = Isometry2::identity();
+    let inters = ray_internal::ray_intersections_with_shape(&m, &polyline, &ray);
+    assert_tois(inters.iter().map(|inter| inter.toi), &[1.5, 2.5, 3.5]);
+}
+
+#[test]
+fn ray_intersections_collision_world() {
+    let mut world = CollisionWorld2::new(0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0f64));
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let m1 = Isometry2::new(Vector2::new(10.0, 0.0), 0.0);
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.0), 0.0);
+    let far = world.add(m1, shape.clone(), groups, query, ());
+    let near = world.add(m2, shape, groups, query, ());
+    world.update();
+
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    let inters = world.intersections_with_ray(&ray, &groups);
+    let handles: Vec<_> = inters.iter().map(|inter| inter.0.handle()).collect();
+    assert_eq!(handles, vec![near, near, far, far]);
+    assert_tois(inters.iter().map(|inter| inter.1.toi), &[2.0, 4.0, 9.0, 11.0]);
+}
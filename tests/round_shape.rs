@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, Cuboid, RoundShape, ShapeHandle};
+use ncollide::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+#[test]
+fn round_cuboid_bounding_volumes() {
+    let shape = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0)), 0.5);
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    let aabb = bounding_volume::aabb(&shape, &m);
+    assert_relative_eq!(*aabb.mins(), Point3::new(-0.5, -2.5, -3.5), epsilon = 1.0e-6);
+    assert_relative_eq!(*aabb.maxs(), Point3::new(2.5, 2.5, 3.5), epsilon = 1.0e-6);
+
+    let sphere = bounding_volume::bounding_sphere(&shape, &m);
+    assert_relative_eq!(sphere.radius(), 14.0f64.sqrt() + 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn round_cuboid_ray_cast() {
+    let shape = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 0.5);
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = shape.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 3.5, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-6);
+
+    // Hits the rounded corner.
+    let dir = -Vector3::new(1.0, 1.0, 1.0).normalize();
+    let ray = Ray::new(Point3::new(5.0, 5.0, 5.0), dir);
+    let toi = shape.toi_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(toi, 4.0 * 3.0f64.sqrt() - 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn round_cuboid_point_projection() {
+    let shape = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 0.5);
+    let m = Isometry3::identity();
+
+    let proj = shape.project_point(&m, &Point3::new(3.0, 3.0, 0.0), true);
+    let offset = 0.5 / 2.0f64.sqrt();
+    assert!(!proj.is_inside);
+    assert_relative_eq!(
+        proj.point,
+        Point3::new(1.0 + offset, 1.0 + offset, 0.0),
+        epsilon = 1.0e-6
+    );
+
+    assert!(shape.contains_point(&m, &Point3::new(1.2, 1.2, 0.0)));
+    assert!(!shape.contains_point(&m, &Point3::new(1.4, 1.4, 0.0)));
+
+    let proj = shape.project_point(&m, &Point3::new(0.5, 0.0, 0.0), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.5, 0.0, 0.0), epsilon = 1.0e-6);
+}
+
+#[test]
+fn round_cuboid_against_ball() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let shape = ShapeHandle::new(RoundShape::new(cuboid, 0.5));
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(1.9, 0.0, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &*shape, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+    assert_relative_eq!(contact.world1, Point3::new(1.5, 0.0, 0.0), epsilon = 1.0e-4);
+    assert_relative_eq!(contact.world2, Point3::new(1.4, 0.0, 0.0), epsilon = 1.0e-4);
+
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+    assert_relative_eq!(query::distance(&m1, &*shape, &m2, &*ball), 1.0, epsilon = 1.0e-6);
+    assert_eq!(
+        query::proximity(&m1, &*shape, &m2, &*ball, 0.5),
+        Proximity::Disjoint
+    );
+    assert_eq!(
+        query::proximity(&m1, &*ball, &m2, &*shape, 1.5),
+        Proximity::WithinMargin
+    );
+}
+
+#[test]
+fn round_cuboid_in_collision_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0));
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(RoundShape::new(cuboid.clone(), 0.2)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(2.3, 0.0, 0.0), na::zero()),
+        ShapeHandle::new(RoundShape::new(cuboid, 0.2)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+
+    let (_, _, contact) = world.contacts().next().unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-5);
+}
@@ -0,0 +1,212 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64;
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Point4, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, CompositeShape, Compound, Cuboid, DynamicCompound, Scaled,
+                      ShapeHandle, SupportMap, TetMesh, Torus, TriMesh};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+use ncollide::transformation;
+
+#[test]
+fn scaled_ball_queries() {
+    let shape = Scaled::new(Ball::new(1.0f64), Vector3::new(2.0, 1.0, 1.0));
+    let m = Isometry3::identity();
+
+    let aabb = bounding_volume::aabb(&shape, &m);
+    assert_relative_eq!(*aabb.mins(), Point3::new(-2.0, -1.0, -1.0), epsilon = 1.0e-6);
+    assert_relative_eq!(*aabb.maxs(), Point3::new(2.0, 1.0, 1.0), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = shape.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 4.0, epsilon = 1.0e-6);
+
+    let proj = shape.project_point(&m, &Point3::new(3.0, 0.0, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::new(2.0, 0.0, 0.0), epsilon = 1.0e-6);
+    assert!(shape.contains_point(&m, &Point3::new(1.9, 0.0, 0.0)));
+    assert!(!shape.contains_point(&m, &Point3::new(0.0, 1.1, 0.0)));
+}
+
+#[test]
+fn scaled_trimesh_queries() {
+    let vertices = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let indices = vec![Point3::new(0usize, 1, 2)];
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let shape = Scaled::new(mesh, Vector3::new(2.0, 3.0, 1.0));
+    let m = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+
+    let aabb = bounding_volume::aabb(&shape, &m);
+    assert_relative_eq!(*aabb.maxs(), Point3::new(2.0, 3.0, 1.0), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(1.5, 0.5, 5.0), -Vector3::z());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 4.0, epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(1.5, 1.5, 5.0), -Vector3::z());
+    assert!(shape.toi_with_ray(&m, &ray, true).is_none());
+
+    let proj = shape.project_point(&m, &Point3::new(1.5, 0.5, 3.0), true);
+    assert_relative_eq!(proj.point, Point3::new(1.5, 0.5, 1.0), epsilon = 1.0e-6);
+}
+
+#[test]
+fn scaled_compound_with_rotated_part() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 0.5, 0.5)));
+    let rot = Isometry3::new(na::zero(), Vector3::z() * f64::consts::FRAC_PI_2);
+    let compound = Compound::new(vec![(rot, cuboid)]);
+    let shape = Scaled::new(compound, Vector3::new(2.0, 1.0, 1.0));
+    let m = Isometry3::identity();
+
+    // The AABB of a compound shape is slightly loosened.
+    let aabb = bounding_volume::aabb(&shape, &m);
+    assert_relative_eq!(*aabb.mins(), Point3::new(-1.0, -1.0, -0.5), epsilon = 0.1);
+    assert_relative_eq!(*aabb.maxs(), Point3::new(1.0, 1.0, 0.5), epsilon = 0.1);
+
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), -Vector3::x());
+    let inter = shape.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, Vector3::x(), epsilon = 1.0e-6);
+
+    assert!(shape.contains_point(&m, &Point3::new(0.9, 0.9, 0.0)));
+    assert!(!shape.contains_point(&m, &Point3::new(1.1, 0.0, 0.0)));
+}
+
+#[test]
+fn scaled_ball_against_ball() {
+    let scaled = ShapeHandle::new(Scaled::new(Ball::new(1.0f64), Vector3::new(2.0, 1.0, 1.0)));
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(2.4, 0.0, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &*scaled, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 2.5, 0.0), na::zero());
+    assert_relative_eq!(query::distance(&m1, &*scaled, &m2, &*ball), 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn scaled_nested_compound() {
+    let cube = transformation::convex_polyhedron(&[
+        Point3::new(-0.5f64, -0.5, -0.5),
+        Point3::new(0.5, -0.5, -0.5),
+        Point3::new(0.5, 0.5, -0.5),
+        Point3::new(-0.5, 0.5, -0.5),
+        Point3::new(-0.5, -0.5, 0.5),
+        Point3::new(0.5, -0.5, 0.5),
+        Point3::new(0.5, 0.5, 0.5),
+        Point3::new(-0.5, 0.5, 0.5),
+    ]).unwrap();
+    let inner = Compound::new(vec![
+        (Isometry3::new(Vector3::x() * 2.0, na::zero()), ShapeHandle::new(Ball::new(0.5f64))),
+        (Isometry3::identity(), ShapeHandle::new(cube)),
+    ]);
+    let compound = Compound::new(vec![
+        (Isometry3::new(Vector3::y() * 2.0, na::zero()), ShapeHandle::new(inner)),
+    ]);
+    let shape = Scaled::new(compound, Vector3::new(2.0, 1.0, 1.0));
+    let m = Isometry3::identity();
+
+    // The ball centered at (4, 2, 0) is scaled to an ellipsoid of semi-axes (1, 0.5, 0.5).
+    let proj = shape.project_point(&m, &Point3::new(4.0, 5.0, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::new(4.0, 2.5, 0.0), epsilon = 1.0e-2);
+    assert!(shape.contains_point(&m, &Point3::new(4.9, 2.0, 0.0)));
+    assert!(shape.contains_point(&m, &Point3::new(0.9, 2.4, 0.4)));
+    assert!(!shape.contains_point(&m, &Point3::new(1.1, 2.0, 0.0)));
+
+    let ray = Ray::new(Point3::new(10.0, 2.0, 0.0), -Vector3::x());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 5.0, epsilon = 1.0e-6);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 4.0, 0.0), na::zero());
+    let dist = query::distance(&m, &shape, &m2, &Ball::new(0.5));
+    assert_relative_eq!(dist, 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn scaled_scaled_compound() {
+    let compound = Compound::new(vec![
+        (Isometry3::new(Vector3::x() * 2.0, na::zero()), ShapeHandle::new(Ball::new(0.5f64))),
+    ]);
+    let scaled = Scaled::new(compound, Vector3::new(2.0, 1.0, 1.0));
+    let shape = Scaled::new(scaled, Vector3::new(1.0, 3.0, 1.0));
+    let m = Isometry3::identity();
+
+    // The ball is scaled to an ellipsoid centered at (4, 0, 0) with semi-axes (1, 1.5, 0.5).
+    let ray = Ray::new(Point3::new(4.0, 10.0, 0.0), -Vector3::y());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 8.5, epsilon = 1.0e-6);
+    assert!(shape.contains_point(&m, &Point3::new(4.0, 1.4, 0.0)));
+    assert!(!shape.contains_point(&m, &Point3::new(4.0, 1.6, 0.0)));
+}
+
+#[test]
+fn scaled_shape_without_support_map_or_parts() {
+    let shape = Scaled::new(Torus::new(2.0f64, 0.5), Vector3::new(2.0, 1.0, 1.0));
+    let m = Isometry3::identity();
+
+    // The support function falls back to the one of the AABB.
+    let pt = SupportMap::<Point3<f64>, Isometry3<f64>>::support_point(&shape, &m, &Vector3::x());
+    let aabb = bounding_volume::aabb(&shape, &m);
+    assert_relative_eq!(pt.x, aabb.maxs().x, epsilon = 1.0e-6);
+
+    // A scaled shape which is not composite has no parts.
+    let mut visited = false;
+    CompositeShape::<Point3<f64>, Isometry3<f64>>::map_part_at(&shape, 0, &mut |_, _| {
+        visited = true
+    });
+    assert!(!visited);
+    assert!(!CompositeShape::<Point3<f64>, Isometry3<f64>>::contains_part(&shape, 0));
+}
+
+#[test]
+fn scaled_tet_mesh() {
+    let vertices = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let mesh = TetMesh::new(Arc::new(vertices), Arc::new(vec![Point4::new(0usize, 1, 2, 3)]));
+    let shape = Scaled::new(mesh, Vector3::new(2.0, 1.0, 1.0));
+    let m = Isometry3::identity();
+
+    assert!(shape.contains_point(&m, &Point3::new(1.5, 0.1, 0.1)));
+    assert!(!shape.contains_point(&m, &Point3::new(0.5, 0.9, 0.1)));
+
+    let proj = shape.project_point(&m, &Point3::new(1.0, 0.25, -1.0), true);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.25, 0.0), epsilon = 1.0e-6);
+
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), -Vector3::x());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 3.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn scaled_dynamic_compound_with_removed_part() {
+    let mut compound = DynamicCompound::new();
+    let ball = ShapeHandle::new(Ball::new(1.0f64));
+    let removed = compound.insert(Isometry3::new(Vector3::x() * 3.0, na::zero()), ball.clone());
+    let _ = compound.insert(Isometry3::identity(), ball);
+    let _ = compound.remove(removed);
+    let shape = Scaled::new(compound, Vector3::new(2.0, 1.0, 1.0));
+
+    // The removed part is not given to the closure.
+    let mut visited = false;
+    shape.map_part_at(removed, &mut |_, _| visited = true);
+    assert!(!visited);
+
+    let m = Isometry3::identity();
+    assert!(shape.contains_point(&m, &Point3::new(1.9, 0.0, 0.0)));
+    assert!(!shape.contains_point(&m, &Point3::new(6.0, 0.0, 0.0)));
+}
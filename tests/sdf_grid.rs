@@ -0,0 +1,190 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Cuboid, SdfGrid, ShapeHandle, TriMesh};
+use ncollide::query::{self, ClosestPoints, PointQuery, Ray, RayCast};
+
+// The distance field of a ball of radius 1 centered at the origin, sampled on [-2, 2]³.
+fn ball_sdf() -> SdfGrid<Point3<f64>> {
+    let n = 41;
+    let cell_size = 0.1;
+    let mut values = Vec::new();
+
+    for k in 0..n {
+        for j in 0..n {
+            for i in 0..n {
+                let pt = Point3::new(i as f64, j as f64, k as f64) * cell_size;
+                let pt = pt + Vector3::repeat(-2.0);
+                values.push(na::norm(&pt.coords) - 1.0);
+            }
+        }
+    }
+
+    SdfGrid::new(Point3::new(-2.0, -2.0, -2.0), cell_size, vec![n, n, n], values)
+}
+
+#[test]
+fn sdf_grid_interpolation() {
+    // The distance field of the half-plane `x <= 0.5` is interpolated exactly.
+    let values = vec![-0.5, 0.5, -0.5, 0.5];
+    let sdf = SdfGrid::new(Point2::origin(), 1.0f64, vec![2, 2], values);
+
+    let (distance, gradient) = sdf.distance_and_gradient_at(&Point2::new(0.25, 0.5));
+    assert_relative_eq!(distance, -0.25, epsilon = 1.0e-6);
+    assert_relative_eq!(gradient, Vector2::x(), epsilon = 1.0e-6);
+
+    // Extrapolation outside of the grid domain.
+    assert_relative_eq!(sdf.distance_at(&Point2::new(3.0, 0.5)), 2.5, epsilon = 1.0e-6);
+
+    let aabb = bounding_volume::aabb(&sdf, &Isometry2::new(Vector2::new(1.0, 0.0), 0.0));
+    assert_relative_eq!(*aabb.mins(), Point2::new(1.0, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(*aabb.maxs(), Point2::new(2.0, 1.0), epsilon = 1.0e-6);
+}
+
+#[test]
+fn sdf_grid_point_queries() {
+    let sdf = ball_sdf();
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    assert!(sdf.contains_point(&m, &Point3::new(1.5, 0.0, 0.5)));
+    assert!(!sdf.contains_point(&m, &Point3::new(2.5, 0.0, 0.5)));
+
+    let proj = sdf.project_point(&m, &Point3::new(1.0, 1.5, 0.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 1.0, 0.0), epsilon = 1.0e-2);
+
+    let pt = Point3::new(1.5, 0.0, 0.0);
+    assert_relative_eq!(sdf.project_point(&m, &pt, true).point, pt);
+    let proj = sdf.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(2.0, 0.0, 0.0), epsilon = 1.0e-2);
+    assert_relative_eq!(sdf.distance_to_point(&m, &pt, false), -0.5, epsilon = 1.0e-2);
+}
+
+#[test]
+fn sdf_grid_ray_cast() {
+    let sdf = ball_sdf();
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = sdf.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-2);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-2);
+
+    let ray = Ray::new(Point3::origin(), Vector3::y());
+    assert_relative_eq!(sdf.toi_with_ray(&m, &ray, true).unwrap(), 0.0);
+    let inter = sdf.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(inter.toi, 1.0, epsilon = 1.0e-2);
+    assert_relative_eq!(inter.normal, -Vector3::y(), epsilon = 1.0e-2);
+
+    let ray = Ray::new(Point3::new(-5.0, 1.5, 0.0), Vector3::x());
+    assert!(sdf.toi_with_ray(&m, &ray, true).is_none());
+}
+
+#[test]
+fn sdf_grid_grazing_ray_cast() {
+    // The distance field of the half-plane `y <= 0`.
+    let values = vec![0.0, 0.0, 1.0, 1.0];
+    let sdf = SdfGrid::new(Point2::origin(), 1.0f64, vec![2, 2], values);
+    let m = Isometry2::identity();
+
+    // The ray stays just above the surface without ever reaching it.
+    let ray = Ray::new(Point2::new(0.5, 1.0e-6), Vector2::x());
+    assert!(sdf.toi_and_normal_with_ray(&m, &ray, true).is_none());
+
+    let ray = Ray::new(Point2::new(0.5, 0.5), -Vector2::y());
+    assert_relative_eq!(sdf.toi_with_ray(&m, &ray, true).unwrap(), 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn sdf_grid_against_cuboid() {
+    let sdf = ShapeHandle::new(ball_sdf());
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.4, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &*sdf, &m2, &*cuboid, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-2);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::y(), epsilon = 1.0e-2);
+
+    let contact = query::contact(&m2, &*cuboid, &m1, &*sdf, 0.0).unwrap();
+    assert_relative_eq!(contact.normal.unwrap(), -Vector3::y(), epsilon = 1.0e-2);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.6, 0.0), na::zero());
+    assert!(query::contact(&m1, &*sdf, &m2, &*cuboid, 0.0).is_none());
+    assert!(query::contact(&m1, &*sdf, &m2, &*cuboid, 0.2).is_some());
+}
+
+#[test]
+fn sdf_grid_closest_points_with_cuboid() {
+    let sdf = ShapeHandle::new(ball_sdf());
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.4, 0.0), na::zero());
+
+    let res = query::closest_points(&m1, &*sdf, &m2, &*cuboid, 0.0);
+    assert_eq!(res, ClosestPoints::Intersecting);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.7, 0.0), na::zero());
+    let res = query::closest_points(&m1, &*sdf, &m2, &*cuboid, 0.1);
+    assert_eq!(res, ClosestPoints::Disjoint);
+
+    match query::closest_points(&m1, &*sdf, &m2, &*cuboid, 0.5) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!(p1, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-2);
+            assert_relative_eq!(p2, Point3::new(0.0, 1.2, 0.0), epsilon = 1.0e-2);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+
+    match query::closest_points(&m2, &*cuboid, &m1, &*sdf, 0.5) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!(p1, Point3::new(0.0, 1.2, 0.0), epsilon = 1.0e-2);
+            assert_relative_eq!(p2, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-2);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+}
+
+#[test]
+fn sdf_grid_from_trimesh() {
+    let mut vertices = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        vertices.push(Point3::new(x, y, z));
+    }
+
+    let indices = vec![
+        Point3::new(0usize, 2, 3),
+        Point3::new(0, 3, 1),
+        Point3::new(4, 5, 7),
+        Point3::new(4, 7, 6),
+        Point3::new(0, 1, 5),
+        Point3::new(0, 5, 4),
+        Point3::new(2, 6, 7),
+        Point3::new(2, 7, 3),
+        Point3::new(0, 4, 6),
+        Point3::new(0, 6, 2),
+        Point3::new(1, 3, 7),
+        Point3::new(1, 7, 5),
+    ];
+    let vertices = Arc::new(vertices);
+    let mesh = TriMesh::new(vertices.clone(), Arc::new(indices), None, None);
+    let sdf = SdfGrid::from_trimesh(&mesh, 0.25, 0.5).unwrap();
+
+    assert_eq!(sdf.num_samples(), &[13, 13, 13]);
+    assert_relative_eq!(sdf.distance_at(&Point3::origin()), -1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(sdf.distance_at(&Point3::new(1.5, 0.0, 0.0)), 0.5, epsilon = 1.0e-6);
+
+    let open_mesh = TriMesh::new(vertices, Arc::new(vec![Point3::new(0, 2, 3)]), None, None);
+    assert!(SdfGrid::from_trimesh(&open_mesh, 0.25, 0.5).is_none());
+}
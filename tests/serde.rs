@@ -0,0 +1,183 @@
+#![cfg(feature = "serde-serialize")]
+
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point2, Point3, Translation2, Vector2, Vector3};
+use ncollide::bounding_volume::{BoundingSphere, AABB};
+use ncollide::query::{Ray, RayCast};
+use ncollide::shape::{self, Ball, Compound, Cuboid, DynamicCompound, RoundShape, Scaled, Shape,
+                      ShapeHandle, Torus, TriMesh};
+
+fn same_shape<P, M>(handle1: &ShapeHandle<P, M>, handle2: &ShapeHandle<P, M>) -> bool
+where
+    P: ncollide::math::Point,
+{
+    let address1 = &**handle1 as *const Shape<P, M> as *const ();
+    let address2 = &**handle2 as *const Shape<P, M> as *const ();
+
+    address1 == address2
+}
+
+#[test]
+fn serde_shape_handles() {
+    let mesh = TriMesh::new(
+        Arc::new(vec![
+            Point3::new(0.0f64, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ]),
+        Arc::new(vec![Point3::new(0usize, 1, 2)]),
+        None,
+        None,
+    );
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let parts = vec![
+        (Isometry3::new(Vector3::x(), na::zero()), ball.clone()),
+        (Isometry3::new(-Vector3::x(), na::zero()), ball.clone()),
+        (Isometry3::identity(), ShapeHandle::new(mesh)),
+    ];
+    let compound = ShapeHandle::new(Compound::new(parts));
+
+    let json = serde_json::to_string(&compound).unwrap();
+    let handle2: ShapeHandle<Point3<f64>, Isometry3<f64>> = serde_json::from_str(&json).unwrap();
+    let compound2 = handle2.as_shape::<Compound<Point3<f64>, Isometry3<f64>>>().unwrap();
+    let parts2 = compound2.shapes();
+
+    // The ball is serialized only once and remains shared.
+    assert_eq!(json.matches("Ball").count(), 1);
+    assert_eq!(parts2.len(), 3);
+    assert!(same_shape(&parts2[0].1, &parts2[1].1));
+    assert_eq!(parts2[0].1.as_shape::<Ball<f64>>(), Some(&Ball::new(0.5)));
+    assert_relative_eq!(parts2[1].0, Isometry3::new(-Vector3::x(), na::zero()));
+    assert!(parts2[2].1.is_shape::<TriMesh<Point3<f64>>>());
+
+    let ray = Ray::new(Point3::new(0.2, 0.2, -1.0), Vector3::z());
+    let toi = compound2.toi_with_ray(&Isometry3::identity(), &ray, true);
+    assert_relative_eq!(toi.unwrap(), 1.0);
+
+    // Independent handles share their shape within a session.
+    let handles = vec![ball.clone(), ball];
+    let json = shape::with_shared_shapes(|| serde_json::to_string(&handles).unwrap());
+    let handles2: Vec<ShapeHandle<Point3<f64>, Isometry3<f64>>> =
+        shape::with_shared_shapes(|| serde_json::from_str(&json).unwrap());
+    assert!(same_shape(&handles2[0], &handles2[1]));
+}
+
+#[test]
+fn serde_shape_handles2() {
+    let torus = ShapeHandle::new(Torus::new(2.0f64, 0.5));
+    let parts = vec![
+        (Translation2::new(1.0, 0.0), torus),
+        (Translation2::new(-1.0, 0.0), ShapeHandle::new(Ball::new(0.5))),
+    ];
+    let compound = ShapeHandle::new(Compound::new(parts));
+
+    let json = serde_json::to_string(&compound).unwrap();
+    let handle2: ShapeHandle<Point2<f64>, Translation2<f64>> = serde_json::from_str(&json).unwrap();
+    let compound2 = handle2
+        .as_shape::<Compound<Point2<f64>, Translation2<f64>>>()
+        .unwrap();
+    let parts2 = compound2.shapes();
+
+    assert_eq!(parts2.len(), 2);
+    assert_eq!(parts2[0].1.as_shape::<Torus<f64>>(), Some(&Torus::new(2.0, 0.5)));
+    assert_eq!(parts2[1].1.as_shape::<Ball<f64>>(), Some(&Ball::new(0.5)));
+}
+
+#[test]
+fn serde_dynamic_compound_and_bounding_volumes() {
+    let mut compound = DynamicCompound::new();
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0)));
+    let _ = compound.insert(Isometry3::new(Vector3::x() * 5.0, na::zero()), cuboid.clone());
+    let removed = compound.insert(Isometry3::identity(), cuboid.clone());
+    let kept = compound.insert(Isometry3::new(-Vector3::x() * 5.0, Vector3::y()), cuboid);
+    let _ = compound.remove(removed);
+
+    let json = serde_json::to_string(&compound).unwrap();
+    let compound2: DynamicCompound<Point3<f64>, Isometry3<f64>> =
+        serde_json::from_str(&json).unwrap();
+    assert_eq!(compound2.len(), 2);
+    assert!(compound2.part(removed).is_none());
+    assert_relative_eq!(compound2.part(kept).unwrap().0, compound.part(kept).unwrap().0);
+    assert_eq!(compound2.aabb_at(kept), compound.aabb_at(kept));
+
+    let aabb = AABB::new(Point2::new(-1.0f64, -2.0), Point2::new(3.0, 4.0));
+    let sphere = BoundingSphere::new(Point3::new(1.0f64, 2.0, 3.0), 4.0);
+    let ray = Ray::new(Point2::new(1.0f64, 2.0), Vector2::new(3.0, 4.0));
+    let aabb2: AABB<Point2<f64>> =
+        serde_json::from_str(&serde_json::to_string(&aabb).unwrap()).unwrap();
+    let sphere2: BoundingSphere<Point3<f64>> =
+        serde_json::from_str(&serde_json::to_string(&sphere).unwrap()).unwrap();
+    let ray2: Ray<Point2<f64>> =
+        serde_json::from_str(&serde_json::to_string(&ray).unwrap()).unwrap();
+    assert_eq!(aabb, aabb2);
+    assert_eq!(sphere, sphere2);
+    assert_eq!(ray.origin, ray2.origin);
+    assert_eq!(ray.dir, ray2.dir);
+}
+
+#[test]
+fn serde_round_and_scaled_shapes() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let round: ShapeHandle<Point3<f64>, Isometry3<f64>> =
+        ShapeHandle::new(RoundShape::new(cuboid.clone(), 0.5));
+    let json = serde_json::to_string(&round).unwrap();
+    let round2: ShapeHandle<Point3<f64>, Isometry3<f64>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        round2.as_shape::<RoundShape<f64, Cuboid<Vector3<f64>>>>(),
+        Some(&RoundShape::new(cuboid, 0.5))
+    );
+
+    // The parts of a scaled compound are serialized as shape handles.
+    let ball = ShapeHandle::new(Ball::new(1.0f64));
+    let compound = Compound::new(vec![
+        (Isometry3::new(Vector3::x(), na::zero()), ball.clone()),
+        (Isometry3::new(-Vector3::x(), na::zero()), ball),
+    ]);
+    let scaled = ShapeHandle::new(Scaled::new(compound, Vector3::new(2.0, 3.0, 1.0)));
+    let json = serde_json::to_string(&scaled).unwrap();
+    let scaled2: ShapeHandle<Point3<f64>, Isometry3<f64>> = serde_json::from_str(&json).unwrap();
+    let scaled2 = scaled2
+        .as_shape::<Scaled<Vector3<f64>, Compound<Point3<f64>, Isometry3<f64>>>>()
+        .unwrap();
+    assert_eq!(*scaled2.scale(), Vector3::new(2.0, 3.0, 1.0));
+    assert_eq!(scaled2.inner().shapes().len(), 2);
+
+    let ray = Ray::new(Point3::new(-10.0, 0.0, 0.0), Vector3::x());
+    let toi = scaled2.toi_with_ray(&Isometry3::identity(), &ray, true);
+    assert_relative_eq!(toi.unwrap(), 6.0);
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Cube {
+    half_side: f64,
+}
+
+impl Shape<Point3<f64>, Isometry3<f64>> for Cube {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB<Point3<f64>> {
+        let half_extents = Vector3::repeat(self.half_side * 3.0f64.sqrt());
+        let center = Point3::from_coordinates(m.translation.vector);
+
+        AABB::new(center - half_extents, center + half_extents)
+    }
+}
+
+#[test]
+fn serde_user_shapes() {
+    let cube = ShapeHandle::new(Cube { half_side: 2.0 });
+    assert!(serde_json::to_string(&cube).is_err());
+
+    shape::register_serializable_shape::<Point3<f64>, Isometry3<f64>, Cube>("Cube");
+
+    let json = serde_json::to_string(&cube).unwrap();
+    let cube2: ShapeHandle<Point3<f64>, Isometry3<f64>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(cube2.as_shape::<Cube>(), Some(&Cube { half_side: 2.0 }));
+}
@@ -0,0 +1,143 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Unit, Vector2, Vector3};
+use ncollide::query::{self, TOIStatus};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, ShapeHandle};
+
+#[test]
+fn shape_cast_balls() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(5.0, 0.0), 0.0);
+    let vel1 = Vector2::zeros();
+    let vel2 = Vector2::new(-1.0, 0.0);
+
+    let toi = query::shape_cast(&m1, &vel1, &ball, &m2, &vel2, &ball).unwrap();
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness1, Point2::new(1.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness2, Point2::new(1.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(toi.normal.unwrap(), Vector2::x(), epsilon = 1.0e-10);
+
+    // Moving away.
+    assert!(query::shape_cast(&m1, &vel1, &ball, &m2, &-vel2, &ball).is_none());
+
+    let m2 = Isometry2::new(Vector2::new(1.5, 0.0), 0.0);
+    let toi = query::shape_cast(&m1, &vel1, &ball, &m2, &vel2, &ball).unwrap();
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_eq!(toi.toi, 0.0);
+    assert_relative_eq!(toi.witness1, Point2::new(1.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness2, Point2::new(0.5, 0.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn shape_cast_support_maps() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(5.0, 0.5, 0.0), na::zero());
+    let vel1 = Vector3::new(1.0, 0.0, 0.0);
+    let vel2 = Vector3::new(-1.0, 0.0, 0.0);
+
+    // Face against face.
+    let toi = query::shape_cast(&m1, &vel1, &cuboid, &m2, &vel2, &cuboid).unwrap();
+    let linear_toi = query::time_of_impact(&m1, &vel1, &cuboid, &m2, &vel2, &cuboid).unwrap();
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 1.5, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.toi, linear_toi, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness1.x, 2.5, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness2.x, 2.5, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+
+    // A ball hitting the edge of the cuboid.
+    let ball = Ball::new(0.5f64);
+    let m2 = Isometry3::new(Vector3::new(4.0, 4.0, 0.0), na::zero());
+    let vel2 = Vector3::new(-1.0, -1.0, 0.0);
+    let toi = query::shape_cast(&m1, &na::zero(), &cuboid, &m2, &vel2, &ball).unwrap();
+    let m2_toi = Isometry3::new(m2.translation.vector + vel2 * toi.toi, na::zero());
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 3.0 - 0.5 / 2.0f64.sqrt(), epsilon = 1.0e-6);
+    assert!(query::distance(&m1, &cuboid, &m2_toi, &ball) < 1.0e-6);
+    assert_relative_eq!(toi.witness1, Point3::new(1.0, 1.0, 0.0), epsilon = 1.0e-4);
+    assert_relative_eq!(toi.witness2, Point3::new(1.0, 1.0, 0.0), epsilon = 1.0e-4);
+    assert_relative_eq!(
+        toi.normal.unwrap(),
+        Vector3::new(1.0, 1.0, 0.0).normalize(),
+        epsilon = 1.0e-4
+    );
+
+    // The shapes miss each other.
+    let vel2 = Vector3::new(-1.0, 1.0, 0.0);
+    assert!(query::shape_cast(&m1, &na::zero(), &cuboid, &m2, &vel2, &ball).is_none());
+
+    // Initial penetration.
+    let m2 = Isometry3::new(Vector3::new(1.25, 0.0, 0.0), na::zero());
+    let toi = query::shape_cast(&m1, &vel1, &cuboid, &m2, &vel2, &ball).unwrap();
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_eq!(toi.toi, 0.0);
+    assert_relative_eq!(toi.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness1.x, 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness2.x, 0.75, epsilon = 1.0e-6);
+}
+
+#[test]
+fn shape_cast_plane() {
+    let plane = Plane::new(Unit::new_normalize(Vector2::y()));
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(1.0, 5.0), PI / 4.0);
+    let vel1 = Vector2::zeros();
+    let vel2 = Vector2::new(1.0, -2.0);
+
+    // The lowest corner of the cuboid hits the plane.
+    let expected = (5.0 - 2.0f64.sqrt()) / 2.0;
+    let toi = query::shape_cast(&m1, &vel1, &plane, &m2, &vel2, &cuboid).unwrap();
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, expected, epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness1, Point2::new(1.0 + expected, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness2, toi.witness1, epsilon = 1.0e-10);
+    assert_relative_eq!(toi.normal.unwrap(), Vector2::y(), epsilon = 1.0e-10);
+
+    let toi = query::shape_cast(&m2, &vel2, &cuboid, &m1, &vel1, &plane).unwrap();
+    assert_relative_eq!(toi.toi, expected, epsilon = 1.0e-10);
+    assert_relative_eq!(toi.normal.unwrap(), -Vector2::y(), epsilon = 1.0e-10);
+
+    assert!(query::shape_cast(&m1, &vel1, &plane, &m2, &-vel2, &cuboid).is_none());
+
+    let m2 = Isometry2::new(Vector2::new(0.0, 0.5), 0.0);
+    let toi = query::shape_cast(&m1, &vel1, &plane, &m2, &vel2, &cuboid).unwrap();
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_eq!(toi.toi, 0.0);
+    assert_relative_eq!(toi.witness1.y, 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness2.y, -0.5, epsilon = 1.0e-10);
+}
+
+#[test]
+fn shape_cast_composite_shapes() {
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-3.0, 0.0), 0.0), ball.clone()),
+        (Isometry2::new(Vector2::new(3.0, 0.0), 0.0), ball.clone()),
+    ]);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(0.0, 4.0), 0.0);
+    let vel1 = Vector2::zeros();
+    let vel2 = Vector2::new(1.0, -1.0);
+
+    // The ball hits the top of the right part of the compound.
+    let toi = query::shape_cast(&m1, &vel1, &compound, &m2, &vel2, &*ball).unwrap();
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness1, Point2::new(3.0, 0.5), epsilon = 1.0e-10);
+    assert_relative_eq!(toi.witness2, Point2::new(3.0, 0.5), epsilon = 1.0e-10);
+    assert_relative_eq!(toi.normal.unwrap(), Vector2::y(), epsilon = 1.0e-10);
+
+    let flipped = query::shape_cast(&m2, &vel2, &*ball, &m1, &vel1, &compound).unwrap();
+    assert_relative_eq!(flipped.toi, toi.toi, epsilon = 1.0e-10);
+    assert_relative_eq!(flipped.witness1, toi.witness2, epsilon = 1.0e-10);
+    assert_relative_eq!(flipped.normal.unwrap(), -toi.normal.unwrap(), epsilon = 1.0e-10);
+}
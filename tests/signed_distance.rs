@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::query;
+use ncollide::shape::{Ball, Compound, Cuboid, ShapeHandle, TriMesh};
+
+#[test]
+fn signed_distance_balls() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.0), 0.0);
+
+    let res = query::signed_distance(&m1, &ball, &m2, &ball).unwrap();
+    assert_relative_eq!(res.distance, 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.world1, Point2::new(1.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(res.world2, Point2::new(2.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(res.normal.unwrap(), Vector2::x(), epsilon = 1.0e-10);
+
+    let m2 = Isometry2::new(Vector2::new(1.5, 0.0), 0.0);
+    let res = query::signed_distance(&m1, &ball, &m2, &ball).unwrap();
+    assert_relative_eq!(res.distance, -0.5, epsilon = 1.0e-10);
+    assert_relative_eq!(res.world1, Point2::new(1.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(res.world2, Point2::new(0.5, 0.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn signed_distance_support_maps() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(1.5, 0.2, 0.0), na::zero());
+
+    // Penetration computed with the EPA.
+    let res = query::signed_distance(&m1, &cuboid, &m2, &cuboid).unwrap();
+    let contact = query::contact(&m1, &cuboid, &m2, &cuboid, 0.0).unwrap();
+    assert_relative_eq!(res.distance, -0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(res.distance, -contact.depth, epsilon = 1.0e-10);
+    assert_relative_eq!(res.normal.unwrap(), Vector3::x(), epsilon = 1.0e-6);
+    assert_relative_eq!(res.world1.x, 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(res.world2.x, 0.5, epsilon = 1.0e-6);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 3.0, 0.0), na::zero());
+    let res = query::signed_distance(&m1, &cuboid, &m2, &cuboid).unwrap();
+    assert_relative_eq!(res.distance, 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(res.normal.unwrap(), Vector3::y(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn signed_distance_composite_shapes() {
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-3.0, 0.0), 0.0), ball.clone()),
+        (Isometry2::new(Vector2::new(3.0, 0.0), 0.0), ball.clone()),
+    ]);
+    let m1 = Isometry2::identity();
+
+    // The deepest part is selected.
+    let m2 = Isometry2::new(Vector2::new(2.5, 0.0), 0.0);
+    let res = query::signed_distance(&m1, &compound, &m2, &*ball).unwrap();
+    assert_relative_eq!(res.distance, -0.5, epsilon = 1.0e-10);
+    assert_relative_eq!(res.normal.unwrap(), -Vector2::x(), epsilon = 1.0e-10);
+
+    let res = query::signed_distance(&m2, &*ball, &m1, &compound).unwrap();
+    assert_relative_eq!(res.distance, -0.5, epsilon = 1.0e-10);
+    assert_relative_eq!(res.normal.unwrap(), Vector2::x(), epsilon = 1.0e-10);
+    assert_relative_eq!(res.world2, Point2::new(2.5, 0.0), epsilon = 1.0e-10);
+
+    // The closest part is selected if there is no penetration.
+    let m2 = Isometry2::new(Vector2::new(-1.0, 0.0), 0.0);
+    let res = query::signed_distance(&m1, &compound, &m2, &*ball).unwrap();
+    assert_relative_eq!(res.distance, 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.world1, Point2::new(-2.5, 0.0), epsilon = 1.0e-10);
+
+    let mesh = TriMesh::new(
+        Arc::new(vec![
+            Point3::new(-5.0f64, 0.0, -5.0),
+            Point3::new(5.0, 0.0, -5.0),
+            Point3::new(5.0, 0.0, 5.0),
+            Point3::new(-5.0, 0.0, 5.0),
+        ]),
+        Arc::new(vec![Point3::new(0usize, 2, 1), Point3::new(0, 3, 2)]),
+        None,
+        None,
+    );
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(1.0, 0.5, 1.0), na::zero());
+    let res = query::signed_distance(&m1, &mesh, &m2, &Ball::new(1.0)).unwrap();
+    assert_relative_eq!(res.distance, -0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(res.normal.unwrap(), Vector3::y(), epsilon = 1.0e-6);
+    assert_relative_eq!(res.world2, Point3::new(1.0, -0.5, 1.0), epsilon = 1.0e-6);
+}
@@ -0,0 +1,105 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Point4, Vector3};
+use ncollide::shape::{Ball, ShapeHandle, TetMesh};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+
+// The cube [-1, 1]³ split into six tetrahedra around its diagonal.
+fn cube() -> TetMesh<Point3<f64>> {
+    let mut vertices = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        vertices.push(Point3::new(x, y, z));
+    }
+
+    let indices = vec![
+        Point4::new(0usize, 1, 3, 7),
+        Point4::new(0, 1, 5, 7),
+        Point4::new(0, 2, 3, 7),
+        Point4::new(0, 2, 6, 7),
+        Point4::new(0, 4, 5, 7),
+        Point4::new(0, 4, 6, 7),
+    ];
+
+    TetMesh::new(Arc::new(vertices), Arc::new(indices))
+}
+
+#[test]
+fn tet_mesh_point_location() {
+    let mesh = cube();
+    let m = Isometry3::new(Vector3::new(10.0, 0.0, 0.0), na::zero());
+    let pt = Point3::new(10.2, 0.3, -0.4);
+
+    assert!(mesh.contains_point(&m, &pt));
+    assert!(!mesh.contains_point(&m, &Point3::new(11.5, 0.0, 0.0)));
+    assert!(mesh.locate_point(&m, &Point3::new(8.5, 0.0, 0.0)).is_none());
+
+    let location = mesh.locate_point(&m, &pt).unwrap();
+    let tetrahedron = mesh.tetrahedron_at(location.element_index);
+    let bcoords = location.barycentric_coordinates;
+    let mut ls_pt = Point3::origin();
+    ls_pt.coords = tetrahedron.a().coords * bcoords[0] + tetrahedron.b().coords * bcoords[1]
+        + tetrahedron.c().coords * bcoords[2] + tetrahedron.d().coords * bcoords[3];
+
+    assert!(bcoords.iter().all(|b| *b >= 0.0));
+    assert_relative_eq!(bcoords.iter().sum::<f64>(), 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(ls_pt, Point3::new(0.2, 0.3, -0.4), epsilon = 1.0e-7);
+
+    let solid_proj = mesh.project_point(&m, &pt, true);
+    assert!(solid_proj.is_inside);
+    assert_relative_eq!(solid_proj.point, pt);
+
+    let proj = mesh.project_point(&m, &Point3::new(10.8, 0.1, 0.2), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(11.0, 0.1, 0.2), epsilon = 1.0e-7);
+}
+
+#[test]
+fn tet_mesh_ray_entry_and_exit() {
+    let mesh = cube();
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(-5.0, 0.1, 0.2), Vector3::x());
+
+    let inter = mesh.entry_and_exit_with_ray(&m, &ray).unwrap();
+    assert_relative_eq!(inter.entry.toi, 4.0, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.entry.normal, -Vector3::x(), epsilon = 1.0e-7);
+    assert_relative_eq!(inter.exit.toi, 6.0, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.exit.normal, Vector3::x(), epsilon = 1.0e-7);
+
+    let entry_tetrahedron = mesh.tetrahedron_at(inter.entry_tetrahedron);
+    let exit_tetrahedron = mesh.tetrahedron_at(inter.exit_tetrahedron);
+    let entry_pt = Point3::new(-1.0, 0.1, 0.2);
+    let exit_pt = Point3::new(1.0, 0.1, 0.2);
+    assert_relative_eq!(entry_tetrahedron.distance_to_point(&m, &entry_pt, true), 0.0);
+    assert_relative_eq!(exit_tetrahedron.distance_to_point(&m, &exit_pt, true), 0.0);
+
+    let inner_ray = Ray::new(Point3::new(0.0, 0.1, 0.2), Vector3::x());
+    assert_relative_eq!(mesh.toi_with_ray(&m, &inner_ray, true).unwrap(), 0.0);
+
+    let inter = mesh.toi_and_normal_with_ray(&m, &inner_ray, false).unwrap();
+    assert_relative_eq!(inter.toi, 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-7);
+
+    let missed_ray = Ray::new(Point3::new(-5.0, 1.5, 0.0), Vector3::x());
+    assert!(mesh.entry_and_exit_with_ray(&m, &missed_ray).is_none());
+}
+
+#[test]
+fn tet_mesh_contact() {
+    let mesh = ShapeHandle::new(cube());
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.1, 1.4, -0.2), na::zero());
+    let contact = query::contact(&m1, &*mesh, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::y(), epsilon = 1.0e-6);
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, ShapeHandle, Torus};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+use ncollide::transformation::ToTriMesh;
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+#[test]
+fn torus_ray_cast() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::identity();
+
+    // Through the hole: no hit.
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert!(torus.toi_with_ray(&m, &ray, true).is_none());
+
+    // Along the x axis: the first hit is on the outer equator.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = torus.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.5, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-6);
+
+    // From inside the tube.
+    let ray = Ray::new(Point3::new(2.0, 0.0, 0.0), Vector3::y());
+    assert_eq!(torus.toi_with_ray(&m, &ray, true), Some(0.0));
+    let toi = torus.toi_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(toi, 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn torus_point_projection() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::identity();
+
+    let proj = torus.project_point(&m, &Point3::new(0.0, 3.0, 6.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.0, 0.3, 2.4), epsilon = 1.0e-6);
+    assert!(torus.contains_point(&m, &Point3::new(0.0, 0.2, -2.1)));
+    assert!(!torus.contains_point(&m, &Point3::origin()));
+}
+
+#[test]
+fn torus_against_ball() {
+    let torus = ShapeHandle::new(Torus::new(2.0f64, 0.5));
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(2.0, 0.9, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &*torus, &m2, &*ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-3);
+    assert_relative_eq!(contact.normal.unwrap(), Vector3::y(), epsilon = 1.0e-3);
+
+    // The ball fits in the hole.
+    let m2 = Isometry3::identity();
+    assert!(query::contact(&m1, &*torus, &m2, &*ball, 0.0).is_none());
+    assert_relative_eq!(query::distance(&m1, &*torus, &m2, &*ball), 1.0, epsilon = 1.0e-2);
+}
+
+#[test]
+fn torus_to_trimesh() {
+    let mesh = Torus::new(2.0f32, 0.5).to_trimesh((10, 8));
+    assert_eq!(mesh.coords.len(), 80);
+    assert_eq!(mesh.indices.unwrap_unified().len(), 160);
+}
+
+#[test]
+fn torus_in_collision_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(Torus::new(2.0f32, 0.5)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(-2.0, 0.0, 0.8), na::zero()),
+        ShapeHandle::new(Ball::new(0.5f32)),
+        CollisionGroups::new(),
+        query,
+        (),
+    );
+    world.update();
+
+    assert!(world.contacts().count() > 0);
+}
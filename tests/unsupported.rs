@@ -0,0 +1,195 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::events::ContactEvent;
+use ncollide::query::{self, Proximity};
+use ncollide::shape::{Ball, Compound, Shape, ShapeHandle};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+// A shape none of the default algorithms know how to handle.
+struct Blob;
+
+impl Shape<Point2<f64>, Isometry2<f64>> for Blob {
+    fn type_name(&self) -> &'static str {
+        "Blob"
+    }
+
+    fn aabb(&self, m: &Isometry2<f64>) -> AABB<Point2<f64>> {
+        let center = Point2::from_coordinates(m.translation.vector);
+
+        AABB::new(center - Vector2::repeat(1.0), center + Vector2::repeat(1.0))
+    }
+}
+
+#[test]
+fn unsupported_shape_pairs() {
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+    let ball = Ball::new(1.0);
+    let vel = Vector2::x();
+
+    let err = query::try_distance(&m1, &Blob, &m2, &ball).unwrap_err();
+    assert_eq!(err.shape1, "Blob");
+    assert_eq!(err.shape2, "Ball");
+    assert!(format!("{}", err).contains("Blob"));
+
+    let err = query::try_contact(&m1, &ball, &m2, &Blob, 0.0).unwrap_err();
+    assert_eq!(err.shape1, "Ball");
+    assert_eq!(err.shape2, "Blob");
+    assert!(query::try_proximity(&m1, &Blob, &m2, &Blob, 0.0).is_err());
+    assert!(query::try_time_of_impact(&m1, &vel, &Blob, &m2, &vel, &ball).is_err());
+
+    // Supported pairs give the same results as the panicking queries.
+    assert_eq!(
+        query::try_distance(&m1, &ball, &m2, &ball),
+        Ok(query::distance(&m1, &ball, &m2, &ball))
+    );
+}
+
+#[test]
+fn unsupported_composite_parts() {
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(10.0, 0.0), 0.0);
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-2.0, 0.0), 0.0), ball.clone()),
+        (Isometry2::new(Vector2::new(2.0, 0.0), 0.0), ShapeHandle::new(Blob)),
+    ]);
+
+    // The error names the unsupported part, not the compound.
+    let err = query::try_distance(&m1, &compound, &m2, &*ball).unwrap_err();
+    assert_eq!(err.shape1, "Blob");
+    assert_eq!(err.shape2, "Ball");
+
+    let err = query::try_contact(&m2, &*ball, &m1, &compound, 100.0).unwrap_err();
+    assert_eq!(err.shape1, "Ball");
+    assert_eq!(err.shape2, "Blob");
+}
+
+#[test]
+fn unsupported_pair_events() {
+    let mut world = CollisionWorld2::new(0.1);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let blob = world.add(Isometry2::identity(), ShapeHandle::new(Blob), groups, query, ());
+    let ball = world.add(
+        Isometry2::new(Vector2::new(1.0, 0.0), 0.0),
+        ShapeHandle::new(Ball::new(1.0)),
+        groups,
+        query,
+        (),
+    );
+
+    world.update();
+
+    let events: Vec<_> = world.unsupported_pair_events().iter().collect();
+    assert_eq!(events.len(), 1);
+
+    let event = events[0];
+    let mut handles = [event.collider1, event.collider2];
+    handles.sort();
+    let mut expected = [blob, ball];
+    expected.sort();
+    assert_eq!(handles, expected);
+    assert!(event.error.shape1 == "Blob" || event.error.shape2 == "Blob");
+    assert_eq!(world.contacts().count(), 0);
+
+    world.clear_events();
+    assert_eq!(world.unsupported_pair_events().iter().count(), 0);
+}
+
+#[test]
+fn replaced_shape_becomes_unsupported() {
+    let mut world = CollisionWorld2::new(0.1);
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let proximity = GeometricQueryType::Proximity(0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let m = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+    let obj1 = world.add(Isometry2::identity(), ball.clone(), groups, contacts, ());
+    let _ = world.add(m, ball.clone(), groups, contacts, ());
+    let _ = world.add(m, ball.clone(), groups, proximity, ());
+
+    world.update();
+    assert_eq!(world.contact_pairs().count(), 1);
+    assert_eq!(world.proximity_pairs().count(), 2);
+    assert_eq!(world.unsupported_pair_events().iter().count(), 0);
+
+    world.clear_events();
+    world.set_shape(obj1, ShapeHandle::new(Blob));
+    world.update();
+
+    // The algorithms of the two pairs involving the blob are removed.
+    assert_eq!(world.unsupported_pair_events().iter().count(), 2);
+    assert_eq!(world.contact_pairs().count(), 0);
+    assert_eq!(world.proximity_pairs().count(), 1);
+    assert_eq!(world.contacts().count(), 0);
+
+    let stopped = world
+        .contact_events()
+        .iter()
+        .filter(|e| match **e {
+            ContactEvent::Stopped(..) => true,
+            _ => false,
+        })
+        .count();
+    assert_eq!(stopped, 1);
+    assert!(
+        world
+            .proximity_events()
+            .iter()
+            .any(|e| e.new_status == Proximity::Disjoint)
+    );
+}
+
+#[test]
+fn replaced_shape_becomes_supported() {
+    let mut world = CollisionWorld2::new(0.1);
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let proximity = GeometricQueryType::Proximity(0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let m = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+    let obj1 = world.add(Isometry2::identity(), ShapeHandle::new(Blob), groups, contacts, ());
+    let _ = world.add(m, ball.clone(), groups, contacts, ());
+    let _ = world.add(m, ball.clone(), groups, proximity, ());
+
+    world.update();
+    assert_eq!(world.unsupported_pair_events().iter().count(), 2);
+    assert_eq!(world.contact_pairs().count(), 0);
+    assert_eq!(world.proximity_pairs().count(), 1);
+
+    world.clear_events();
+    world.set_shape(obj1, ball);
+    world.update();
+
+    // The AABBs kept overlapping so the broad phase did not report the pairs again.
+    assert_eq!(world.unsupported_pair_events().iter().count(), 0);
+    assert_eq!(world.contact_pairs().count(), 1);
+    assert_eq!(world.proximity_pairs().count(), 2);
+    assert!(world.contacts().count() > 0);
+
+    let started = world
+        .contact_events()
+        .iter()
+        .filter(|e| match **e {
+            ContactEvent::Started(..) => true,
+            _ => false,
+        })
+        .count();
+    assert_eq!(started, 1);
+    assert!(
+        world
+            .proximity_events()
+            .iter()
+            .any(|e| e.new_status == Proximity::Intersecting)
+    );
+
+    // Nothing is reported twice.
+    world.clear_events();
+    world.update();
+    assert_eq!(world.contact_events().iter().count(), 0);
+    assert_eq!(world.unsupported_pair_events().iter().count(), 0);
+}
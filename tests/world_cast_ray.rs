@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate approx;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::query::{Ray, RayCast, RayIntersection};
+use ncollide::shape::{Ball, Shape, ShapeHandle};
+use ncollide::world::{CollisionGroups, CollisionObject2, CollisionWorld2, GeometricQueryType};
+
+static TRIPWIRE_CASTS: AtomicUsize = AtomicUsize::new(0);
+
+// A box that counts the rays cast on it.
+struct Tripwire;
+
+impl RayCast<Point2<f64>, Isometry2<f64>> for Tripwire {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry2<f64>,
+        ray: &Ray<Point2<f64>>,
+        solid: bool,
+    ) -> Option<RayIntersection<Vector2<f64>>> {
+        let _ = TRIPWIRE_CASTS.fetch_add(1, Ordering::SeqCst);
+        self.aabb(m)
+            .toi_and_normal_with_ray(&Isometry2::identity(), ray, solid)
+    }
+}
+
+impl Shape<Point2<f64>, Isometry2<f64>> for Tripwire {
+    fn aabb(&self, m: &Isometry2<f64>) -> AABB<Point2<f64>> {
+        let center = Point2::from_coordinates(m.translation.vector);
+
+        AABB::new(center - Vector2::repeat(1.0), center + Vector2::repeat(1.0))
+    }
+
+    fn as_ray_cast(&self) -> Option<&RayCast<Point2<f64>, Isometry2<f64>>> {
+        Some(self)
+    }
+}
+
+#[test]
+fn world_cast_ray() {
+    let mut world = CollisionWorld2::new(0.0);
+    let ball = ShapeHandle::new(Ball::new(1.0f64));
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let mut near_groups = CollisionGroups::new();
+    near_groups.set_membership(&[1]);
+
+    let m = |x: f64| Isometry2::new(Vector2::new(x, 0.0), 0.0);
+    let shooter = world.add(m(0.0), ball.clone(), groups, query, ());
+    let near = world.add(m(4.0), ball.clone(), near_groups, query, ());
+    let far = world.add(m(10.0), ball.clone(), groups, query, ());
+    let _ = world.add(m(20.0), ShapeHandle::new(Tripwire), groups, query, ());
+    world.update();
+
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+
+    // The ray starts inside of the shooter.
+    let (handle, inter) = world.cast_ray(&ray, &groups, |_| true).unwrap();
+    assert_eq!(handle, shooter);
+    assert_eq!(inter.toi, 0.0);
+
+    let not_shooter = |co: &CollisionObject2<f64, ()>| co.handle() != shooter;
+    let (handle, inter) = world.cast_ray(&ray, &groups, &not_shooter).unwrap();
+    assert_eq!(handle, near);
+    assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-10);
+
+    let mut no_near = CollisionGroups::new();
+    no_near.set_whitelist(&[0]);
+    let (handle, inter) = world.cast_ray(&ray, &no_near, &not_shooter).unwrap();
+    assert_eq!(handle, far);
+    assert_relative_eq!(inter.toi, 9.0, epsilon = 1.0e-10);
+
+    // The objects behind the first hit are not ray cast.
+    assert_eq!(TRIPWIRE_CASTS.load(Ordering::SeqCst), 0);
+    let up = Ray::new(Point2::new(0.0, 5.0), Vector2::x());
+    assert!(world.cast_ray(&up, &groups, |_| true).is_none());
+
+    // Same queries once the objects have been moved to the static tree of the broad phase.
+    for _ in 0..200 {
+        world.update();
+    }
+
+    let (handle, inter) = world.cast_ray(&ray, &groups, &not_shooter).unwrap();
+    assert_eq!(handle, near);
+    assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-10);
+    assert_eq!(TRIPWIRE_CASTS.load(Ordering::SeqCst), 0);
+
+    let (handle, inter) = world.cast_ray(&ray, &no_near, |co| co.handle() == far).unwrap();
+    assert_eq!(handle, far);
+    assert_relative_eq!(inter.toi, 9.0, epsilon = 1.0e-10);
+}